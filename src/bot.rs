@@ -1,15 +1,33 @@
+use std::collections::HashSet;
 use std::env;
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use chrono::Utc;
 use serenity::framework::standard::StandardFramework;
-use serenity::model::channel::Message;
+use serenity::http::Http;
+use serenity::model::channel::{Message, Reaction};
 use serenity::model::gateway::Ready;
+use serenity::model::id::UserId;
 use serenity::prelude::{Client, Context, EventHandler};
 
+use crate::commands::giveaway::latency::COMMAND_LATENCY;
 use crate::commands::giveaway::manager::GiveawayManager;
-use crate::commands::{GET_COMMANDS_LIST, GIVEAWAY_GROUP};
+use crate::commands::giveaway::reactions::ReactionAction;
+use crate::commands::giveaway::scheduler::MessageEdit;
+use crate::commands::giveaway::utils::{
+    add_claim_reaction, apply_message_edit, claims_limit_output, parse_giveaway_index,
+    should_process_message, TRACK_OWN_MESSAGES_ONLY,
+};
+use crate::commands::{GET_COMMANDS_LIST, GIVEAWAY_GROUP, HELP_GROUP};
 use crate::storage::{BotIdStorage, GiveawayStorage};
 
+// How often the bot checks for giveaways that need to be finished.
+const AUTO_FINISH_SCAN_INTERVAL: Duration = Duration::from_secs(5);
+// How often the bot checks for queued edits of the giveaway messages.
+const EDIT_SCHEDULER_SCAN_INTERVAL: Duration = Duration::from_millis(100);
+
 pub struct Handler;
 
 impl EventHandler for Handler {
@@ -21,17 +39,16 @@ impl EventHandler for Handler {
             .cloned()
             .expect("Expected BotId in ShareMap.");
 
-        if msg.author.id.0 == bot_id.0 && msg.content.starts_with("Giveaway #") {
-            let substrings: Vec<&str> = msg.content.split_terminator("\n").collect();
-            if substrings.len() < 1 {
-                return;
-            }
-
-            let index = substrings[0]
-                .trim_start_matches("Giveaway #")
-                .trim_end_matches(":")
-                .parse::<usize>()
-                .unwrap();
+        if should_process_message(
+            *TRACK_OWN_MESSAGES_ONLY,
+            bot_id.0,
+            msg.author.id.0,
+            &msg.content,
+        ) {
+            let index = match parse_giveaway_index(&msg.content) {
+                Some(index) => index,
+                None => return,
+            };
 
             let giveaway_manager = ctx
                 .data
@@ -52,22 +69,255 @@ impl EventHandler for Handler {
     fn ready(&self, _: Context, ready: Ready) {
         println!("{} is connected!", ready.user.name);
     }
+
+    // Rolls, confirms or denies the rewards when participants react on the
+    // giveaway message with one of the configured emojis.
+    fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+        let bot_id = ctx
+            .data
+            .read()
+            .get::<BotIdStorage>()
+            .cloned()
+            .expect("Expected BotId in ShareMap.");
+
+        if reaction.user_id.0 == bot_id.0 {
+            return;
+        }
+
+        let giveaway_manager = ctx
+            .data
+            .write()
+            .get::<GiveawayStorage>()
+            .cloned()
+            .expect("Expected GiveawayManager in ShareMap.");
+
+        let index = match giveaway_manager.get_giveaway_index_by_message(reaction.message_id) {
+            Some(index) => index,
+            None => return,
+        };
+        let emoji = reaction.emoji.to_string();
+        let action = match giveaway_manager.reaction_controls().resolve(&emoji) {
+            Some(action) => action,
+            None => return,
+        };
+        let user = match reaction.user(&ctx) {
+            Ok(user) => user,
+            Err(err) => {
+                println!("Can't get the user of the reaction: {}", err.to_string());
+                return;
+            }
+        };
+
+        // The strategies with picking the reward by its number can't roll
+        // the reward without the `groll` command, so such reactions are ignored.
+        if action == ReactionAction::Roll {
+            match giveaway_manager.get_giveaway_by_index(index) {
+                Ok(giveaway) if giveaway.strategy().requires_reward_number() => return,
+                _ => (),
+            }
+        }
+
+        let result = match action {
+            // The reserved rewards are available only via the `groll` command,
+            // because the booster role is checked from the author of a message.
+            ReactionAction::Roll => giveaway_manager.roll_reward(&user, index, "", false),
+            ReactionAction::Confirm | ReactionAction::Deny => {
+                match giveaway_manager.get_pending_reward_index(user.id.0, index) {
+                    Ok(Some(reward_index)) if action == ReactionAction::Confirm => giveaway_manager
+                        .confirm_reward(&user, index, reward_index)
+                        .map(|receipt_id| {
+                            println!(
+                                "The reward #{} in the giveaway #{} was activated by {}. Receipt: {}",
+                                reward_index, index, user.id.0, receipt_id
+                            );
+                            add_claim_reaction(&ctx, &giveaway_manager, index);
+                            Some(format!(
+                                "{}, the reward #{} has been activated. Receipt: {}",
+                                user.name, reward_index, receipt_id
+                            ))
+                        }),
+                    Ok(Some(reward_index)) => giveaway_manager
+                        .deny_reward(&user, index, reward_index)
+                        .map(|_| None),
+                    Ok(None) => Ok(Some(format!(
+                        "{}, you don't have a pending reward.",
+                        user.name
+                    ))),
+                    Err(err) => Err(err),
+                }
+            }
+        };
+
+        let is_rolled = action == ReactionAction::Roll && result.is_ok();
+        let response = match result {
+            Ok(response) => response,
+            Err(err) => Some(format!("{}", err)),
+        };
+        if let Some(content) = response {
+            if let Err(err) = reaction.channel_id.say(&ctx.http, &content) {
+                println!("Can't reply on the reaction: {}", err.to_string());
+            }
+        }
+        // Same as for the `groll` command, notify the channel when the roll
+        // has paused the giveaway.
+        if is_rolled {
+            claims_limit_output(&ctx.http, reaction.channel_id, &giveaway_manager, index);
+        }
+
+        match giveaway_manager.pretty_print_giveaway(index) {
+            Ok(output) => {
+                let edit = MessageEdit {
+                    channel_id: reaction.channel_id,
+                    message_id: Some(reaction.message_id),
+                    content: output,
+                };
+                giveaway_manager.edit_scheduler().enqueue(index, edit);
+            }
+            Err(err) => println!(
+                "Can't retrieve formatted giveaway state: {}",
+                err.to_string()
+            ),
+        }
+    }
+}
+
+// Schedules the edit of the giveaway state message, when it was posted.
+fn enqueue_giveaway_update(giveaway_manager: &Arc<GiveawayManager>, index: usize) {
+    let giveaway = match giveaway_manager.get_giveaway_by_index(index) {
+        Ok(giveaway) => giveaway,
+        Err(_) => return,
+    };
+    let channel_id = match giveaway.get_channel_id() {
+        Some(channel_id) => channel_id,
+        None => return,
+    };
+
+    match giveaway_manager.pretty_print_giveaway(index) {
+        Ok(output) => {
+            let edit = MessageEdit {
+                channel_id,
+                message_id: giveaway.get_message_id(),
+                content: output,
+            };
+            giveaway_manager.edit_scheduler().enqueue(index, edit);
+        }
+        Err(err) => println!(
+            "Can't retrieve formatted giveaway state: {}",
+            err.to_string()
+        ),
+    }
+}
+
+// Periodically starts the scheduled giveaways, denies the stale pending
+// rewards, finishes the giveaways that have reached their deadline and posts
+// their state in the channel.
+fn spawn_auto_finish_timer(http: Arc<Http>, giveaway_manager: Arc<GiveawayManager>) {
+    thread::spawn(move || loop {
+        thread::sleep(AUTO_FINISH_SCAN_INTERVAL);
+
+        for (index, giveaway) in giveaway_manager.start_scheduled_giveaways(Instant::now()) {
+            let channel_id = match giveaway.get_channel_id() {
+                Some(channel_id) => channel_id,
+                None => continue,
+            };
+
+            let output = match giveaway_manager.pretty_print_giveaway(index) {
+                Ok(output) => output,
+                Err(err) => {
+                    println!(
+                        "Can't retrieve formatted giveaway state: {}",
+                        err.to_string()
+                    );
+                    continue;
+                }
+            };
+
+            if let Err(err) = channel_id.say(&http, &output) {
+                println!(
+                    "Impossible to output the started giveaway. Reason: {}",
+                    err.to_string()
+                );
+            }
+        }
+
+        let mut updated_giveaways = HashSet::new();
+        for (index, reward_index, user_id) in giveaway_manager.auto_deny_stale_pending(Utc::now()) {
+            updated_giveaways.insert(index);
+            let content = format!(
+                "The reward #{} in the giveaway #{} wasn't confirmed in time and has been returned to the giveaway.",
+                reward_index, index
+            );
+            let result = UserId(user_id)
+                .create_dm_channel(&http)
+                .and_then(|channel| channel.say(&http, &content));
+            if let Err(err) = result {
+                println!(
+                    "Can't notify the user about the denied reward: {}",
+                    err.to_string()
+                );
+            }
+        }
+        for index in updated_giveaways {
+            enqueue_giveaway_update(&giveaway_manager, index);
+        }
+
+        for (giveaway, output) in giveaway_manager.finish_expired_giveaways(Instant::now()) {
+            let channel_id = match giveaway.get_channel_id() {
+                Some(channel_id) => channel_id,
+                None => continue,
+            };
+
+            let result = match giveaway.get_message_id() {
+                Some(message_id) => channel_id
+                    .edit_message(&http, message_id, |m| m.content(&output))
+                    .map(|_| ()),
+                None => channel_id.say(&http, &output).map(|_| ()),
+            };
+
+            if let Err(err) = result {
+                println!(
+                    "Impossible to output the finished giveaway. Reason: {}",
+                    err.to_string()
+                );
+            }
+        }
+    });
+}
+
+// Periodically sends the queued edits of the giveaway messages, no faster
+// than the rate allowed by the edit scheduler.
+fn spawn_edit_scheduler(http: Arc<Http>, giveaway_manager: Arc<GiveawayManager>) {
+    thread::spawn(move || loop {
+        thread::sleep(EDIT_SCHEDULER_SCAN_INTERVAL);
+
+        let edit_scheduler = giveaway_manager.edit_scheduler();
+        while let Some((_, edit)) = edit_scheduler.next_edit(Instant::now()) {
+            apply_message_edit(&http, &edit);
+        }
+    });
 }
 
 pub fn run_discord_bot() {
     let token = env::var("DISCORD_TOKEN").expect("Expected a DISCORD_TOKEN in the environment");
     let mut client = Client::new(&token, Handler).expect("Cannot create a Discord client");
 
-    let bot_id = match client.cache_and_http.http.get_current_application_info() {
-        Ok(info) => info.id,
+    let (bot_id, owners) = match client.cache_and_http.http.get_current_application_info() {
+        Ok(info) => {
+            let mut owners = HashSet::new();
+            owners.insert(info.owner.id);
+            (info.id, owners)
+        }
         Err(why) => panic!("Could not access application info: {:?}", why),
     };
 
+    let giveaway_manager = Arc::new(GiveawayManager::new());
     {
         let mut data = client.data.write();
-        data.insert::<GiveawayStorage>(Arc::new(GiveawayManager::new()));
+        data.insert::<GiveawayStorage>(giveaway_manager.clone());
         data.insert::<BotIdStorage>(Arc::new(bot_id));
     }
+    spawn_auto_finish_timer(client.cache_and_http.http.clone(), giveaway_manager.clone());
+    spawn_edit_scheduler(client.cache_and_http.http.clone(), giveaway_manager);
 
     client.with_framework(
         StandardFramework::new()
@@ -75,9 +325,18 @@ pub fn run_discord_bot() {
                 c.with_whitespace(false)
                     .on_mention(Some(bot_id))
                     .prefix("!")
+                    .owners(owners)
+            })
+            .before(|_, msg, _| {
+                COMMAND_LATENCY.start(msg.id.0, Instant::now());
+                true
+            })
+            .after(|_, msg, command_name, _| {
+                COMMAND_LATENCY.finish(msg.id.0, command_name, Instant::now());
             })
             .help(&GET_COMMANDS_LIST)
-            .group(&GIVEAWAY_GROUP),
+            .group(&GIVEAWAY_GROUP)
+            .group(&HELP_GROUP),
     );
 
     if let Err(why) = client.start() {
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+// Describes a single giveaway in the imported data.
+#[derive(Debug, Deserialize)]
+pub struct GiveawayDto {
+    pub description: String,
+    #[serde(default)]
+    pub rewards: Vec<String>,
+}
+
+// The result of the bulk import: the number of created giveaways and
+// the reason of failure for each skipped entry by its position.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub imported: usize,
+    pub failed: Vec<(usize, String)>,
+}
+
+// Aggregated numbers of the giveaway without any user identifiers, which
+// are safe to share publicly.
+#[derive(Debug, Default, PartialEq)]
+pub struct AnonStats {
+    pub participants: usize,
+    pub claims: usize,
+    pub rewards_remaining: usize,
+    pub average_claims: f64,
+}
+
+// The state of a single giveaway shown in the owner's dashboard.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GiveawaySummary {
+    pub index: usize,
+    pub description: String,
+    pub rewards: usize,
+    pub remaining: usize,
+    pub claims: u64,
+    pub participants: usize,
+}
+
+// The summaries of all giveaways owned by the user with the totals across
+// them. The participants are counted once, even if they took part in
+// several giveaways.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct OwnerDashboard {
+    pub giveaways: Vec<GiveawaySummary>,
+    pub total_rewards: usize,
+    pub total_remaining: usize,
+    pub total_claims: u64,
+    pub total_participants: usize,
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::commands::giveaway::models::Reward;
+use crate::commands::giveaway::models::{ObjectState, Reward};
 
 pub trait RewardFormatter {
     // Returns detailed info for the giveaway owner when necessary
@@ -9,4 +9,10 @@ pub trait RewardFormatter {
     // Stylized print for the users in the channel when the giveaways
     // has been started.
     fn pretty_print(&self, reward: &Arc<Box<Reward>>) -> String;
+    // Returns only the value of the reward, masked the same way as in
+    // the stylized print.
+    fn masked_value(&self, reward: &Arc<Box<Reward>>) -> String;
+    // Changes how many segments of the key are visible to the users
+    // for the certain reward state.
+    fn set_revealed_segments(&self, state: ObjectState, segments: Option<usize>);
 }
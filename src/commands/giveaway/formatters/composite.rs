@@ -0,0 +1,136 @@
+// Formatter that picks the output for each reward by its object type, so
+// the keys, links and other rewards can be masked differently.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::commands::giveaway::formatters::base::RewardFormatter;
+use crate::commands::giveaway::formatters::reward::DefaultRewardFormatter;
+use crate::commands::giveaway::models::{ObjectState, ObjectType, Reward};
+
+// The owners can't pick the formatter of the giveaway yet, so the bot itself
+// doesn't construct this formatter until such a command is added.
+#[allow(dead_code)]
+pub struct CompositeRewardFormatter {
+    // Formatters registered for the certain object types.
+    formatters: HashMap<ObjectType, Box<dyn RewardFormatter + Send + Sync>>,
+    // Formatter used for the object types without a registered formatter.
+    default: Box<dyn RewardFormatter + Send + Sync>,
+}
+
+#[allow(dead_code)]
+impl CompositeRewardFormatter {
+    pub fn new() -> Self {
+        CompositeRewardFormatter::with_default(Box::new(DefaultRewardFormatter::new()))
+    }
+
+    pub fn with_default(default: Box<dyn RewardFormatter + Send + Sync>) -> Self {
+        CompositeRewardFormatter {
+            formatters: HashMap::new(),
+            default,
+        }
+    }
+
+    // Registers the formatter for the given object type. The previously
+    // registered formatter for this type is replaced.
+    pub fn with_formatter(
+        mut self,
+        object_type: ObjectType,
+        formatter: Box<dyn RewardFormatter + Send + Sync>,
+    ) -> Self {
+        self.formatters.insert(object_type, formatter);
+        self
+    }
+
+    fn formatter_for(&self, reward: &Arc<Box<Reward>>) -> &(dyn RewardFormatter + Send + Sync) {
+        match self.formatters.get(&reward.object_type()) {
+            Some(formatter) => formatter.as_ref(),
+            None => self.default.as_ref(),
+        }
+    }
+}
+
+impl RewardFormatter for CompositeRewardFormatter {
+    fn debug_print(&self, reward: &Arc<Box<Reward>>) -> String {
+        self.formatter_for(reward).debug_print(reward)
+    }
+
+    fn pretty_print(&self, reward: &Arc<Box<Reward>>) -> String {
+        self.formatter_for(reward).pretty_print(reward)
+    }
+
+    fn masked_value(&self, reward: &Arc<Box<Reward>>) -> String {
+        self.formatter_for(reward).masked_value(reward)
+    }
+
+    // The reveal policy is applied to all of the formatters.
+    fn set_revealed_segments(&self, state: ObjectState, segments: Option<usize>) {
+        for formatter in self.formatters.values() {
+            formatter.set_revealed_segments(state, segments);
+        }
+        self.default.set_revealed_segments(state, segments);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::commands::giveaway::formatters::{
+        CompositeRewardFormatter, DefaultRewardFormatter, JsonRewardFormatter, RewardFormatter,
+    };
+    use crate::commands::giveaway::models::{ObjectState, ObjectType, Reward};
+
+    fn get_formatter() -> CompositeRewardFormatter {
+        CompositeRewardFormatter::new()
+            .with_formatter(ObjectType::Key, Box::new(JsonRewardFormatter::new()))
+            .with_formatter(
+                ObjectType::KeyPreorder,
+                Box::new(DefaultRewardFormatter::with_options(2, '*')),
+            )
+    }
+
+    #[test]
+    fn test_key_is_routed_to_its_formatter() {
+        let reward = Arc::new(Box::new(Reward::new("AAAAA-BBBBB-CCCCC -> Some game")));
+        let formatter = get_formatter();
+
+        assert_eq!(
+            formatter.pretty_print(&reward),
+            JsonRewardFormatter::new().pretty_print(&reward)
+        );
+        assert_eq!(
+            formatter.debug_print(&reward),
+            JsonRewardFormatter::new().debug_print(&reward)
+        );
+    }
+
+    #[test]
+    fn test_preorder_key_is_routed_to_its_formatter() {
+        let reward = Arc::new(Box::new(Reward::new("AAAAA-BBBBB-CCCCC -> Preorder game")));
+        let formatter = get_formatter();
+
+        assert_eq!(reward.object_type(), ObjectType::KeyPreorder);
+        assert_eq!(formatter.masked_value(&reward), "AAAAA-*****-*****");
+    }
+
+    #[test]
+    fn test_other_object_is_routed_to_the_default_formatter() {
+        let reward = Arc::new(Box::new(Reward::new("https://example.com/gift")));
+        let formatter = get_formatter();
+
+        assert_eq!(reward.object_type(), ObjectType::Other);
+        assert_eq!(
+            formatter.pretty_print(&reward),
+            DefaultRewardFormatter::new().pretty_print(&reward)
+        );
+    }
+
+    #[test]
+    fn test_reveal_policy_is_applied_to_the_registered_formatters() {
+        let reward = Arc::new(Box::new(Reward::new("AAAAA-BBBBB-CCCCC -> Preorder game")));
+        let formatter = get_formatter();
+
+        formatter.set_revealed_segments(ObjectState::Unused, Some(3));
+        assert_eq!(formatter.masked_value(&reward), "AAAAA-BBBBB-CCCCC");
+    }
+}
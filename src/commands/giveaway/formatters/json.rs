@@ -0,0 +1,141 @@
+// Formatter that outputs the rewards as JSON objects for machine-readable exports
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::commands::giveaway::formatters::base::RewardFormatter;
+use crate::commands::giveaway::formatters::reward::DefaultRewardFormatter;
+use crate::commands::giveaway::models::{ObjectState, ObjectType, Reward};
+
+#[derive(Serialize)]
+struct RewardJson {
+    id: String,
+    value: String,
+    description: Option<String>,
+    object_info: Option<String>,
+    object_type: ObjectType,
+    object_state: ObjectState,
+}
+
+pub struct JsonRewardFormatter;
+
+impl JsonRewardFormatter {
+    pub fn new() -> Self {
+        JsonRewardFormatter {}
+    }
+
+    fn to_json(&self, reward: &Arc<Box<Reward>>, value: String) -> String {
+        let data = RewardJson {
+            id: reward.id().to_string(),
+            value,
+            description: reward.description(),
+            object_info: reward.object_info(),
+            object_type: reward.object_type(),
+            object_state: reward.object_state(),
+        };
+        serde_json::to_string(&data).unwrap()
+    }
+}
+
+impl RewardFormatter for JsonRewardFormatter {
+    // Returns all information about the reward as a compact JSON object.
+    fn debug_print(&self, reward: &Arc<Box<Reward>>) -> String {
+        self.to_json(reward, reward.value().to_string())
+    }
+
+    // Returns the same JSON object, but the unused keys are masked.
+    fn pretty_print(&self, reward: &Arc<Box<Reward>>) -> String {
+        self.to_json(reward, self.masked_value(reward))
+    }
+
+    fn masked_value(&self, reward: &Arc<Box<Reward>>) -> String {
+        let value = match (reward.object_type(), reward.object_state()) {
+            (ObjectType::Key, ObjectState::Unused)
+            | (ObjectType::KeyPreorder, ObjectState::Unused) => {
+                DefaultRewardFormatter::new().generate_key_with_mask(reward)
+            }
+            _ => reward.value(),
+        };
+        value.to_string()
+    }
+
+    // The JSON output always uses the default masking.
+    fn set_revealed_segments(&self, _state: ObjectState, _segments: Option<usize>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::commands::giveaway::formatters::{JsonRewardFormatter, RewardFormatter};
+    use crate::commands::giveaway::models::{ObjectState, Reward};
+
+    #[test]
+    fn test_json_debug_print_for_the_key() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = JsonRewardFormatter::new();
+
+        let output = formatter.debug_print(&reward);
+        assert_eq!(
+            output,
+            format!(
+                "{{\"id\":\"{}\",\"value\":\"AAAAA-BBBBB-CCCCC-DDDD\",\"description\":\"Some game\",\
+                \"object_info\":\"[Store]\",\"object_type\":\"Key\",\"object_state\":\"Unused\"}}",
+                reward.id()
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_pretty_print_for_the_key_in_the_unused_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = JsonRewardFormatter::new();
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(
+            output,
+            format!(
+                "{{\"id\":\"{}\",\"value\":\"AAAAA-BBBBB-CCCCC-xxxx\",\"description\":\"Some game\",\
+                \"object_info\":\"[Store]\",\"object_type\":\"Key\",\"object_state\":\"Unused\"}}",
+                reward.id()
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_pretty_print_for_the_key_in_the_pending_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = JsonRewardFormatter::new();
+
+        reward.set_object_state(ObjectState::Pending);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(
+            output,
+            format!(
+                "{{\"id\":\"{}\",\"value\":\"AAAAA-BBBBB-CCCCC-DDDD\",\"description\":\"Some game\",\
+                \"object_info\":null,\"object_type\":\"Key\",\"object_state\":\"Pending\"}}",
+                reward.id()
+            )
+        );
+    }
+
+    #[test]
+    fn test_json_pretty_print_for_an_unknown_object() {
+        let text = "just a text";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = JsonRewardFormatter::new();
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(
+            output,
+            format!(
+                "{{\"id\":\"{}\",\"value\":\"just a text\",\"description\":null,\
+                \"object_info\":null,\"object_type\":\"Other\",\"object_state\":\"Unused\"}}",
+                reward.id()
+            )
+        );
+    }
+}
@@ -1,5 +1,13 @@
 pub mod base;
+pub mod composite;
+pub mod json;
 pub mod reward;
+pub mod table;
+pub mod tsv;
 
 pub use crate::commands::giveaway::formatters::base::RewardFormatter;
+pub use crate::commands::giveaway::formatters::composite::CompositeRewardFormatter;
+pub use crate::commands::giveaway::formatters::json::JsonRewardFormatter;
 pub use crate::commands::giveaway::formatters::reward::DefaultRewardFormatter;
+pub use crate::commands::giveaway::formatters::table::AsciiTableExporter;
+pub use crate::commands::giveaway::formatters::tsv::TsvExporter;
@@ -2,42 +2,79 @@
 // formatters for the giveaway rewards
 use std::sync::Arc;
 
+use dashmap::DashMap;
+
 use crate::commands::giveaway::formatters::base::RewardFormatter;
 use crate::commands::giveaway::models::{ObjectState, ObjectType, Reward};
 
-pub struct DefaultRewardFormatter;
+pub const DEFAULT_MASKED_SEGMENTS: usize = 1;
+pub const DEFAULT_MASK_CHAR: char = 'x';
+
+pub struct DefaultRewardFormatter {
+    // How many segments of the key are revealed for each reward state.
+    // States without an entry are using the default masking.
+    reveal_policy: DashMap<ObjectState, usize>,
+    // How many of the last parts of the key are hidden by default.
+    masked_segments: usize,
+    // The symbol used for hiding the key.
+    mask_char: char,
+}
 
 impl DefaultRewardFormatter {
     pub fn new() -> Self {
-        DefaultRewardFormatter {}
+        DefaultRewardFormatter::with_options(DEFAULT_MASKED_SEGMENTS, DEFAULT_MASK_CHAR)
     }
 
-    // Replaces the last part of the key into `x` symbols to stop abusing
+    pub fn with_options(masked_segments: usize, mask_char: char) -> Self {
+        DefaultRewardFormatter {
+            reveal_policy: DashMap::new(),
+            masked_segments,
+            mask_char,
+        }
+    }
+
+    // Replaces the last parts of the key into the mask symbols to stop abusing
     // exposed keys in giveaways.
-    fn generate_key_with_mask(&self, reward: &Arc<Box<Reward>>) -> Arc<String> {
-        let key_fragments = reward
+    pub fn generate_key_with_mask(&self, reward: &Arc<Box<Reward>>) -> Arc<String> {
+        let parts_count = reward.value().split('-').count();
+        let revealed = parts_count.saturating_sub(self.masked_segments);
+        self.generate_key_with_revealed_segments(reward, revealed)
+    }
+
+    // Keeps the first `revealed` parts of the key as is and replaces
+    // the rest into the mask symbols.
+    pub fn generate_key_with_revealed_segments(
+        &self,
+        reward: &Arc<Box<Reward>>,
+        revealed: usize,
+    ) -> Arc<String> {
+        let key_with_mask = reward
             .value()
             .split('-')
-            .map(|key_fragment| key_fragment.to_string())
-            .collect::<Vec<String>>();
-        let parts_count = key_fragments.len();
-        let key_with_mask = key_fragments
-            .into_iter()
             .enumerate()
-            .map(|(index, key_fragment)| match index == parts_count - 1 {
-                true => key_fragment.chars().map(|_| 'x').collect::<String>(),
-                false => key_fragment,
+            .map(|(index, key_fragment)| match index < revealed {
+                true => key_fragment.to_string(),
+                false => key_fragment
+                    .chars()
+                    .map(|_| self.mask_char)
+                    .collect::<String>(),
             })
             .collect::<Vec<String>>()
             .join("-");
         Arc::new(key_with_mask)
     }
+
+    // Returns the number of revealed segments for the given state, if
+    // it was overridden by the giveaway owner.
+    pub fn revealed_segments(&self, state: ObjectState) -> Option<usize> {
+        self.reveal_policy.get(&state).map(|segments| *segments)
+    }
 }
 
 impl RewardFormatter for DefaultRewardFormatter {
     // Returns detailed info for the giveaway owner when necessary to update the giveaway.
     fn debug_print(&self, reward: &Arc<Box<Reward>>) -> String {
-        match reward.object_type() {
+        let text = match reward.object_type() {
             ObjectType::Key | ObjectType::KeyPreorder => {
                 let key = match reward.object_info() {
                     Some(info) => format!("{} {}", reward.value(), info),
@@ -55,6 +92,12 @@ impl RewardFormatter for DefaultRewardFormatter {
                 reward.value(),
                 reward.description().unwrap_or(String::from("")),
             ),
+        };
+
+        // The notes are shown only in the detailed info for the owner.
+        match reward.owner_note() {
+            Some(note) => format!("{} (note: {})", text, note),
+            None => text,
         }
     }
 
@@ -63,15 +106,15 @@ impl RewardFormatter for DefaultRewardFormatter {
         let text = match reward.object_type() {
             // Different output of the key, depends on the current state
             ObjectType::Key | ObjectType::KeyPreorder => {
-                let masked_key = match reward.object_state() == ObjectState::Unused {
-                    true => self.generate_key_with_mask(reward),
-                    false => reward.value(),
-                };
-
+                let masked_key = self.masked_value(reward);
                 let key = match reward.object_info() {
                     Some(info) => format!("{} {}", masked_key, info),
                     None => format!("{}", masked_key),
                 };
+                let key = match reward.platform().label() {
+                    Some(label) => format!("{} {}", label, key),
+                    None => key,
+                };
 
                 match reward.object_state() {
                     // When is Activated show what was hidden behind the key
@@ -95,9 +138,42 @@ impl RewardFormatter for DefaultRewardFormatter {
         };
 
         // If the object was taken by someone, then cross out the text
-        match reward.object_state() == ObjectState::Activated {
-            true => format!("~~{}~~", text),
-            false => text,
+        match reward.object_state() {
+            ObjectState::Activated => format!("~~{}~~", text),
+            ObjectState::Expired => format!("~~{}~~ (expired)", text),
+            _ if reward.total_quantity() > 1 => format!("{} ({} left)", text, reward.quantity()),
+            _ => text,
+        }
+    }
+
+    // Hides the key in according with the reveal policy. Without the policy
+    // only the unused keys are masked.
+    fn masked_value(&self, reward: &Arc<Box<Reward>>) -> String {
+        let value = match reward.object_type() {
+            ObjectType::Key | ObjectType::KeyPreorder => {
+                match self.revealed_segments(reward.object_state()) {
+                    Some(revealed) => self.generate_key_with_revealed_segments(reward, revealed),
+                    None => match reward.object_state() == ObjectState::Unused {
+                        true => self.generate_key_with_mask(reward),
+                        false => reward.value(),
+                    },
+                }
+            }
+            ObjectType::Other => reward.value(),
+        };
+        value.to_string()
+    }
+
+    // Overrides the number of revealed key segments for the certain state.
+    // Passing `None` restores the default masking.
+    fn set_revealed_segments(&self, state: ObjectState, segments: Option<usize>) {
+        match segments {
+            Some(value) => {
+                self.reveal_policy.insert(state, value);
+            }
+            None => {
+                self.reveal_policy.remove(&state);
+            }
         }
     }
 }
@@ -109,6 +185,23 @@ mod tests {
     use crate::commands::giveaway::formatters::{DefaultRewardFormatter, RewardFormatter};
     use crate::commands::giveaway::models::{ObjectState, Reward};
 
+    #[test]
+    fn test_default_debug_print_with_owner_note() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        reward.set_owner_note("from the bundle");
+        assert_eq!(
+            formatter.debug_print(&reward),
+            "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game (note: from the bundle)"
+        );
+        assert_eq!(
+            formatter.pretty_print(&reward),
+            "[ ] AAAAA-BBBBB-CCCCC-xxxx [Store]"
+        );
+    }
+
     #[test]
     fn test_default_pretty_print_for_the_reward_in_the_unused_state() {
         let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
@@ -144,6 +237,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_default_pretty_print_for_the_reward_in_the_expired_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        reward.set_object_state(ObjectState::Expired);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "~~[x] AAAAA-BBBBB-CCCCC-DDDD [Store]~~ (expired)");
+    }
+
+    #[test]
+    fn test_default_pretty_print_for_the_steam_key() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Steam] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] (Steam) AAAAA-BBBBB-CCCCC-xxxx [Steam]");
+    }
+
+    #[test]
+    fn test_default_pretty_print_for_the_epic_games_key() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Epic Games] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        reward.set_object_state(ObjectState::Activated);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(
+            output,
+            "~~[+] (Epic) AAAAA-BBBBB-CCCCC-DDDD [Epic Games] -> Some game~~"
+        );
+    }
+
+    #[test]
+    fn test_default_pretty_print_for_the_key_without_object_info() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] AAAAA-BBBBB-CCCCC-xxxx");
+    }
+
     #[test]
     fn test_default_pretty_print_for_an_unknown_object_in_the_unused_state() {
         let text = "just a text";
@@ -164,4 +302,111 @@ mod tests {
         let output = formatter.pretty_print(&reward);
         assert_eq!(output, "~~[+] just a text~~");
     }
+
+    #[test]
+    fn test_pretty_print_with_reveal_policy_for_the_unused_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        formatter.set_revealed_segments(ObjectState::Unused, Some(0));
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] xxxxx-xxxxx-xxxxx-xxxx [Store]");
+    }
+
+    #[test]
+    fn test_pretty_print_with_reveal_policy_for_the_pending_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        formatter.set_revealed_segments(ObjectState::Pending, Some(2));
+        reward.set_object_state(ObjectState::Pending);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[?] AAAAA-BBBBB-xxxxx-xxxx [Store]");
+    }
+
+    #[test]
+    fn test_pretty_print_with_reveal_policy_for_the_activated_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        formatter.set_revealed_segments(ObjectState::Activated, Some(10));
+        reward.set_object_state(ObjectState::Activated);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(
+            output,
+            "~~[+] AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game~~"
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_after_reset_of_the_reveal_policy() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        formatter.set_revealed_segments(ObjectState::Unused, Some(0));
+        formatter.set_revealed_segments(ObjectState::Unused, None);
+        assert_eq!(formatter.revealed_segments(ObjectState::Unused), None);
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] AAAAA-BBBBB-CCCCC-xxxx [Store]");
+    }
+
+    #[test]
+    fn test_pretty_print_with_two_masked_segments() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::with_options(2, 'x');
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] AAAAA-BBBBB-xxxxx-xxxx [Store]");
+    }
+
+    #[test]
+    fn test_pretty_print_with_custom_mask_char() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::with_options(2, '*');
+
+        let output = formatter.pretty_print(&reward);
+        assert_eq!(output, "[ ] AAAAA-BBBBB-*****-**** [Store]");
+    }
+
+    #[test]
+    fn test_generate_key_with_more_masked_segments_than_the_key_has() {
+        let text = "AAAAA-BBBBB -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::with_options(5, 'x');
+
+        let output = formatter.generate_key_with_mask(&reward);
+        assert_eq!(*output, "xxxxx-xxxxx");
+    }
+
+    #[test]
+    fn test_generate_key_with_default_mask() {
+        let text = "AAAAA-BBBBB-CCCCC -> Some game";
+        let reward = Arc::new(Box::new(Reward::new(text)));
+        let formatter = DefaultRewardFormatter::new();
+
+        let output = formatter.generate_key_with_mask(&reward);
+        assert_eq!(*output, "AAAAA-BBBBB-xxxxx");
+    }
+
+    #[test]
+    fn test_pretty_print_with_remaining_quantity() {
+        let reward = Arc::new(Box::new(Reward::new("3x Discord Nitro")));
+        let formatter = DefaultRewardFormatter::new();
+
+        assert_eq!(
+            formatter.pretty_print(&reward),
+            "[ ] Discord Nitro (3 left)"
+        );
+        reward.take_copy();
+        assert_eq!(
+            formatter.pretty_print(&reward),
+            "[ ] Discord Nitro (2 left)"
+        );
+    }
 }
@@ -0,0 +1,138 @@
+// Exporter that renders the rewards as a fixed-width table for plaintext logs
+pub struct AsciiTableExporter {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl AsciiTableExporter {
+    pub fn new(headers: &[&str]) -> Self {
+        AsciiTableExporter {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    // Adds a new row to the table. Missing cells are rendered as empty ones.
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    // Returns the width of each column, which is enough to fit the longest
+    // cell or the header.
+    fn column_widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .fold(header.chars().count(), usize::max)
+            })
+            .collect()
+    }
+
+    fn render_separator(&self, widths: &[usize]) -> String {
+        let cells = widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<String>>();
+        format!("+{}+", cells.join("+"))
+    }
+
+    fn render_row(&self, row: &[String], widths: &[usize]) -> String {
+        let cells = widths
+            .iter()
+            .enumerate()
+            .map(|(column, width)| {
+                let cell = row.get(column).map(|cell| cell.as_str()).unwrap_or("");
+                format!(" {:<width$} ", cell, width = width)
+            })
+            .collect::<Vec<String>>();
+        format!("|{}|", cells.join("|"))
+    }
+
+    // Renders the table with the headers, separated from the rows.
+    pub fn render(&self) -> String {
+        let widths = self.column_widths();
+        let separator = self.render_separator(&widths);
+
+        let mut lines = vec![
+            separator.clone(),
+            self.render_row(&self.headers, &widths),
+            separator.clone(),
+        ];
+        for row in self.rows.iter() {
+            lines.push(self.render_row(row, &widths));
+        }
+        lines.push(separator);
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::giveaway::formatters::AsciiTableExporter;
+
+    #[test]
+    fn test_render_aligned_columns() {
+        let mut exporter = AsciiTableExporter::new(&["#", "Value"]);
+        exporter.add_row(vec![format!("1"), format!("AAAAA-BBBBB")]);
+        exporter.add_row(vec![format!("10"), format!("C")]);
+
+        let output = exporter.render();
+        assert_eq!(
+            output,
+            vec![
+                "+----+-------------+",
+                "| #  | Value       |",
+                "+----+-------------+",
+                "| 1  | AAAAA-BBBBB |",
+                "| 10 | C           |",
+                "+----+-------------+",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_render_rows_of_the_same_width() {
+        let mut exporter = AsciiTableExporter::new(&["#", "State", "Value"]);
+        exporter.add_row(vec![format!("1"), format!("Unused"), format!("x")]);
+        exporter.add_row(vec![
+            format!("2"),
+            format!("Activated"),
+            format!("long value"),
+        ]);
+        exporter.add_row(vec![format!("3")]);
+
+        let output = exporter.render();
+        let widths = output
+            .lines()
+            .map(|line| line.chars().count())
+            .collect::<Vec<usize>>();
+        assert_eq!(widths.iter().all(|width| *width == widths[0]), true);
+        assert_eq!(
+            output.lines().nth(5).unwrap(),
+            "| 3 |           |            |"
+        );
+    }
+
+    #[test]
+    fn test_render_table_without_rows() {
+        let exporter = AsciiTableExporter::new(&["#", "Value"]);
+
+        let output = exporter.render();
+        assert_eq!(
+            output,
+            vec![
+                "+---+-------+",
+                "| # | Value |",
+                "+---+-------+",
+                "+---+-------+"
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Exporter that renders the rewards as tab-separated values, which can be
+// pasted into the spreadsheets as is.
+pub struct TsvExporter {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl TsvExporter {
+    pub fn new(headers: &[&str]) -> Self {
+        TsvExporter {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    // Adds a new row to the output.
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    // Escapes the characters that would break the rows or the columns.
+    fn escape_field(field: &str) -> String {
+        field
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\r', "\\r")
+            .replace('\n', "\\n")
+    }
+
+    fn render_row(row: &[String]) -> String {
+        row.iter()
+            .map(|field| TsvExporter::escape_field(field))
+            .collect::<Vec<String>>()
+            .join("\t")
+    }
+
+    // Renders the header row, followed by the rows. Each line ends with a newline.
+    pub fn render(&self) -> String {
+        let mut lines = vec![TsvExporter::render_row(&self.headers)];
+        for row in self.rows.iter() {
+            lines.push(TsvExporter::render_row(row));
+        }
+        lines
+            .iter()
+            .map(|line| format!("{}\n", line))
+            .collect::<String>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::giveaway::formatters::TsvExporter;
+
+    #[test]
+    fn test_render_header_and_rows() {
+        let mut exporter = TsvExporter::new(&["#", "Value"]);
+        exporter.add_row(vec![format!("1"), format!("AAAAA-BBBBB")]);
+
+        let output = exporter.render();
+        assert_eq!(output, "#\tValue\n1\tAAAAA-BBBBB\n");
+    }
+
+    #[test]
+    fn test_escape_tabs_and_newlines_in_fields() {
+        let mut exporter = TsvExporter::new(&["#", "Description"]);
+        exporter.add_row(vec![format!("1"), format!("Some\tgame\nwith\\DLC")]);
+
+        let output = exporter.render();
+        let lines = output.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "#\tDescription");
+        assert_eq!(lines[1], "1\tSome\\tgame\\nwith\\\\DLC");
+    }
+}
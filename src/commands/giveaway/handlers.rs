@@ -1,3 +1,8 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use chrono::{Duration as ChronoDuration, Utc};
+
 use serenity::framework::standard::macros::{command, group};
 use serenity::framework::standard::Args;
 use serenity::framework::standard::CommandResult;
@@ -5,29 +10,99 @@ use serenity::model::channel::Message;
 use serenity::prelude::Context;
 use serenity::utils::MessageBuilder;
 
-use crate::commands::giveaway::models::Giveaway as GiveawayInstance;
-use crate::commands::giveaway::utils::{periodic_giveaway_state_output, update_giveaway_message};
+use crate::commands::giveaway::latency::COMMAND_LATENCY;
+use crate::commands::giveaway::models::{
+    Giveaway as GiveawayInstance, ObjectState, MAX_GIVEAWAY_DURATION,
+    OUTPUT_AFTER_GIVEAWAY_COMMANDS, PENDING_REWARD_TIMEOUT,
+};
+use crate::commands::giveaway::reactions::ReactionAction;
+use crate::commands::giveaway::strategies::{EntryLotteryStrategy, WeightedRandomStrategy};
+use crate::commands::giveaway::utils::{
+    add_claim_reaction, claims_limit_output, decode_rewards_file, extract_rewards_from_message,
+    format_duration, is_giveaway_creator, is_server_booster, malformed_keys_warning,
+    median_and_average, parse_window_bound, periodic_giveaway_state_output, render_dashboard,
+    render_state_bar, split_message, update_giveaway_message, LEADERBOARD_SIZE,
+    MAX_IMPORT_FILE_SIZE, MAX_MESSAGE_LENGTH, STATE_BAR_WIDTH,
+};
 use crate::storage::GiveawayStorage;
 
 #[group]
 #[commands(
     // Giveaway management
     list_giveaways,
+    list_own_giveaways,
+    show_owner_dashboard,
     create_giveaway,
+    edit_giveaway,
+    set_empty_message,
+    set_claim_emoji,
     start_giveaway,
+    schedule_giveaway,
+    unschedule_giveaway,
     deactivate_giveaway,
     finish_giveaway,
+    set_blind_mode,
+    set_adaptive_output,
+    set_reveal_policy,
+    add_moderator,
+    toggle_cohost,
+    transfer_giveaway,
+    move_giveaway,
+    clone_giveaway,
+    import_giveaways,
 
     // Giveaway rewards management
     list_rewards,
+    list_grouped_rewards,
+    show_store_stats,
+    export_rewards_table,
+    export_rewards_tsv,
+    export_rewards_json,
+    peek_rewards,
     add_reward,
     add_multiple_rewards,
+    add_rewards_from_message,
+    import_rewards_from_file,
     remove_reward,
+    set_reward_note,
+    rename_reward,
+    expire_reward,
+    release_pending_rewards,
+    shuffle_rewards,
+    clear_rewards,
+    set_reward_dependency,
+    set_reward_claim_window,
+    list_duplicate_rewards,
 
     // Interaction with the giveaway
+    set_giveaway_alias,
     roll_reward,
+    reroll_reward,
     confirm_reward,
     deny_reward,
+    show_receipt,
+    enter_giveaway,
+    draw_winners,
+    list_claimed_rewards,
+    list_my_rewards,
+    count_rewards,
+    show_state_bar,
+    show_giveaway_status,
+    confirm_times,
+    announce_winners,
+    show_anonymous_stats,
+    list_winners,
+    simulate_giveaway,
+
+    // Bot statistics
+    show_metrics,
+    show_latency,
+    show_leaderboard,
+    validate_giveaways,
+    clean_stale_messages,
+    show_lock_stats,
+    set_reaction_emoji,
+    set_edit_rate,
 )]
 #[description = "Commands for managing giveaways"]
 #[help_available]
@@ -44,10 +119,9 @@ fn list_giveaways(ctx: &mut Context, msg: &Message) -> CommandResult {
         .expect("Expected GiveawayManager in ShareMap.");
 
     let giveaways = giveaway_manager
-        .get_giveaways()
+        .get_indexed_giveaways()
         .iter()
-        .enumerate()
-        .map(|(index, giveaway)| format!("{}. {}", index + 1, giveaway.pretty_print()))
+        .map(|(index, giveaway)| format!("{}. {}", index, giveaway.pretty_print()))
         .collect::<Vec<String>>();
 
     let content = match giveaways.len() {
@@ -61,15 +135,200 @@ fn list_giveaways(ctx: &mut Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
+#[command("gmine")]
+#[description = "Get a list of giveaways that you own"]
+fn list_own_giveaways(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let giveaways = giveaway_manager
+        .get_giveaways_by_owner(msg.author.id.0)
+        .iter()
+        .map(|(index, giveaway)| format!("{}. {}", index, giveaway.pretty_print()))
+        .collect::<Vec<String>>();
+
+    let content = match giveaways.len() {
+        0 => "You don't own any giveaways.".to_string(),
+        _ => format!("Giveaways:\n{}", giveaways.join("\n")),
+    };
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("gdashboard")]
+#[description = "Get a summary of all giveaways that you own"]
+fn show_owner_dashboard(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let dashboard = giveaway_manager.owner_dashboard(msg.author.id.0);
+    if dashboard.giveaways.is_empty() {
+        msg.channel_id
+            .say(&ctx.http, "You don't own any giveaways.")?;
+        return Ok(());
+    }
+
+    for part in split_message(&render_dashboard(&dashboard), MAX_MESSAGE_LENGTH) {
+        let message = MessageBuilder::new().push(part).build();
+        msg.channel_id.say(&ctx.http, message)?;
+    }
+
+    Ok(())
+}
+
 #[command("gcreate")]
 #[min_args(1)]
 #[help_available]
-#[usage("<description>")]
-#[example("My new Steam / EGS games giveaway.")]
-#[description = "Create a new giveaway"]
-fn create_giveaway(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
-    let description = args.message();
-    let giveaway = GiveawayInstance::new(&msg.author).with_description(description);
+#[usage(
+    "[--pause-after <claims>] [--limit <rewards>] [--duration <minutes>] [--cooldown <seconds>] [--auto-deny <minutes>] [--booster-reserve <percent>] [--output-every <actions>] [--lottery] [--weighted] <description>"
+)]
+#[example("--pause-after 10 --duration 60 My new Steam / EGS games giveaway.")]
+#[description = "Create a new giveaway. With the `--pause-after` option the giveaway will be paused after the given number of claims. With the `--limit` option the giveaway stops handing out the rewards after the given number of rewards. With the `--duration` option the giveaway will be finished automatically after the given number of minutes. With the `--cooldown` option each participant can roll no more often than once per the given number of seconds. With the `--auto-deny` option the pending rewards that weren't confirmed during the given number of minutes are returned to the giveaway. With the `--booster-reserve` option the given percent of rewards is reserved for the server boosters. With the `--output-every` option the state of the giveaway is printed after the given number of actions (15 by default). With the `--lottery` option the participants enter the giveaway and the owner draws the winners. With the `--weighted` option the rewards are rolled randomly in according with their `{weight=N}` tokens"]
+fn create_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    if !is_giveaway_creator(ctx, msg) {
+        msg.channel_id.say(
+            &ctx.http,
+            "You are not allowed to create giveaways on this server.",
+        )?;
+        return Ok(());
+    }
+
+    let mut pause_after_claims = None;
+    let mut hand_out_limit = None;
+    let mut duration = None;
+    let mut roll_cooldown = None;
+    let mut auto_deny_after = None;
+    let mut booster_reserved_fraction = 0.0;
+    let mut output_interval = OUTPUT_AFTER_GIVEAWAY_COMMANDS;
+    let mut lottery = false;
+    let mut weighted = false;
+    loop {
+        match args.current() {
+            Some("--pause-after") => match args.advance().single::<u64>() {
+                Ok(value) if value > 0 => pause_after_claims = Some(value),
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--pause-after` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--limit") => match args.advance().single::<u64>() {
+                Ok(value) if value > 0 => hand_out_limit = Some(value),
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--limit` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--duration") => match args.advance().single::<u64>() {
+                Ok(value) if value > 0 => {
+                    duration = Some(Duration::from_secs(value.saturating_mul(60)))
+                }
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--duration` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--cooldown") => match args.advance().single::<u64>() {
+                Ok(value) if value > 0 => roll_cooldown = Some(Duration::from_secs(value)),
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--cooldown` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--auto-deny") => match args.advance().single::<u64>() {
+                Ok(value) if value > 0 => {
+                    auto_deny_after = Some(Duration::from_secs(value.saturating_mul(60)))
+                }
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--auto-deny` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--booster-reserve") => match args.advance().single::<u8>() {
+                Ok(value) if value <= 100 => booster_reserved_fraction = value as f32 / 100.0,
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--booster-reserve` option for the `gcreate` command must be an integer between 0 and 100.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--output-every") => match args.advance().single::<u64>() {
+                Ok(value) if value >= 1 => output_interval = value,
+                _ => {
+                    msg.channel_id.say(
+                        &ctx.http,
+                        "The `--output-every` option for the `gcreate` command must be a positive integer.",
+                    )?;
+                    return Ok(());
+                }
+            },
+            Some("--lottery") => {
+                args.advance();
+                lottery = true;
+            }
+            Some("--weighted") => {
+                args.advance();
+                weighted = true;
+            }
+            _ => break,
+        }
+    }
+    if lottery && weighted {
+        msg.channel_id.say(
+            &ctx.http,
+            "The `--lottery` and `--weighted` options for the `gcreate` command can't be used together.",
+        )?;
+        return Ok(());
+    }
+    let description = args.rest();
+    let mut giveaway = GiveawayInstance::new(&msg.author)
+        .with_description(description)
+        .with_pause_after_claims(pause_after_claims)
+        .with_hand_out_limit(hand_out_limit)
+        .with_booster_reserved_fraction(booster_reserved_fraction)
+        .with_output_interval(output_interval);
+    if let Some(duration) = duration {
+        giveaway = giveaway.with_duration(duration);
+    }
+    if let Some(roll_cooldown) = roll_cooldown {
+        giveaway = giveaway.with_roll_cooldown(roll_cooldown);
+    }
+    if let Some(auto_deny_after) = auto_deny_after {
+        giveaway = giveaway.with_auto_deny_after(auto_deny_after);
+    }
+    if lottery {
+        giveaway = giveaway.with_strategy(Box::new(EntryLotteryStrategy::new()));
+    }
+    if weighted {
+        giveaway = giveaway.with_strategy(Box::new(WeightedRandomStrategy::new()));
+    }
 
     let giveaway_manager = ctx
         .data
@@ -79,30 +338,36 @@ fn create_giveaway(ctx: &mut Context, msg: &Message, args: Args) -> CommandResul
         .expect("Expected GiveawayManager in ShareMap.");
 
     giveaway_manager.add_giveaway(giveaway);
-    msg.channel_id
-        .say(&ctx.http, "The giveaway has been created!")?;
+    let content = match duration {
+        Some(duration) if duration > MAX_GIVEAWAY_DURATION => format!(
+            "The giveaway has been created! The duration has been reduced to the maximum of {} minutes.",
+            MAX_GIVEAWAY_DURATION.as_secs() / 60
+        ),
+        _ => format!("The giveaway has been created!"),
+    };
+    msg.channel_id.say(&ctx.http, content)?;
 
     Ok(())
 }
 
-#[command("gstart")]
-#[min_args(1)]
-#[max_args(1)]
+#[command("gedit")]
+#[min_args(2)]
 #[help_available]
-#[usage("<giveaway-number>")]
-#[example("1")]
-#[description = "Start the certain giveaway"]
-fn start_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <description>")]
+#[example("1 My new Steam / EGS games giveaway.")]
+#[description = "Change the description of the giveaway"]
+fn edit_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gstart` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gedit` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
+    let description = args.rest();
 
     let giveaway_manager = ctx
         .data
@@ -111,33 +376,40 @@ fn start_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandRe
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.activate_giveaway(&msg.author, index) {
-        Ok(_) => {
-            let response = giveaway_manager.pretty_print_giveaway(index)?;
-            msg.channel_id.say(&ctx.http, &response)?;
-        }
-        Err(err) => {
-            msg.channel_id.say(&ctx.http, format!("{}", err))?;
-        }
+    match giveaway_manager.update_giveaway_description(&msg.author, index, description) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The giveaway description has been updated.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gdeactivate")]
-#[min_args(1)]
-#[max_args(1)]
+#[command("gmod")]
+#[min_args(2)]
+#[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number>")]
-#[example("1")]
-#[description = "Deactivates the giveaway by the given number"]
-fn deactivate_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <@user>")]
+#[example("1 @Moderator")]
+#[description = "Allow another user to manage the giveaway. Only the owner can delete the giveaway"]
+fn add_moderator(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gdeactivate` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gmod` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let moderator = match msg.mentions.first() {
+        Some(user) => user,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `user` argument for the `gmod` command must be a mention of the user.",
             )?;
             return Ok(());
         }
@@ -150,30 +422,41 @@ fn deactivate_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> Comm
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.deactivate_giveaway(&msg.author, index) {
-        Ok(_) => msg
-            .channel_id
-            .say(&ctx.http, "The giveaway has been deactivated.")?,
+    match giveaway_manager.add_moderator(&msg.author, index, moderator.id.0) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            format!("<@{}> can manage the giveaway now.", moderator.id.0),
+        )?,
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gfinish")]
-#[min_args(1)]
-#[max_args(1)]
+#[command("gcohost")]
+#[min_args(2)]
+#[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number>")]
-#[example("1")]
-#[description = "Finishes and deletes the giveaway by the given number"]
-fn finish_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <@user>")]
+#[example("1 @Staff")]
+#[description = "Add the user to the co-hosts of the giveaway, who share the owner permissions, or remove the user if it's a co-host already"]
+fn toggle_cohost(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gfinish` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gcohost` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let cohost = match msg.mentions.first() {
+        Some(user) => user,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `user` argument for the `gcohost` command must be a mention of the user.",
             )?;
             return Ok(());
         }
@@ -186,30 +469,45 @@ fn finish_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandR
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.delete_giveaway(&msg.author, index) {
-        Ok(_) => msg
-            .channel_id
-            .say(&ctx.http, "The giveaway has been finished.")?,
+    match giveaway_manager.toggle_cohost(&msg.author, index, cohost.id.0) {
+        Ok(true) => msg.channel_id.say(
+            &ctx.http,
+            format!("<@{}> is a co-host of the giveaway now.", cohost.id.0),
+        )?,
+        Ok(false) => msg.channel_id.say(
+            &ctx.http,
+            format!("<@{}> is no longer a co-host of the giveaway.", cohost.id.0),
+        )?,
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gitems")]
-#[min_args(1)]
-#[max_args(1)]
+#[command("gtransfer")]
+#[min_args(2)]
+#[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number>")]
-#[example("1")]
-#[description = "Display detailed info about the rewards in the giveaway for the owner."]
-fn list_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <@user>")]
+#[example("1 @NewOwner")]
+#[description = "Hand the giveaway over to another user, who becomes its new owner"]
+fn transfer_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gitems` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gtransfer` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let new_owner = match msg.mentions.first() {
+        Some(user) => user,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `user` argument for the `gtransfer` command must be a mention of the user.",
             )?;
             return Ok(());
         }
@@ -222,55 +520,45 @@ fn list_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResu
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.get_giveaway_rewards(&msg.author, index) {
-        Ok(items) => {
-            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
-            let reward_formatter = giveaway.reward_formatter();
-            let content = match items.len() {
-                0 => "There are no added rewards.".to_string(),
-                _ => format!(
-                    "Rewards:\n{}",
-                    items
-                        .iter()
-                        .enumerate()
-                        .map(|(index, obj)| format!(
-                            "{}. {}",
-                            index + 1,
-                            reward_formatter.debug_print(obj)
-                        ))
-                        .collect::<Vec<String>>()
-                        .join("\n")
-                ),
-            };
-
-            let message = MessageBuilder::new().push(content).build();
-            msg.channel_id.say(&ctx.http, message)?
-        }
+    match giveaway_manager.transfer_ownership(&msg.author, index, new_owner) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            format!("<@{}> is the new owner of the giveaway.", new_owner.id.0),
+        )?,
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gadd")]
+#[command("gmove")]
 #[min_args(2)]
+#[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number> <description>")]
-#[example("1 Just a plain text with some description about the reward")]
-#[example("1 AAAAA-BBBBB-CCCCC-DDDD [Store name] -> Game name")]
-#[description = "Adds a new reward to the certain giveaway"]
-fn add_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <new-position>")]
+#[example("3 1")]
+#[description = "Move the giveaway to another position in the list of giveaways"]
+fn move_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gadd` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gmove` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let position = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `new-position` argument for the `gmove` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
-    let data = args.rest();
 
     let giveaway_manager = ctx
         .data
@@ -279,33 +567,76 @@ fn add_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.add_giveaway_reward(&msg.author, index, data) {
-        Ok(_) => msg
-            .channel_id
-            .say(&ctx.http, "The reward has been added to the giveaway.")?,
+    match giveaway_manager.reorder_giveaway(&msg.author, index, position) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            format!("The giveaway has been moved to #{}.", position),
+        )?,
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gaddm")]
+#[command("gimportall")]
+#[min_args(1)]
+#[owners_only]
+#[help_available]
+#[usage("<json-array>")]
+#[example("[{\"description\": \"Steam keys\", \"rewards\": [\"AAAAA-BBBBB-CCCCC\"]}]")]
+#[description = "Create multiple giveaways at once from a JSON array"]
+fn import_giveaways(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
+    let data = args
+        .message()
+        .trim()
+        .trim_start_matches("```json")
+        .trim_matches('`');
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.import_giveaways(&msg.author, data) {
+        Ok(report) => {
+            let mut content = format!(
+                "Imported giveaways: {}. Failed: {}.",
+                report.imported,
+                report.failed.len()
+            );
+            for (position, reason) in report.failed.iter() {
+                content.push_str(&format!("\n#{}: {}", position, reason));
+            }
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gempty-msg")]
 #[min_args(2)]
 #[help_available]
-#[usage("<giveaway-number> <description>")]
-#[description = "Adds a new reward to the certain giveaway, parsed from the single message. The separator for rewards is the new line"]
-fn add_multiple_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <message>")]
+#[example("1 Rewards will be added at 18:00 UTC!")]
+#[description = "Change the message shown while the giveaway doesn't have any rewards"]
+fn set_empty_message(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gaddm` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gempty-msg` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
-    let data = args.rest();
+    let message = args.rest();
 
     let giveaway_manager = ctx
         .data
@@ -314,40 +645,42 @@ fn add_multiple_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> Com
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.add_multiple_giveaway_rewards(&msg.author, index, data) {
-        Ok(_) => msg
-            .channel_id
-            .say(&ctx.http, "The reward has been added to the giveaway.")?,
+    match giveaway_manager.set_giveaway_empty_message(&msg.author, index, message) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            "The placeholder for the empty giveaway has been updated.",
+        )?,
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("gremove")]
+#[command("gclaimemoji")]
 #[min_args(2)]
 #[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number> <reward-to-remove>")]
-#[example("1 1")]
-#[description = "Removes the reward from the certain giveaway"]
-fn remove_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <emoji|off>")]
+#[example("1 🎉")]
+#[description = "Set the emoji which the bot puts on the giveaway message after each claimed reward"]
+fn set_claim_emoji(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gremove` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gclaimemoji` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
-    let reward_index = match args.single::<usize>() {
-        Ok(value) => value,
+    let emoji = match args.single::<String>() {
+        Ok(ref value) if value == "off" => None,
+        Ok(value) => Some(value),
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `reward-to-remove` argument for the `gremove` command must be a positive integer.",
+                "The `emoji` argument for the `gclaimemoji` command must be an emoji or `off`.",
             )?;
             return Ok(());
         }
@@ -360,33 +693,43 @@ fn remove_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandRes
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.remove_giveaway_reward(&msg.author, index, reward_index) {
-        Ok(_) => msg
-            .channel_id
-            .say(&ctx.http, "The reward has been removed from the giveaway.")?,
+    match giveaway_manager.set_giveaway_claim_emoji(&msg.author, index, emoji.as_deref()) {
+        Ok(_) => {
+            let response = match emoji {
+                Some(_) => "The claim emoji has been set for the giveaway.",
+                None => "The claim emoji has been disabled for the giveaway.",
+            };
+            msg.channel_id.say(&ctx.http, response)?
+        }
         Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
     Ok(())
 }
 
-#[command("groll")]
+#[command("gstart")]
 #[min_args(1)]
+#[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number> <reward-number>")]
-#[example("1 1")]
-#[description = "Roll the reward from the certain giveaway"]
-fn roll_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> [force]")]
+#[example("1")]
+#[example("1 force")]
+#[description = "Start the certain giveaway. Only one active giveaway per channel is allowed, unless the `force` option was specified"]
+fn start_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `groll` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gstart` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
+    let force = match args.single::<String>() {
+        Ok(value) => value == "force",
+        Err(_) => false,
+    };
 
     let giveaway_manager = ctx
         .data
@@ -395,47 +738,43 @@ fn roll_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResul
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.roll_reward(&msg.author, index, args.rest()) {
-        Ok(response) => match response {
-            Some(reward) => {
-                msg.channel_id.say(&ctx.http, &reward)?;
-            }
-            None => (),
-        },
+    match giveaway_manager.activate_giveaway(&msg.author, index, msg.channel_id, force) {
+        Ok(_) => {
+            let response = giveaway_manager.pretty_print_giveaway(index)?;
+            msg.channel_id.say(&ctx.http, &response)?;
+        }
         Err(err) => {
             msg.channel_id.say(&ctx.http, format!("{}", err))?;
         }
     };
 
-    update_giveaway_message(ctx, msg, &giveaway_manager, index);
-    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
     Ok(())
 }
 
-#[command("gconfirm")]
+#[command("gschedule")]
 #[min_args(2)]
 #[max_args(2)]
 #[help_available]
-#[usage("<giveaway-number> <reward-number>")]
-#[example("1 1")]
-#[description = "Confirm that the reward was activated from the certain giveaway"]
-fn confirm_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number> <minutes>")]
+#[example("1 60")]
+#[description = "Starts the paused giveaway automatically in the current channel after the given number of minutes"]
+fn schedule_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `giveaway-number` argument for the `gconfirm` command must be a positive integer.",
+                "The `giveaway-number` argument for the `gschedule` command must be a positive integer.",
             )?;
             return Ok(());
         }
     };
-    let reward_index = match args.single::<usize>() {
-        Ok(value) => value,
-        Err(_) => {
+    let delay = match args.single::<u64>() {
+        Ok(value) if value > 0 => Duration::from_secs(value.saturating_mul(60)),
+        _ => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `reward-number` argument for the `gconfirm` command must be a positive integer.",
+                "The `minutes` argument for the `gschedule` command must be a positive integer.",
             )?;
             return Ok(());
         }
@@ -448,26 +787,1632 @@ fn confirm_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandRe
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.confirm_reward(&msg.author, index, reward_index) {
-        Ok(_) => (),
-        Err(err) => {
-            msg.reply(&ctx.http, format!("{}", err))?;
-        }
+    let start_at = Instant::now() + delay.min(MAX_GIVEAWAY_DURATION);
+    match giveaway_manager.schedule_giveaway(&msg.author, index, msg.channel_id, start_at) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The start of the giveaway has been scheduled.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
     };
 
-    update_giveaway_message(ctx, msg, &giveaway_manager, index);
-    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
     Ok(())
 }
 
-#[command("gdeny")]
-#[min_args(2)]
-#[max_args(2)]
+#[command("gunschedule")]
+#[min_args(1)]
+#[max_args(1)]
 #[help_available]
-#[usage("<giveaway-number> <reward-number>")]
-#[example("1 1")]
-#[description = "Return the reward back that can't be activated"]
-fn deny_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Cancels the scheduled start of the giveaway. The giveaway stays paused"]
+fn unschedule_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gunschedule` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.cancel_schedule(&msg.author, index) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            "The scheduled start of the giveaway has been cancelled.",
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gclone")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Creates a new giveaway with the same description, strategy and rewards as the given one, but without any claimed rewards"]
+fn clone_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gclone` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.clone_giveaway(&msg.author, index) {
+        Ok(new_index) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "The giveaway has been cloned as the giveaway #{}.",
+                new_index
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gdeactivate")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Deactivates the giveaway by the given number"]
+fn deactivate_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gdeactivate` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.deactivate_giveaway(&msg.author, index) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The giveaway has been deactivated.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gfinish")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Finishes and deletes the giveaway by the given number"]
+fn finish_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gfinish` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.delete_giveaway(&msg.author, index) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The giveaway has been finished.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gblind")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <on|off>")]
+#[example("1 on")]
+#[description = "Hides any details about the rewards in the giveaway until they were activated"]
+fn set_blind_mode(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gblind` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let blind = match args.single::<String>() {
+        Ok(ref value) if value == "on" => true,
+        Ok(ref value) if value == "off" => false,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `mode` argument for the `gblind` command must be `on` or `off`.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_giveaway_blind_mode(&msg.author, index, blind) {
+        Ok(_) => {
+            let response = match blind {
+                true => "The blind mode has been enabled for the giveaway.",
+                false => "The blind mode has been disabled for the giveaway.",
+            };
+            msg.channel_id.say(&ctx.http, response)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gadaptive")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <on|off>")]
+#[example("1 on")]
+#[description = "Prints the giveaway state less often while the participants are active and more often when they aren't"]
+fn set_adaptive_output(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gadaptive` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let enabled = match args.single::<String>() {
+        Ok(ref value) if value == "on" => true,
+        Ok(ref value) if value == "off" => false,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `mode` argument for the `gadaptive` command must be `on` or `off`.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_giveaway_adaptive_output(&msg.author, index, enabled) {
+        Ok(_) => {
+            let response = match enabled {
+                true => "The adaptive output has been enabled for the giveaway.",
+                false => "The adaptive output has been disabled for the giveaway.",
+            };
+            msg.channel_id.say(&ctx.http, response)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("greveal-policy")]
+#[min_args(3)]
+#[max_args(3)]
+#[help_available]
+#[usage("<giveaway-number> <unused|pending|activated> <segments|all|default>")]
+#[example("1 pending 2")]
+#[description = "Sets how many segments of the keys are revealed for the rewards in the certain state"]
+fn set_reveal_policy(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `greveal-policy` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let state = match args.single::<String>() {
+        Ok(ref value) if value == "unused" => ObjectState::Unused,
+        Ok(ref value) if value == "pending" => ObjectState::Pending,
+        Ok(ref value) if value == "activated" => ObjectState::Activated,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `state` argument for the `greveal-policy` command must be `unused`, `pending` or `activated`.",
+            )?;
+            return Ok(());
+        }
+    };
+    let segments = match args.single::<String>() {
+        Ok(ref value) if value == "all" => Some(usize::MAX),
+        Ok(ref value) if value == "default" => None,
+        Ok(ref value) if value.parse::<usize>().is_ok() => value.parse::<usize>().ok(),
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `segments` argument for the `greveal-policy` command must be a non-negative integer, `all` or `default`.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_giveaway_reveal_policy(&msg.author, index, state, segments) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            "The reveal policy has been updated for the giveaway.",
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gitems")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display detailed info about the rewards in the giveaway for the owner."]
+fn list_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gitems` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_giveaway_rewards(&msg.author, index) {
+        Ok(items) => {
+            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
+            let reward_formatter = giveaway.reward_formatter();
+            let content = match items.len() {
+                0 => "There are no added rewards.".to_string(),
+                _ => format!(
+                    "Rewards:\n{}",
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, obj)| format!(
+                            "{}. {}",
+                            index + 1,
+                            reward_formatter.debug_print(obj)
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("ggrouped")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the state of the giveaway with the rewards grouped by keys, links and other rewards"]
+fn list_grouped_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `ggrouped` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.pretty_print_giveaway_grouped(&msg.author, index) {
+        Ok(response) => msg.channel_id.say(&ctx.http, &response)?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gstorestats")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display how many rewards were claimed and how many are remaining per store"]
+fn show_store_stats(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gstorestats` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.store_claim_stats(index) {
+        Ok(stats) if stats.is_empty() => msg
+            .channel_id
+            .say(&ctx.http, "The giveaway doesn't have any rewards.")?,
+        Ok(stats) => {
+            let response = stats
+                .iter()
+                .map(|(store, claimed, remaining)| {
+                    format!("{}: {} claimed, {} remaining", store, claimed, remaining)
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            msg.channel_id.say(&ctx.http, &response)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gtable")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the rewards in the giveaway as a plain text table for the owner."]
+fn export_rewards_table(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gtable` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.export_giveaway_table(&msg.author, index) {
+        Ok(content) => msg.channel_id.say(&ctx.http, content)?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gtsv")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Export the rewards in the giveaway as a TSV file for the spreadsheets."]
+fn export_rewards_tsv(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gtsv` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.export_giveaway_tsv(&msg.author, index) {
+        Ok(content) => {
+            let filename = format!("giveaway-{}.tsv", index);
+            msg.channel_id.send_message(&ctx.http, |m| {
+                m.content(format!("Rewards of the giveaway #{}:", index));
+                m.add_file((content.as_bytes(), filename.as_str()));
+                m
+            })?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gjson")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Export the rewards in the giveaway as a JSON file for the dashboards."]
+fn export_rewards_json(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gjson` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.export_giveaway_json(&msg.author, index) {
+        Ok(content) => {
+            let filename = format!("giveaway-{}.json", index);
+            msg.channel_id.send_message(&ctx.http, |m| {
+                m.content(format!("Rewards of the giveaway #{}:", index));
+                m.add_file((content.as_bytes(), filename.as_str()));
+                m
+            })?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gpeek")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Send the unmasked rewards of the giveaway to the owner in direct messages."]
+fn peek_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gpeek` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_giveaway_rewards(&msg.author, index) {
+        Ok(items) => {
+            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
+            let reward_formatter = giveaway.reward_formatter();
+            let content = match items.len() {
+                0 => format!("There are no added rewards in the giveaway #{}.", index),
+                _ => format!(
+                    "Rewards of the giveaway #{}:\n{}",
+                    index,
+                    items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, obj)| format!(
+                            "{}. {}",
+                            index + 1,
+                            reward_formatter.debug_print(obj)
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            let is_sent = msg
+                .author
+                .create_dm_channel(&ctx.http)
+                .and_then(|channel| channel.say(&ctx.http, &message));
+            match is_sent {
+                Ok(_) => msg.channel_id.say(&ctx.http, "Check your DMs.")?,
+                Err(_) => msg.channel_id.say(
+                    &ctx.http,
+                    "Can't send you a direct message. Please, allow direct messages from the server members and try again.",
+                )?,
+            }
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gadd")]
+#[min_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <description>")]
+#[example("1 Just a plain text with some description about the reward")]
+#[example("1 AAAAA-BBBBB-CCCCC-DDDD [Store name] -> Game name")]
+#[description = "Adds a new reward to the certain giveaway"]
+fn add_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gadd` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let data = args.rest();
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.add_giveaway_reward(&msg.author, index, data) {
+        Ok(false) => msg
+            .channel_id
+            .say(&ctx.http, "The reward has been added to the giveaway.")?,
+        Ok(true) => msg.channel_id.say(
+            &ctx.http,
+            "The reward has been added to the giveaway; it may be a malformed key.",
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gaddm")]
+#[min_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <description>")]
+#[description = "Adds a new reward to the certain giveaway, parsed from the single message. The separator for rewards is the new line"]
+fn add_multiple_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gaddm` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let data = args.rest();
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.add_multiple_giveaway_rewards(&msg.author, index, data) {
+        Ok((added, skipped, malformed)) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "Added {} reward(s) to the giveaway, skipped {} duplicate(s).{}",
+                added,
+                skipped,
+                malformed_keys_warning(malformed)
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gaddreply")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <message-id>")]
+#[example("1 123456789012345678")]
+#[description = "Adds rewards to the certain giveaway from the existing message in the channel. Each line of the message is a separate reward"]
+fn add_rewards_from_message(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gaddreply` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let message_id = match args.single::<u64>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `message-id` argument for the `gaddreply` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let source_message = match msg.channel_id.message(&ctx.http, message_id) {
+        Ok(message) => message,
+        Err(_) => {
+            msg.channel_id
+                .say(&ctx.http, "The requested message was not found.")?;
+            return Ok(());
+        }
+    };
+
+    let rewards = extract_rewards_from_message(&source_message.content);
+    if rewards.is_empty() {
+        msg.channel_id.say(
+            &ctx.http,
+            "The requested message doesn't contain any rewards.",
+        )?;
+        return Ok(());
+    }
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.add_multiple_giveaway_rewards(&msg.author, index, &rewards.join("\n")) {
+        Ok((added, skipped, malformed)) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "Added {} reward(s) to the giveaway, skipped {} duplicate(s).{}",
+                added,
+                skipped,
+                malformed_keys_warning(malformed)
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gimport")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Adds rewards to the certain giveaway from the attached text file. Each line of the file is a separate reward"]
+fn import_rewards_from_file(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gimport` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let attachment = match msg.attachments.first() {
+        Some(attachment) => attachment,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "Attach a text file with the rewards to the `gimport` command.",
+            )?;
+            return Ok(());
+        }
+    };
+    if attachment.size > MAX_IMPORT_FILE_SIZE {
+        msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "The attached file is too large. The maximum size is {} KB.",
+                MAX_IMPORT_FILE_SIZE / 1024
+            ),
+        )?;
+        return Ok(());
+    }
+
+    let content = match attachment
+        .download()
+        .ok()
+        .and_then(|data| decode_rewards_file(&data))
+    {
+        Some(content) => content,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The attached file can't be read. It must be a UTF-8 text file.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let rewards = extract_rewards_from_message(&content);
+    if rewards.is_empty() {
+        msg.channel_id
+            .say(&ctx.http, "The attached file doesn't contain any rewards.")?;
+        return Ok(());
+    }
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.add_multiple_giveaway_rewards(&msg.author, index, &rewards.join("\n")) {
+        Ok((added, skipped, malformed)) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "Imported {} reward(s) to the giveaway, skipped {} duplicate(s).{}",
+                added,
+                skipped,
+                malformed_keys_warning(malformed)
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gremove")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <reward-to-remove>")]
+#[example("1 1")]
+#[description = "Removes the reward from the certain giveaway"]
+fn remove_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gremove` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-to-remove` argument for the `gremove` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.remove_giveaway_reward(&msg.author, index, reward_index) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The reward has been removed from the giveaway.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("grename")]
+#[min_args(3)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number> <description>")]
+#[example("1 2 Some game (Steam)")]
+#[description = "Change the description of the reward, without removing it from the giveaway"]
+fn rename_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `grename` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `grename` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let description = args.rest();
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.update_giveaway_reward_description(
+        &msg.author,
+        index,
+        reward_index,
+        description,
+    ) {
+        Ok(_) => {
+            msg.channel_id
+                .say(&ctx.http, "The reward description has been updated.")?;
+            update_giveaway_message(msg, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    Ok(())
+}
+
+#[command("gnote")]
+#[min_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number> [note]")]
+#[example("1 2 From the Humble bundle")]
+#[example("1 2")]
+#[description = "Set the note of the reward, visible only to the owner and the co-hosts in the `gitems` output. Without the note the existing one is removed"]
+fn set_reward_note(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gnote` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gnote` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let note = args.rest();
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_reward_owner_note(&msg.author, index, reward_index, note) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The reward note has been updated.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gexpire")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number>")]
+#[example("1 1")]
+#[description = "Marks the reward in the giveaway as expired, when it doesn't work anymore"]
+fn expire_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gexpire` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gexpire` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.expire_reward(&msg.author, index, reward_index) {
+        Ok(_) => {
+            msg.channel_id
+                .say(&ctx.http, "The reward has been marked as expired.")?;
+            update_giveaway_message(msg, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    Ok(())
+}
+
+#[command("grelease")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Returns the rewards that stayed pending for too long back to the giveaway"]
+fn release_pending_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `grelease` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let timeout = ChronoDuration::from_std(PENDING_REWARD_TIMEOUT)
+        .expect("Expected a valid timeout for the pending rewards.");
+    match giveaway_manager.release_expired_pending(&msg.author, index, Utc::now(), timeout) {
+        Ok(0) => {
+            msg.channel_id
+                .say(&ctx.http, "There are no stale pending rewards.")?;
+        }
+        Ok(released) => {
+            msg.channel_id.say(
+                &ctx.http,
+                format!("Released {} pending reward(s).", released),
+            )?;
+            update_giveaway_message(msg, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    Ok(())
+}
+
+#[command("gshuffle")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Randomize the order of the rewards in the certain giveaway before starting it"]
+fn shuffle_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gshuffle` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.shuffle_rewards(&msg.author, index) {
+        Ok(_) => msg
+            .channel_id
+            .say(&ctx.http, "The rewards have been shuffled.")?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gclear")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Removes all rewards from the certain giveaway"]
+fn clear_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gclear` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.clear_giveaway_rewards(&msg.author, index) {
+        Ok(removed) => msg.channel_id.say(
+            &ctx.http,
+            format!("Removed {} reward(s) from the giveaway.", removed),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gdepend")]
+#[min_args(3)]
+#[max_args(3)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number> <prerequisite-number>")]
+#[example("1 2 1")]
+#[description = "Makes the reward available only after activating the prerequisite reward"]
+fn set_reward_dependency(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gdepend` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gdepend` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let prerequisite_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `prerequisite-number` argument for the `gdepend` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_reward_dependency(
+        &msg.author,
+        index,
+        reward_index,
+        prerequisite_index,
+    ) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "The reward #{} will be available after activating the reward #{}.",
+                reward_index, prerequisite_index
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gwindow")]
+#[min_args(4)]
+#[max_args(4)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number> <from> <until>")]
+#[example("1 2 2024-01-01T18:00 2024-01-01T20:00")]
+#[description = "Limits the time when the reward can be claimed. The time is in UTC, `-` leaves the side of the window open"]
+fn set_reward_claim_window(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gwindow` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gwindow` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let from = match args
+        .single::<String>()
+        .ok()
+        .and_then(|value| parse_window_bound(&value))
+    {
+        Some(value) => value,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `from` argument for the `gwindow` command must be a time in the `YYYY-MM-DDTHH:MM` format or `-`.",
+            )?;
+            return Ok(());
+        }
+    };
+    let until = match args
+        .single::<String>()
+        .ok()
+        .and_then(|value| parse_window_bound(&value))
+    {
+        Some(value) => value,
+        None => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `until` argument for the `gwindow` command must be a time in the `YYYY-MM-DDTHH:MM` format or `-`.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_reward_claim_window(&msg.author, index, reward_index, from, until) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            "The claiming window of the reward has been updated.",
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gdupes")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the rewards that were added to the giveaway more than once"]
+fn list_duplicate_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gdupes` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.duplicate_reward_values(&msg.author, index) {
+        Ok(duplicates) => {
+            let content = match duplicates.len() {
+                0 => "There are no duplicated rewards.".to_string(),
+                _ => format!(
+                    "Duplicated rewards:\n{}",
+                    duplicates
+                        .iter()
+                        .map(|(value, indices)| format!(
+                            "{} [rewards: {}]",
+                            value,
+                            indices
+                                .iter()
+                                .map(|index| index.to_string())
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gai")]
+#[min_args(3)]
+#[max_args(3)]
+#[help_available]
+#[usage("alias <name> <giveaway-number>")]
+#[example("alias fav 1")]
+#[description = "Define a short name of the giveaway, that can be used instead of its number in the `groll` command"]
+fn set_giveaway_alias(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    match args.single::<String>() {
+        Ok(ref value) if value == "alias" => (),
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `gai` command supports only the `alias` action.",
+            )?;
+            return Ok(());
+        }
+    };
+    let name = args.single::<String>().unwrap_or_default();
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gai` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.set_giveaway_alias(&msg.author, &name, index) {
+        Ok(_) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "The `{}` alias now refers to the giveaway #{}.",
+                name, index
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("groll")]
+#[min_args(1)]
+#[help_available]
+#[usage("<giveaway-number|alias> <reward-number>")]
+#[example("1 1")]
+#[description = "Roll the reward from the certain giveaway"]
+fn roll_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let raw_index = args.single::<String>().unwrap_or_default();
+    let index = match giveaway_manager.resolve_giveaway_index(&msg.author, &raw_index) {
+        Ok(value) => value,
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+            return Ok(());
+        }
+    };
+
+    let is_booster = is_server_booster(ctx, msg);
+    match giveaway_manager.roll_reward(&msg.author, index, args.rest(), is_booster) {
+        Ok(response) => {
+            match response {
+                Some(reward) => {
+                    msg.channel_id.say(&ctx.http, &reward)?;
+                }
+                None => (),
+            };
+            claims_limit_output(&ctx.http, msg.channel_id, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    update_giveaway_message(msg, &giveaway_manager, index);
+    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("greroll")]
+#[min_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <pending-reward-number> <reward-number>")]
+#[example("1 1 2")]
+#[description = "Return the pending reward back and roll a new one from the certain giveaway"]
+fn reroll_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `greroll` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `pending-reward-number` argument for the `greroll` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let is_booster = is_server_booster(ctx, msg);
+    match giveaway_manager.reroll_reward(&msg.author, index, reward_index, args.rest(), is_booster)
+    {
+        Ok(response) => {
+            match response {
+                Some(reward) => {
+                    msg.channel_id.say(&ctx.http, &reward)?;
+                }
+                None => (),
+            };
+            claims_limit_output(&ctx.http, msg.channel_id, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    update_giveaway_message(msg, &giveaway_manager, index);
+    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("gconfirm")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number>")]
+#[example("1 1")]
+#[description = "Confirm that the reward was activated from the certain giveaway"]
+fn confirm_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gconfirm` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gconfirm` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.confirm_reward_detailed(&msg.author, index, reward_index) {
+        Ok((reward, receipt_id)) => {
+            println!(
+                "The reward #{} in the giveaway #{} was activated by {}. Receipt: {}",
+                reward_index, index, msg.author.id.0, receipt_id
+            );
+            let activated = match reward.description() {
+                Some(description) if !description.trim().is_empty() => {
+                    description.trim().to_string()
+                }
+                _ => reward.value().to_string(),
+            };
+            let content = format!(
+                "You've activated: {}\nThe reward #{} in the giveaway #{}. Receipt: {}",
+                activated, reward_index, index, receipt_id
+            );
+            let is_sent = msg
+                .author
+                .create_dm_channel(&ctx.http)
+                .and_then(|channel| channel.say(&ctx.http, &content));
+            if is_sent.is_err() {
+                msg.reply(&ctx.http, &content)?;
+            }
+            add_claim_reaction(ctx, &giveaway_manager, index);
+        }
+        Err(err) => {
+            msg.reply(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    update_giveaway_message(msg, &giveaway_manager, index);
+    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("gdeny")]
+#[min_args(2)]
+#[max_args(2)]
+#[help_available]
+#[usage("<giveaway-number> <reward-number>")]
+#[example("1 1")]
+#[description = "Return the reward back that can't be activated"]
+fn deny_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
     let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
@@ -478,12 +2423,690 @@ fn deny_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResul
             return Ok(());
         }
     };
-    let reward_index = match args.single::<usize>() {
+    let reward_index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `reward-number` argument for the `gdeny` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.deny_reward(&msg.author, index, reward_index) {
+        Ok(_) => (),
+        Err(err) => {
+            msg.reply(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    update_giveaway_message(msg, &giveaway_manager, index);
+    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("greceipt")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<receipt-id>")]
+#[example("1A2B3C4D")]
+#[description = "Look up the receipt of the activated reward in the giveaways that you manage"]
+fn show_receipt(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let receipt_id = match args.single::<String>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `receipt-id` argument for the `greceipt` command must be specified.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let content = match giveaway_manager.find_receipt(&msg.author, &receipt_id) {
+        Ok((index, reward_index, receipt)) => {
+            let reward = match reward_index {
+                Some(reward_index) => format!("The reward #{}", reward_index),
+                None => format!("The removed reward"),
+            };
+            format!(
+                "Receipt {}: {} in the giveaway #{} was activated by <@{}> at {}.",
+                receipt.id(),
+                reward,
+                index,
+                receipt.user_id(),
+                receipt.created_at().format("%Y-%m-%d %H:%M:%S UTC")
+            )
+        }
+        Err(err) => format!("{}", err),
+    };
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("genter")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Enter the certain giveaway, which draws the winners among the entrants"]
+fn enter_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `genter` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.enter_giveaway(&msg.author, index) {
+        Ok(_) => msg.reply(&ctx.http, "You have entered the giveaway.")?,
+        Err(err) => msg.reply(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gdraw")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Hand out the unused rewards to the randomly picked entrants of the certain giveaway"]
+fn draw_winners(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gdraw` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.draw_giveaway(&msg.author, index) {
+        Ok(winners) => {
+            let content = match winners.len() {
+                0 => format!("Nobody has won a reward in the giveaway #{}.", index),
+                _ => {
+                    let lines = winners
+                        .iter()
+                        .map(|(user_id, reward_index)| {
+                            format!("<@{}> won the reward #{}", user_id, reward_index)
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n");
+                    format!("Winners of the draw in the giveaway #{}:\n{}", index, lines)
+                }
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?;
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    };
+
+    update_giveaway_message(msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("gme")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Send your pending and claimed rewards of the giveaway in direct messages"]
+fn list_my_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gme` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_user_rewards(&msg.author, index) {
+        Ok((pending, retrieved)) => {
+            let format_list = |items: Vec<String>| match items.is_empty() {
+                true => format!(" none"),
+                false => format!("\n{}", items.join("\n")),
+            };
+            let content = format!(
+                "Your rewards in the giveaway #{}:\nPending:{}\nClaimed:{}",
+                index,
+                format_list(pending),
+                format_list(retrieved)
+            );
+
+            let message = MessageBuilder::new().push(content).build();
+            let is_sent = msg
+                .author
+                .create_dm_channel(&ctx.http)
+                .and_then(|channel| channel.say(&ctx.http, &message));
+            match is_sent {
+                Ok(_) => msg.channel_id.say(&ctx.http, "Check your DMs.")?,
+                Err(_) => msg.channel_id.say(
+                    &ctx.http,
+                    "Can't send you a direct message. Please, allow direct messages from the server members and try again.",
+                )?,
+            }
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gcount")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display how many rewards are available, pending and claimed in the giveaway"]
+fn count_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gcount` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_reward_counts(index) {
+        Ok((unused, pending, activated)) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "Available: {}, Pending: {}, Claimed: {}",
+                unused, pending, activated
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gstatus")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display a short status of the giveaway without the list of rewards"]
+fn show_giveaway_status(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gstatus` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.giveaway_summary(index) {
+        Ok(response) => msg.channel_id.say(&ctx.http, &response)?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gspark")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the share of claimed, pending and available rewards in the giveaway as a bar"]
+fn show_state_bar(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gspark` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_reward_counts(index) {
+        Ok((unused, pending, activated)) => msg.channel_id.say(
+            &ctx.http,
+            format!(
+                "`{}`\n█ Claimed: {} ▒ Pending: {} ░ Available: {}",
+                render_state_bar(unused, pending, activated, STATE_BAR_WIDTH),
+                activated,
+                pending,
+                unused
+            ),
+        )?,
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gconfirmtimes")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display how long the participants took to confirm the rolled rewards"]
+fn confirm_times(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gconfirmtimes` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_confirm_times(&msg.author, index) {
+        Ok(items) => {
+            let durations = items
+                .iter()
+                .map(|(_, latency)| *latency)
+                .collect::<Vec<_>>();
+            let content = match median_and_average(&durations) {
+                None => "Nobody has confirmed any rewards yet.".to_string(),
+                Some((median, average)) => format!(
+                    "Median confirm time: {}\nAverage confirm time: {}\n{}",
+                    format_duration(&median),
+                    format_duration(&average),
+                    items
+                        .iter()
+                        .map(|(user_id, latency)| format!(
+                            "- <@{}>: {}",
+                            user_id,
+                            format_duration(latency)
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, &message)?;
+        }
+        Err(err) => {
+            msg.channel_id.say(&ctx.http, format!("{}", err))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[command("gclaimed")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the taken rewards in the giveaway with the time when they were taken"]
+fn list_claimed_rewards(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gclaimed` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_claimed_rewards(index) {
+        Ok(items) => {
+            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
+            let content = match items.len() {
+                0 => "Nobody has taken any rewards yet.".to_string(),
+                _ => format!(
+                    "Claimed rewards:\n{}",
+                    items
+                        .iter()
+                        .map(|(reward_index, obj, claimed_at)| format!(
+                            "{}. {} [taken at {}]",
+                            reward_index,
+                            giveaway.pretty_print_reward(obj),
+                            claimed_at.format("%Y-%m-%d %H:%M:%S UTC")
+                        ))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                ),
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("ganonstats")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display the aggregated participation numbers of the giveaway without mentioning anyone"]
+fn show_anonymous_stats(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `ganonstats` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.anonymous_stats(index) {
+        Ok(stats) => {
+            let content = format!(
+                "Participants: {}\nClaims: {}\nRewards remaining: {}\nAverage claims per participant: {:.2}",
+                stats.participants, stats.claims, stats.rewards_remaining, stats.average_claims
+            );
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gtop")]
+#[max_args(0)]
+#[help_available]
+#[description = "Show the users who got the most rewards across all giveaways"]
+fn show_leaderboard(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let leaderboard = giveaway_manager.global_leaderboard();
+    let content = match leaderboard.is_empty() {
+        true => format!("Nobody has got any rewards yet."),
+        false => leaderboard
+            .iter()
+            .take(LEADERBOARD_SIZE)
+            .enumerate()
+            .map(|(position, (user_id, count))| {
+                format!("{}. <@{}> — {} rewards", position + 1, user_id, count)
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+    };
+    msg.channel_id.say(&ctx.http, content)?;
+
+    Ok(())
+}
+
+#[command("gmetrics")]
+#[max_args(1)]
+#[owners_only]
+#[help_available]
+#[usage("[--reset]")]
+#[example("--reset")]
+#[description = "Show the number of rolled, confirmed and denied rewards across all giveaways"]
+fn show_metrics(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let reset = match args.single::<String>() {
+        Ok(ref value) if value == "--reset" => true,
+        Ok(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `gmetrics` command accepts only the `--reset` option.",
+            )?;
+            return Ok(());
+        }
+        Err(_) => false,
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .write()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let metrics = giveaway_manager.metrics();
+    let snapshot = match reset {
+        true => metrics.reset(),
+        false => metrics.read(),
+    };
+    let mut content = format!(
+        "Rolls: {}\nConfirms: {}\nDenies: {}",
+        snapshot.rolls, snapshot.confirms, snapshot.denies
+    );
+    if reset {
+        content.push_str("\nThe counters have been reset.");
+    }
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("glatency")]
+#[max_args(0)]
+#[owners_only]
+#[help_available]
+#[description = "Show the average and maximum handling time of each command since the bot start"]
+fn show_latency(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let latency = COMMAND_LATENCY.read();
+    let content = match latency.is_empty() {
+        true => format!("No commands have been handled yet."),
+        false => latency
+            .iter()
+            .map(|(command, snapshot)| {
+                format!(
+                    "{}: avg {} ms, max {} ms ({} calls)",
+                    command,
+                    snapshot.average.as_millis(),
+                    snapshot.max.as_millis(),
+                    snapshot.calls
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+    };
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("glockstats")]
+#[min_args(1)]
+#[max_args(1)]
+#[owners_only]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Show the average and maximum wait time for the lock on the rewards of the certain giveaway"]
+fn show_lock_stats(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
         Ok(value) => value,
         Err(_) => {
             msg.channel_id.say(
                 &ctx.http,
-                "The `reward-number` argument for the `gdeny` command must be a positive integer.",
+                "The `giveaway-number` argument for the `glockstats` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let content = match giveaway_manager.get_lock_stats(index) {
+        Ok(Some(stats)) => format!(
+            "Lock acquisitions: {}\nAverage wait: {:?}\nMax wait: {:?}",
+            stats.acquisitions, stats.average_wait, stats.max_wait
+        ),
+        Ok(None) => format!(
+            "The locks profiling is disabled. Set the `PROFILE_LOCKS` environment variable for enabling it."
+        ),
+        Err(err) => format!("{}", err),
+    };
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("greactionconfig")]
+#[min_args(2)]
+#[max_args(2)]
+#[owners_only]
+#[help_available]
+#[usage("<roll|confirm|deny> <emoji>")]
+#[example("roll 🎉")]
+#[description = "Change the emoji used for rolling, confirming or denying the rewards via reactions"]
+fn set_reaction_emoji(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let action = match args.single::<String>() {
+        Ok(value) => match ReactionAction::from_name(&value) {
+            Some(action) => action,
+            None => {
+                msg.channel_id.say(
+                    &ctx.http,
+                    "The `action` argument for the `greactionconfig` command must be one of `roll`, `confirm` or `deny`.",
+                )?;
+                return Ok(());
+            }
+        },
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `action` argument for the `greactionconfig` command must be one of `roll`, `confirm` or `deny`.",
+            )?;
+            return Ok(());
+        }
+    };
+    let emoji = match args.single::<String>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `emoji` argument for the `greactionconfig` command must be specified.",
             )?;
             return Ok(());
         }
@@ -496,14 +3119,313 @@ fn deny_reward(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResul
         .cloned()
         .expect("Expected GiveawayManager in ShareMap.");
 
-    match giveaway_manager.deny_reward(&msg.author, index, reward_index) {
-        Ok(_) => (),
+    match giveaway_manager.reaction_controls().bind(action, &emoji) {
+        Ok(_) => {
+            let content = format!(
+                "The {} reaction is used for the `{}` action.",
+                emoji, action
+            );
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?;
+        }
         Err(err) => {
             msg.reply(&ctx.http, format!("{}", err))?;
         }
     };
 
-    update_giveaway_message(ctx, msg, &giveaway_manager, index);
-    periodic_giveaway_state_output(ctx, msg, &giveaway_manager, index);
+    Ok(())
+}
+
+#[command("gannounce")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Mention everyone who won the rewards in the certain giveaway"]
+fn announce_winners(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gannounce` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.winner_mentions(&msg.author, index) {
+        Ok(winners) => {
+            let content = match winners.len() {
+                0 => format!("There are no winners in the giveaway #{} yet.", index),
+                _ => {
+                    let lines = winners
+                        .iter()
+                        .map(|(user_id, rewards)| format!("<@{}>: {}", user_id, rewards.join(", ")))
+                        .collect::<Vec<String>>()
+                        .join("\n");
+                    format!("Winners of the giveaway #{}:\n{}", index, lines)
+                }
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gwinners")]
+#[min_args(1)]
+#[max_args(1)]
+#[help_available]
+#[usage("<giveaway-number>")]
+#[example("1")]
+#[description = "Display a recap of who got which rewards in the certain giveaway"]
+fn list_winners(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gwinners` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.get_winners(&msg.author, index) {
+        Ok(winners) => {
+            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
+            let descriptions = giveaway
+                .get_available_rewards()
+                .iter()
+                .map(|reward| {
+                    let description = reward
+                        .description()
+                        .unwrap_or_else(|| reward.value().to_string());
+                    (reward.id(), description)
+                })
+                .collect::<HashMap<_, _>>();
+
+            let content = match winners.len() {
+                0 => format!("Nobody has won any rewards in the giveaway #{} yet.", index),
+                _ => {
+                    let lines = winners
+                        .iter()
+                        .map(|(user_id, rewards)| {
+                            let rewards = rewards
+                                .iter()
+                                .filter_map(|reward_id| descriptions.get(reward_id).cloned())
+                                .collect::<Vec<String>>();
+                            format!("<@{}>: {}", user_id, rewards.join(", "))
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n");
+                    format!("Winners of the giveaway #{}:\n{}", index, lines)
+                }
+            };
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gsimulate")]
+#[min_args(3)]
+#[max_args(3)]
+#[help_available]
+#[usage("<giveaway-number> <participants> <iterations>")]
+#[example("1 20 1000")]
+#[description = "Simulate the random giveaway many times and display how often each reward was won. Doesn't change the giveaway"]
+fn simulate_giveaway(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let index = match args.single::<usize>() {
+        Ok(value) => value,
+        Err(_) => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `giveaway-number` argument for the `gsimulate` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let participants = match args.single::<usize>() {
+        Ok(value) if value > 0 => value,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `participants` argument for the `gsimulate` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+    let iterations = match args.single::<u64>() {
+        Ok(value) if value > 0 => value,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `iterations` argument for the `gsimulate` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    match giveaway_manager.simulate_giveaway(&msg.author, index, participants, iterations) {
+        Ok(report) => {
+            let giveaway = giveaway_manager.get_giveaway_by_index(index).unwrap();
+            let lines = giveaway
+                .get_available_rewards()
+                .iter()
+                .enumerate()
+                .filter(|(_, reward)| report.wins().iter().any(|(id, _)| *id == reward.id()))
+                .map(|(position, reward)| {
+                    format!(
+                        "{}. {} - won in {:.1}% of draws",
+                        position + 1,
+                        giveaway.pretty_print_reward(reward),
+                        report.win_rate(&reward.id()) * 100.0
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+            let content = format!(
+                "Simulated {} draws with {} participants:\n{}",
+                report.iterations(),
+                report.participants(),
+                lines
+            );
+
+            let message = MessageBuilder::new().push(content).build();
+            msg.channel_id.say(&ctx.http, message)?
+        }
+        Err(err) => msg.channel_id.say(&ctx.http, format!("{}", err))?,
+    };
+
+    Ok(())
+}
+
+#[command("gvalidate")]
+#[max_args(0)]
+#[owners_only]
+#[help_available]
+#[description = "Check that the rewards of all giveaways are consistent with the participants' statistics"]
+fn validate_giveaways(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    let report = giveaway_manager.validate_all();
+    let content = match report.len() {
+        0 => "All giveaways are consistent.".to_string(),
+        _ => {
+            let lines = report
+                .iter()
+                .map(|(index, issues)| format!("Giveaway #{}:\n{}", index, issues.join("\n")))
+                .collect::<Vec<String>>()
+                .join("\n");
+            format!("Found inconsistencies:\n{}", lines)
+        }
+    };
+
+    let message = MessageBuilder::new().push(content).build();
+    msg.channel_id.say(&ctx.http, message)?;
+
+    Ok(())
+}
+
+#[command("gcleanmessages")]
+#[max_args(0)]
+#[owners_only]
+#[help_available]
+#[description = "Forget the giveaway messages that were deleted from the channels"]
+fn clean_stale_messages(ctx: &mut Context, msg: &Message) -> CommandResult {
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    // The message is considered deleted when it can't be fetched anymore.
+    let cleaned = giveaway_manager.clean_stale_messages(|channel_id, message_id| {
+        channel_id.message(&ctx.http, message_id).is_ok()
+    });
+    msg.channel_id.say(
+        &ctx.http,
+        format!("Cleaned {} stale message reference(s).", cleaned),
+    )?;
+
+    Ok(())
+}
+
+#[command("geditrate")]
+#[min_args(1)]
+#[max_args(1)]
+#[owners_only]
+#[help_available]
+#[usage("<edits-per-second>")]
+#[example("2")]
+#[description = "Change how many giveaway messages the bot can edit per second across all giveaways"]
+fn set_edit_rate(ctx: &mut Context, msg: &Message, mut args: Args) -> CommandResult {
+    let edits_per_second = match args.single::<u64>() {
+        Ok(value) if value > 0 => value,
+        _ => {
+            msg.channel_id.say(
+                &ctx.http,
+                "The `edits-per-second` argument for the `geditrate` command must be a positive integer.",
+            )?;
+            return Ok(());
+        }
+    };
+
+    let giveaway_manager = ctx
+        .data
+        .read()
+        .get::<GiveawayStorage>()
+        .cloned()
+        .expect("Expected GiveawayManager in ShareMap.");
+
+    giveaway_manager
+        .edit_scheduler()
+        .set_edits_per_second(edits_per_second);
+    msg.channel_id.say(
+        &ctx.http,
+        format!(
+            "The giveaway messages will be edited at most {} time(s) per second.",
+            edits_per_second
+        ),
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    // Handling time of the commands since the bot start.
+    pub static ref COMMAND_LATENCY: CommandLatency = CommandLatency::new();
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LatencySnapshot {
+    pub calls: u64,
+    pub average: Duration,
+    pub max: Duration,
+}
+
+#[derive(Debug, Default)]
+struct LatencyStats {
+    calls: u64,
+    total: Duration,
+    max: Duration,
+}
+
+// Collects how long the handlers of each command took. The start of the
+// handling is remembered by the message that invoked the command.
+#[derive(Debug, Default)]
+pub struct CommandLatency {
+    started: Mutex<HashMap<u64, Instant>>,
+    stats: Mutex<BTreeMap<String, LatencyStats>>,
+}
+
+impl CommandLatency {
+    pub fn new() -> Self {
+        CommandLatency::default()
+    }
+
+    // Remembers the moment when the command from the message started.
+    pub fn start(&self, message_id: u64, now: Instant) {
+        self.started.lock().unwrap().insert(message_id, now);
+    }
+
+    // Records the handling time of the command from the message. Commands
+    // without the registered start are ignored.
+    pub fn finish(&self, message_id: u64, command: &str, now: Instant) {
+        let started_at = match self.started.lock().unwrap().remove(&message_id) {
+            Some(started_at) => started_at,
+            None => return,
+        };
+
+        self.record(command, now.saturating_duration_since(started_at));
+    }
+
+    // Adds the handling time of the command.
+    pub fn record(&self, command: &str, elapsed: Duration) {
+        let mut guard_stats = self.stats.lock().unwrap();
+        let stats = guard_stats
+            .entry(command.to_string())
+            .or_insert_with(LatencyStats::default);
+        stats.calls += 1;
+        stats.total += elapsed;
+        stats.max = stats.max.max(elapsed);
+    }
+
+    // Returns the number of calls, the average and the maximal handling time
+    // of each command, sorted by the command name.
+    pub fn read(&self) -> Vec<(String, LatencySnapshot)> {
+        self.stats
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(command, stats)| {
+                let snapshot = LatencySnapshot {
+                    calls: stats.calls,
+                    average: stats.total / stats.calls as u32,
+                    max: stats.max,
+                };
+                (command.clone(), snapshot)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::commands::giveaway::latency::{CommandLatency, LatencySnapshot};
+
+    #[test]
+    fn test_read_average_and_max_latency() {
+        let latency = CommandLatency::new();
+        latency.record("groll", Duration::from_millis(10));
+        latency.record("groll", Duration::from_millis(30));
+        latency.record("groll", Duration::from_millis(20));
+        latency.record("glist", Duration::from_millis(5));
+
+        let expected = vec![
+            (
+                "glist".to_string(),
+                LatencySnapshot {
+                    calls: 1,
+                    average: Duration::from_millis(5),
+                    max: Duration::from_millis(5),
+                },
+            ),
+            (
+                "groll".to_string(),
+                LatencySnapshot {
+                    calls: 3,
+                    average: Duration::from_millis(20),
+                    max: Duration::from_millis(30),
+                },
+            ),
+        ];
+        assert_eq!(latency.read(), expected);
+    }
+
+    #[test]
+    fn test_record_latency_between_start_and_finish() {
+        let latency = CommandLatency::new();
+        let now = Instant::now();
+
+        latency.start(1, now);
+        latency.finish(1, "groll", now + Duration::from_millis(15));
+        latency.finish(2, "groll", now + Duration::from_millis(50));
+
+        let result = latency.read();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].1.calls, 1);
+        assert_eq!(result[0].1.max, Duration::from_millis(15));
+    }
+}
@@ -0,0 +1,130 @@
+use std::env;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    // The profiling is enabled once on the bot start via the environment.
+    static ref PROFILE_LOCKS: bool = env::var("PROFILE_LOCKS").is_ok();
+}
+
+// Wait times for acquiring the lock on the giveaway rewards.
+#[derive(Debug, Default)]
+pub struct LockStats {
+    enabled: bool,
+    acquisitions: AtomicU64,
+    total_wait_nanos: AtomicU64,
+    max_wait_nanos: AtomicU64,
+}
+
+// A point-in-time copy of the lock statistics.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LockStatsSnapshot {
+    pub acquisitions: u64,
+    pub average_wait: Duration,
+    pub max_wait: Duration,
+}
+
+impl LockStats {
+    pub fn new() -> Self {
+        LockStats::with_enabled(*PROFILE_LOCKS)
+    }
+
+    pub fn with_enabled(enabled: bool) -> Self {
+        LockStats {
+            enabled,
+            ..LockStats::default()
+        }
+    }
+
+    // Returns true when the wait times are recorded.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    // Acquires the lock with the given function. When the profiling is
+    // disabled, the lock is acquired as is, without measuring anything.
+    pub fn timed<T, F: FnOnce() -> T>(&self, acquire: F) -> T {
+        if !self.enabled {
+            return acquire();
+        }
+
+        let started_at = Instant::now();
+        let guard = acquire();
+        self.record(started_at.elapsed());
+        guard
+    }
+
+    fn record(&self, wait: Duration) {
+        let wait_nanos = wait.as_nanos() as u64;
+        self.acquisitions.fetch_add(1, Ordering::SeqCst);
+        self.total_wait_nanos
+            .fetch_add(wait_nanos, Ordering::SeqCst);
+        self.max_wait_nanos.fetch_max(wait_nanos, Ordering::SeqCst);
+    }
+
+    // Returns the number of acquisitions with the average and maximum wait.
+    pub fn read(&self) -> LockStatsSnapshot {
+        let acquisitions = self.acquisitions.load(Ordering::SeqCst);
+        let total_wait_nanos = self.total_wait_nanos.load(Ordering::SeqCst);
+        let average_wait_nanos = match acquisitions {
+            0 => 0,
+            _ => total_wait_nanos / acquisitions,
+        };
+
+        LockStatsSnapshot {
+            acquisitions,
+            average_wait: Duration::from_nanos(average_wait_nanos),
+            max_wait: Duration::from_nanos(self.max_wait_nanos.load(Ordering::SeqCst)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::commands::giveaway::locks::{LockStats, LockStatsSnapshot};
+
+    #[test]
+    fn test_disabled_stats_are_not_recorded() {
+        let stats = LockStats::with_enabled(false);
+        let data = Mutex::new(0);
+
+        *stats.timed(|| data.lock().unwrap()) += 1;
+
+        assert_eq!(stats.read(), LockStatsSnapshot::default());
+        assert_eq!(*data.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_wait_times_under_concurrent_access() {
+        let stats = Arc::new(LockStats::with_enabled(true));
+        let data = Arc::new(Mutex::new(0));
+
+        let handles = (0..4)
+            .map(|_| {
+                let stats = stats.clone();
+                let data = data.clone();
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        let mut guard = stats.timed(|| data.lock().unwrap());
+                        *guard += 1;
+                        thread::sleep(Duration::from_millis(1));
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let snapshot = stats.read();
+        assert_eq!(*data.lock().unwrap(), 20);
+        assert_eq!(snapshot.acquisitions, 20);
+        assert_eq!(snapshot.max_wait >= snapshot.average_wait, true);
+    }
+}
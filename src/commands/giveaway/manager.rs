@@ -1,43 +1,120 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
+use chrono::{DateTime, Duration, Utc};
 use dashmap::mapref::one::RefMut;
 use dashmap::DashMap;
+use rand::seq::SliceRandom;
+use serenity::model::id::{ChannelId, MessageId};
 use serenity::model::user::User as DiscordUser;
 use uuid::Uuid;
 
+use crate::commands::giveaway::dto::{
+    AnonStats, GiveawayDto, GiveawaySummary, ImportReport, OwnerDashboard,
+};
+use crate::commands::giveaway::formatters::{
+    AsciiTableExporter, JsonRewardFormatter, RewardFormatter, TsvExporter,
+};
+use crate::commands::giveaway::locks::LockStatsSnapshot;
+use crate::commands::giveaway::metrics::Metrics;
 use crate::commands::giveaway::models::{
-    Giveaway, ObjectState, Participant, ParticipantStats, Reward,
+    Giveaway, ObjectState, ObjectType, Participant, ParticipantStats, Receipt, Reward,
 };
-use crate::commands::giveaway::strategies::RollOptions;
+use crate::commands::giveaway::reactions::ReactionControls;
+use crate::commands::giveaway::scheduler::{EditScheduler, DEFAULT_EDITS_PER_SECOND};
+use crate::commands::giveaway::strategies::{
+    draw_entrants, simulate, DistributionReport, RollOptions,
+};
+use crate::commands::giveaway::utils::is_well_formed_key;
 use crate::error::{Error, ErrorKind, Result};
 
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct GiveawayManager {
     giveaways: Arc<Mutex<Vec<Arc<Box<Giveaway>>>>>,
+    metrics: Arc<Metrics>,
+    // Short names of giveaways, defined by each user for themselves.
+    aliases: DashMap<(u64, String), usize>,
+    reaction_controls: Arc<ReactionControls>,
+    // Throttles the edits of the giveaway state messages.
+    edit_scheduler: Arc<EditScheduler>,
 }
 
 impl GiveawayManager {
     pub fn new() -> Self {
         GiveawayManager {
             giveaways: Arc::new(Mutex::new(Vec::new())),
+            metrics: Arc::new(Metrics::new()),
+            aliases: DashMap::new(),
+            reaction_controls: Arc::new(ReactionControls::new()),
+            edit_scheduler: Arc::new(EditScheduler::new(DEFAULT_EDITS_PER_SECOND)),
         }
     }
 
+    // Returns the counters of rolls, confirms and denies across all giveaways.
+    pub fn metrics(&self) -> Arc<Metrics> {
+        self.metrics.clone()
+    }
+
+    // Returns the emojis used for interacting with the giveaway messages.
+    pub fn reaction_controls(&self) -> Arc<ReactionControls> {
+        self.reaction_controls.clone()
+    }
+
+    // Returns the queue of the giveaway state message edits.
+    pub fn edit_scheduler(&self) -> Arc<EditScheduler> {
+        self.edit_scheduler.clone()
+    }
+
     // Returns all current giveaways (started and on a pause).
     pub fn get_giveaways(&self) -> Vec<Arc<Box<Giveaway>>> {
+        self.get_indexed_giveaways()
+            .into_iter()
+            .map(|(_, giveaway)| giveaway)
+            .collect()
+    }
+
+    // Returns all current giveaways with their indices. Deleted giveaways are
+    // skipped, but still reserve their indices, so the numbers of the other
+    // giveaways are never shifted after the deletion.
+    pub fn get_indexed_giveaways(&self) -> Vec<(usize, Arc<Box<Giveaway>>)> {
         let ref_giveaways = self.giveaways.clone();
         let guard_giveaways = ref_giveaways.lock().unwrap();
-        guard_giveaways.to_vec()
+        guard_giveaways
+            .iter()
+            .enumerate()
+            .filter(|(_, giveaway)| !giveaway.is_deleted())
+            .map(|(position, giveaway)| (position + 1, giveaway.clone()))
+            .collect()
+    }
+
+    // Returns the giveaways owned by the user with the same indices as in
+    // the full list of giveaways.
+    pub fn get_giveaways_by_owner(&self, user_id: u64) -> Vec<(usize, Arc<Box<Giveaway>>)> {
+        self.get_indexed_giveaways()
+            .into_iter()
+            .filter(|(_, giveaway)| giveaway.owner().get_user_id() == user_id)
+            .collect()
+    }
+
+    // Returns the index of the giveaway displayed in the given message.
+    pub fn get_giveaway_index_by_message(&self, message_id: MessageId) -> Option<usize> {
+        self.get_indexed_giveaways()
+            .into_iter()
+            .find(|(_, giveaway)| giveaway.get_message_id() == Some(message_id))
+            .map(|(index, _)| index)
     }
 
-    // Returns a giveaway by the given index.
+    // Returns a giveaway by the given index. Deleted giveaways can't be found.
     pub fn get_giveaway_by_index(&self, index: usize) -> Result<Arc<Box<Giveaway>>> {
         let ref_giveaways = self.giveaways.clone();
         let guard_giveaways = ref_giveaways.lock().unwrap();
 
-        match index > 0 && index < guard_giveaways.len() + 1 {
+        match index > 0
+            && index < guard_giveaways.len() + 1
+            && !guard_giveaways[index - 1].is_deleted()
+        {
             true => Ok(guard_giveaways[index - 1].clone()),
             false => {
                 let message = format!("The requested giveaway was not found.");
@@ -46,11 +123,52 @@ impl GiveawayManager {
         }
     }
 
-    // Sets the giveaway to the "active" state. Available only for the owner.
-    pub fn activate_giveaway(&self, user: &DiscordUser, index: usize) -> Result<()> {
+    // Registers a short name of the giveaway for the user.
+    pub fn set_giveaway_alias(&self, user: &DiscordUser, name: &str, index: usize) -> Result<()> {
+        if name.parse::<usize>().is_ok() {
+            let message = format!("The alias name can't be a number.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        self.get_giveaway_by_index(index)?;
+        self.aliases.insert((user.id.0, name.to_string()), index);
+        Ok(())
+    }
+
+    // Returns the giveaway index by the number or the user's alias.
+    pub fn resolve_giveaway_index(&self, user: &DiscordUser, value: &str) -> Result<usize> {
+        if let Ok(index) = value.parse::<usize>() {
+            return Ok(index);
+        }
+
+        match self.aliases.get(&(user.id.0, value.to_string())) {
+            Some(index) => Ok(*index),
+            None => {
+                let message = format!("The `{}` alias is not defined.", value);
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Sets the giveaway to the "active" state and binds it to the given channel.
+    // Only one active giveaway per channel is allowed, unless the owner forces
+    // the start. Available only for the owner.
+    pub fn activate_giveaway(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        channel_id: ChannelId,
+        force: bool,
+    ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_owner(user, &giveaway)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        if !force {
+            self.check_no_active_giveaway_in_channel(index, channel_id)?;
+        }
+        giveaway.strategy().validate_start(&giveaway)?;
 
+        giveaway.set_channel_id(Some(channel_id));
         giveaway.activate();
         Ok(())
     }
@@ -58,457 +176,5220 @@ impl GiveawayManager {
     // Sets the giveaway to the "pause" state. Available only for the owner.
     pub fn deactivate_giveaway(&self, user: &DiscordUser, index: usize) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?.clone();
-        self.check_giveaway_owner(user, &giveaway)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
         giveaway.deactivate();
         Ok(())
     }
 
-    // Deletes the giveaway. Available only for the owner.
-    pub fn delete_giveaway(&self, user: &DiscordUser, index: usize) -> Result<()> {
-        let ref_giveaways = self.giveaways.clone();
-        let mut guard_giveaways = ref_giveaways.lock().unwrap();
+    // Starts the paused giveaway automatically at the given moment in the
+    // certain channel. Available for the owner and the moderators.
+    pub fn schedule_giveaway(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        channel_id: ChannelId,
+        start_at: Instant,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        match index > 0 && index < guard_giveaways.len() + 1 {
-            true => {
-                if user.id.0 != guard_giveaways[index - 1].owner().get_user_id() {
-                    let message = format!("For deleting this giveaway you need to be its owner.");
-                    return Err(Error::from(ErrorKind::Giveaway(message)));
+        if giveaway.is_activated() {
+            let message = format!("The giveaway has been already started.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        giveaway.set_channel_id(Some(channel_id));
+        giveaway.set_scheduled_start(Some(start_at));
+        Ok(())
+    }
+
+    // Cancels the automatic start of the giveaway, so it stays paused.
+    // Available for the owner and the moderators.
+    pub fn cancel_schedule(&self, user: &DiscordUser, index: usize) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        if giveaway.scheduled_start().is_none() {
+            let message = format!("The giveaway doesn't have a scheduled start.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        giveaway.set_scheduled_start(None);
+        Ok(())
+    }
+
+    // Starts the giveaways that have reached their scheduled start. Returns
+    // the indexes of the started giveaways. Giveaways that can't be started
+    // by their strategy stay paused.
+    pub fn start_scheduled_giveaways(&self, now: Instant) -> Vec<(usize, Arc<Box<Giveaway>>)> {
+        self.get_indexed_giveaways()
+            .into_iter()
+            .filter(|(_, giveaway)| giveaway.is_start_due(now))
+            .filter_map(|(index, giveaway)| {
+                giveaway.set_scheduled_start(None);
+                if let Err(err) = giveaway.strategy().validate_start(&giveaway) {
+                    println!(
+                        "Can't start the scheduled giveaway #{}: {}",
+                        index,
+                        err.to_string()
+                    );
+                    return None;
                 }
 
-                guard_giveaways.remove(index - 1);
-                Ok(())
-            }
-            false => {
-                let message = format!("The requested giveaway was not found.");
-                Err(Error::from(ErrorKind::Giveaway(message)))
-            }
+                giveaway.activate();
+                Some((index, giveaway))
+            })
+            .collect()
+    }
+
+    // Randomizes the order of the rewards, so that nobody can guess which number
+    // maps to which reward. Available only for the owner and only before the
+    // giveaway has been started, because the numbers of the rewards change.
+    pub fn shuffle_rewards(&self, user: &DiscordUser, index: usize) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        if giveaway.is_activated() {
+            let message = format!(
+                "The rewards can't be shuffled while the giveaway is active, because their numbers would change for the participants."
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
         }
+
+        giveaway.write_rewards().shuffle(&mut rand::thread_rng());
+        Ok(())
     }
 
-    // Adds a new giveaway.
-    pub fn add_giveaway(&self, giveaway: Giveaway) {
-        let ref_giveaways = self.giveaways.clone();
-        let mut guard_giveaways = ref_giveaways.lock().unwrap();
-        guard_giveaways.push(Arc::new(Box::new(giveaway)));
+    // Removes all rewards from the giveaway and returns how many were removed.
+    // Available only for the owner, while the giveaway isn't active.
+    pub fn clear_giveaway_rewards(&self, user: &DiscordUser, index: usize) -> Result<usize> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        if giveaway.is_activated() {
+            let message = format!(
+                "The rewards can't be cleared while the giveaway is active. Deactivate the giveaway first."
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(giveaway.clear_rewards())
     }
 
-    // Returns a list of reward for the certain giveaway. Mostly used for checks
-    // before the beginning and debugging. Available only for the owner.
-    pub fn get_giveaway_rewards(
+    // Enables or disables the blind mode for the giveaway, that hides rewards
+    // until they were activated. Available only for the owner.
+    pub fn set_giveaway_blind_mode(
         &self,
         user: &DiscordUser,
         index: usize,
-    ) -> Result<Vec<Arc<Box<Reward>>>> {
+        blind: bool,
+    ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_owner(user, &giveaway)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        let rewards = giveaway
-            .get_available_rewards()
-            .iter()
-            .cloned()
-            .collect::<Vec<Arc<Box<Reward>>>>();
-        Ok(rewards)
+        giveaway.set_blind(blind);
+        Ok(())
     }
 
-    // Parses the messages into the certain type of reward and adds to the certain
-    // giveaway. Owners can add rewards only for their own giveaways.
-    pub fn add_giveaway_reward(&self, user: &DiscordUser, index: usize, data: &str) -> Result<()> {
+    // Enables or disables the adaptive output for the giveaway, that prints
+    // the state less often when the participants are active and more often
+    // when they aren't. Available only for the owner.
+    pub fn set_giveaway_adaptive_output(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        enabled: bool,
+    ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_owner(user, &giveaway)?;
-
-        let reward = Reward::new(data);
-        giveaway.add_reward(&reward);
+        self.check_giveaway_manager(user, &giveaway)?;
 
+        giveaway.set_adaptive_output(enabled);
         Ok(())
     }
 
-    // Parses the given message into multiple reward and then adds them to the
-    // certain giveaway. The separator is the `\n` (just a new line) for the
-    // each declared reward. Owners can add rewards only for their own giveaways.
-    pub fn add_multiple_giveaway_rewards(
+    // Replaces the description of the giveaway. Available only for the owner.
+    pub fn update_giveaway_description(
         &self,
         user: &DiscordUser,
         index: usize,
-        data: &str,
+        new_description: &str,
     ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_owner(user, &giveaway)?;
-
-        for raw_reward_data in data.split("\n") {
-            let reward = Reward::new(raw_reward_data);
-            giveaway.add_reward(&reward);
-        }
+        self.check_giveaway_manager(user, &giveaway)?;
 
+        giveaway.set_description(new_description);
         Ok(())
     }
 
-    // Removed the giveaway from the certain giveaways. Owners can remove rewards
-    // only for their own giveaways.
-    pub fn remove_giveaway_reward(
+    // Replaces the description of the certain reward, keeping its identifier
+    // and state. Owners can edit rewards only for their own giveaways.
+    pub fn update_giveaway_reward_description(
         &self,
         user: &DiscordUser,
         index: usize,
         reward_index: usize,
+        new_description: &str,
     ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_owner(user, &giveaway)?;
-        giveaway.remove_reward_by_index(reward_index)?;
-        Ok(())
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        giveaway.update_reward_description(reward_index, new_description)
     }
 
-    // Returns a reward from the requested giveaway in according with the set strategy.
-    pub fn roll_reward(
+    // Changes the note of the reward, which is shown only in the detailed
+    // list of rewards. Available only for the owner.
+    pub fn set_reward_owner_note(
         &self,
         user: &DiscordUser,
         index: usize,
-        raw_message: &str,
-    ) -> Result<Option<String>> {
+        reward_index: usize,
+        note: &str,
+    ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_is_active(&giveaway)?;
-
-        giveaway.update_actions_processed();
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        let participant = Participant::from(user.clone());
-        let stats = giveaway.stats();
-        let rewards = giveaway.raw_rewards();
-        let roll_options = RollOptions::new(&participant, &rewards, raw_message, &stats);
-        let strategy = giveaway.strategy();
-        let selected_reward = strategy.roll(&roll_options)?;
+        giveaway.update_reward_owner_note(reward_index, note)
+    }
 
-        let user_id = participant.get_user_id();
-        let next_state = match stats.get_mut(&user_id) {
-            Some(mut data) => self.get_next_reward_state_after_roll(&selected_reward, &mut data),
-            None => {
-                stats.insert(user_id, ParticipantStats::new());
-                let mut data = stats.get_mut(&user_id).unwrap();
-                self.get_next_reward_state_after_roll(&selected_reward, &mut data)
-            }
-        };
-        selected_reward.set_object_state(next_state);
+    // Changes the message shown while the giveaway doesn't have any rewards.
+    pub fn set_giveaway_empty_message(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        message: &str,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        let response = strategy.to_message(selected_reward);
-        Ok(response)
+        giveaway.set_empty_message(message);
+        Ok(())
     }
 
-    // Returns a next state that needs to be set for the rolled reward. Also
-    // updates user's statistics for tracking what have been taken.
-    fn get_next_reward_state_after_roll(
+    // Sets the emoji which the bot puts on the state message of the giveaway
+    // after each claim. Passing `None` disables the reactions. Available only
+    // for the owner.
+    pub fn set_giveaway_claim_emoji(
         &self,
-        reward: &Arc<Box<Reward>>,
-        user_data: &mut RefMut<u64, ParticipantStats>,
-    ) -> ObjectState {
-        match reward.is_preorder() {
-            // Any pre-order goes to activated instanly after the roll
-            true => {
-                user_data.add_retrieved_reward(reward.id());
-                ObjectState::Activated
-            }
-            // All other types needs activated manually
-            false => {
-                user_data.add_pending_reward(reward.id());
-                ObjectState::Pending
+        user: &DiscordUser,
+        index: usize,
+        emoji: Option<&str>,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        giveaway.set_claim_emoji(emoji.map(|value| value.to_string()));
+        Ok(())
+    }
+
+    // Reacts on the state message of the giveaway with the claim emoji via the
+    // `add_reaction` function. Nothing happens when the emoji wasn't set or the
+    // state message is missing. Returns true when the reaction was added.
+    pub fn add_claim_reaction<F>(&self, index: usize, add_reaction: F) -> bool
+    where
+        F: FnOnce(ChannelId, MessageId, &str) -> bool,
+    {
+        let giveaway = match self.get_giveaway_by_index(index) {
+            Ok(giveaway) => giveaway,
+            Err(_) => return false,
+        };
+
+        match (
+            giveaway.claim_emoji(),
+            giveaway.get_channel_id(),
+            giveaway.get_message_id(),
+        ) {
+            (Some(emoji), Some(channel_id), Some(message_id)) => {
+                add_reaction(channel_id, message_id, &emoji)
             }
+            _ => false,
         }
     }
 
-    // Confirm that the reward was received and has been activated.
-    pub fn confirm_reward(
+    // Changes how many segments of the keys are revealed for the certain
+    // reward state. Passing `None` restores the default masking.
+    pub fn set_giveaway_reveal_policy(
         &self,
         user: &DiscordUser,
         index: usize,
-        reward_index: usize,
+        state: ObjectState,
+        segments: Option<usize>,
     ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_is_active(&giveaway)?;
-
-        giveaway.update_actions_processed();
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        let ref_rewards = giveaway.raw_rewards().clone();
-        let guard_rewards = ref_rewards.lock().unwrap();
+        giveaway
+            .reward_formatter()
+            .set_revealed_segments(state, segments);
+        Ok(())
+    }
 
-        match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
-            true => {
-                let participant = Participant::from(user.clone());
-                let stats = giveaway.stats();
-                let user_id = participant.get_user_id();
-                let selected_reward = guard_rewards[reward_index - 1].clone();
+    // Deletes the giveaway. Available only for the owner.
+    pub fn delete_giveaway(&self, user: &DiscordUser, index: usize) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
 
-                let user_stats = stats.get_mut(&user_id);
-                match user_stats {
-                    Some(mut data) => self.move_reward_to_retrieved(&mut data, &selected_reward),
-                    None => {
-                        stats.insert(user_id, ParticipantStats::new());
-                        let message = format!("The reward must be rolled before confirming.");
-                        return Err(Error::from(ErrorKind::Giveaway(message)));
-                    }
-                }
-            }
-            false => {
-                let message = format!("The requested reward was not found.");
-                Err(Error::from(ErrorKind::Giveaway(message)))
-            }
+        if user.id.0 != giveaway.owner().get_user_id() {
+            let message = format!("For deleting this giveaway you need to be its owner.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
         }
+
+        giveaway.mark_as_deleted();
+        Ok(())
     }
 
-    // Return the certain reward to the unused state and cleanup the user's stats
-    pub fn deny_reward(&self, user: &DiscordUser, index: usize, reward_index: usize) -> Result<()> {
-        let giveaway = self.get_giveaway_by_index(index)?;
-        self.check_giveaway_is_active(&giveaway)?;
+    // Finishes the giveaways that have reached their deadline. Returns the
+    // final state of each finished giveaway, rendered before the deletion.
+    pub fn finish_expired_giveaways(&self, now: Instant) -> Vec<(Arc<Box<Giveaway>>, String)> {
+        self.get_indexed_giveaways()
+            .into_iter()
+            .filter(|(_, giveaway)| giveaway.is_expired(now))
+            .filter_map(|(index, giveaway)| {
+                let output = match self.pretty_print_giveaway(index) {
+                    Ok(output) => output,
+                    Err(err) => {
+                        println!(
+                            "Can't retrieve formatted giveaway state: {}",
+                            err.to_string()
+                        );
+                        return None;
+                    }
+                };
 
-        giveaway.update_actions_processed();
+                giveaway.mark_as_deleted();
+                Some((giveaway, output))
+            })
+            .collect()
+    }
 
-        let ref_rewards = giveaway.raw_rewards().clone();
-        let guard_rewards = ref_rewards.lock().unwrap();
+    // Allows another user to manage the giveaway. Only the owner can add moderators.
+    pub fn add_moderator(
+        &self,
+        owner: &DiscordUser,
+        index: usize,
+        moderator_id: u64,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_owner(owner, &giveaway)?;
+        giveaway.add_moderator(moderator_id);
+        Ok(())
+    }
 
-        match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
-            true => {
-                let participant = Participant::from(user.clone());
-                let stats = giveaway.stats();
-                let user_id = participant.get_user_id();
-                let selected_reward = guard_rewards[reward_index - 1].clone();
+    // Adds the user to the co-hosts of the giveaway, or removes the user when
+    // it's already one of them. Co-hosts are the same users as moderators and
+    // share the owner permissions, except deleting and transferring the giveaway
+    // or changing its co-hosts. Returns true when the user became a co-host.
+    pub fn toggle_cohost(&self, owner: &DiscordUser, index: usize, cohost_id: u64) -> Result<bool> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_owner(owner, &giveaway)?;
 
-                let user_stats = stats.get_mut(&user_id);
-                match user_stats {
-                    Some(mut data) => self.rollback_reward_to_unused(&mut data, &selected_reward),
-                    None => {
-                        stats.insert(user_id, ParticipantStats::new());
-                        let message = format!("The reward must be rolled before return.");
-                        return Err(Error::from(ErrorKind::Giveaway(message)));
-                    }
-                }
-            }
+        match giveaway.remove_moderator(cohost_id) {
+            true => Ok(false),
             false => {
-                let message = format!("The requested reward was not found.");
-                Err(Error::from(ErrorKind::Giveaway(message)))
+                giveaway.add_moderator(cohost_id);
+                Ok(true)
             }
         }
     }
 
-    // Checks that whether the certain giveaway needs to be printed out
-    pub fn is_required_state_output(&self, index: usize) -> Result<bool> {
+    // Hands the giveaway over to the new owner. Available only for the current owner.
+    pub fn transfer_ownership(
+        &self,
+        current_owner: &DiscordUser,
+        index: usize,
+        new_owner: &DiscordUser,
+    ) -> Result<()> {
         let giveaway = self.get_giveaway_by_index(index)?;
-        Ok(giveaway.is_required_state_output())
+        self.check_giveaway_owner(current_owner, &giveaway)?;
+        giveaway.set_owner(Participant::from(new_owner.clone()));
+        Ok(())
     }
 
-    // Returns a pretty print of the giveaway state
-    pub fn pretty_print_giveaway(&self, giveaway_index: usize) -> Result<String> {
-        let giveaway = self.get_giveaway_by_index(giveaway_index)?;
-        let stats = giveaway.stats();
+    // Moves the giveaway to the given position in the list. The giveaways in
+    // between are shifted by one and the aliases are updated to keep pointing
+    // to the same giveaways. The deleted giveaways can't be used as the target
+    // position. Available only for the owner.
+    pub fn reorder_giveaway(
+        &self,
+        owner: &DiscordUser,
+        from_index: usize,
+        to_index: usize,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(from_index)?;
+        self.check_giveaway_owner(owner, &giveaway)?;
 
-        let pending_rewards = self.extract_pending_rewards(&stats);
-        let retrieved_rewards = self.extract_retrieved_rewards(&stats);
+        let ref_giveaways = self.giveaways.clone();
+        let mut guard_giveaways = ref_giveaways.lock().unwrap();
+        if to_index == 0
+            || to_index > guard_giveaways.len()
+            || guard_giveaways[to_index - 1].is_deleted()
+        {
+            let message = format!("The requested position is out of the giveaways list.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
 
-        let reward_formatter = giveaway.reward_formatter();
-        let rewards_output = giveaway
-            .raw_rewards()
-            .clone()
-            .lock()
-            .unwrap()
-            .iter()
-            .enumerate()
-            .map(|(index, reward)| {
-                let reward_id = reward.id();
-                let is_pending = pending_rewards.contains_key(&reward_id);
-                let is_retrieved = retrieved_rewards.contains_key(&reward_id);
-
-                let reward_output = reward_formatter.pretty_print(reward);
-                match (is_pending, is_retrieved) {
-                    (true, false) => {
-                        let user_id = pending_rewards.get(&reward_id).unwrap();
-                        format!(
-                            "{}. {}  [taken by <@{}>]",
-                            index + 1,
-                            reward_output,
-                            user_id
-                        )
-                    }
-                    (false, true) => {
-                        let user_id = retrieved_rewards.get(&reward_id).unwrap();
-                        format!(
-                            "{}. {}  [activated by <@{}>]",
-                            index + 1,
-                            reward_output,
-                            user_id
-                        )
-                    }
-                    _ => format!("{}. {}", index + 1, reward_output),
-                }
-            })
-            .collect::<Vec<String>>()
-            .join("\n");
+        let moved_giveaway = guard_giveaways.remove(from_index - 1);
+        guard_giveaways.insert(to_index - 1, moved_giveaway);
+        for mut alias in self.aliases.iter_mut() {
+            let index = *alias.value();
+            *alias.value_mut() = match index {
+                index if index == from_index => to_index,
+                index if from_index < index && index <= to_index => index - 1,
+                index if to_index <= index && index < from_index => index + 1,
+                index => index,
+            };
+        }
+        Ok(())
+    }
 
-        let response = format!("Giveaway #{}:\n{}", giveaway_index, rewards_output);
-        Ok(response)
+    // Adds a new giveaway.
+    pub fn add_giveaway(&self, giveaway: Giveaway) {
+        let ref_giveaways = self.giveaways.clone();
+        let mut guard_giveaways = ref_giveaways.lock().unwrap();
+        guard_giveaways.push(Arc::new(Box::new(giveaway)));
     }
 
-    // A special wrapper to help with moving the reward in the retrieved group in stats
-    fn move_reward_to_retrieved(
-        &self,
-        data: &mut RefMut<u64, ParticipantStats>,
-        reward: &Arc<Box<Reward>>,
-    ) -> Result<()> {
-        let pending_rewards = data.pending_rewards();
+    // Creates a copy of the giveaway with the same description, strategy and
+    // rewards, but without any claimed state. The rewards are parsed again
+    // from their original text. Returns the number of the new giveaway.
+    // Available only for the owner.
+    pub fn clone_giveaway(&self, user: &DiscordUser, index: usize) -> Result<usize> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_owner(user, &giveaway)?;
 
-        match reward.object_state() {
-            ObjectState::Activated => {
-                let message = format!("The reward has been activated already.");
+        let cloned_giveaway = Giveaway::new(user)
+            .with_description(&giveaway.description())
+            .with_shared_strategy(giveaway.strategy());
+        for reward in giveaway.read_rewards().iter() {
+            let cloned_reward = Reward::new(&reward.source());
+            cloned_reward.set_description(&reward.description().unwrap_or_default());
+            cloned_reward.set_owner_note(&reward.owner_note().unwrap_or_default());
+            cloned_giveaway.add_reward(&cloned_reward)?;
+        }
+
+        let ref_giveaways = self.giveaways.clone();
+        let mut guard_giveaways = ref_giveaways.lock().unwrap();
+        guard_giveaways.push(Arc::new(Box::new(cloned_giveaway)));
+        Ok(guard_giveaways.len())
+    }
+
+    // Creates giveaways from the JSON array. Each entry is validated on its own,
+    // so that invalid ones are reported and skipped without aborting the import.
+    pub fn import_giveaways(&self, user: &DiscordUser, data: &str) -> Result<ImportReport> {
+        let entries = match serde_json::from_str::<Vec<serde_json::Value>>(data) {
+            Ok(entries) => entries,
+            Err(err) => {
+                let message = format!(
+                    "The imported data must be a JSON array of giveaways: {}",
+                    err
+                );
                 return Err(Error::from(ErrorKind::Giveaway(message)));
             }
-            ObjectState::Pending => match pending_rewards.contains(&reward.id()) {
-                true => {
-                    data.remove_pending_reward(reward.id());
-                    data.add_retrieved_reward(reward.id());
-                    reward.set_object_state(ObjectState::Activated);
-                    Ok(())
-                }
-                false => {
-                    let message = format!("This reward can't be activated by others.");
-                    return Err(Error::from(ErrorKind::Giveaway(message)));
-                }
-            },
-            ObjectState::Unused => {
-                let message = format!("The reward must be rolled before confirming.");
+        };
+
+        let mut report = ImportReport::default();
+        for (index, entry) in entries.into_iter().enumerate() {
+            match self.import_giveaway(user, entry) {
+                Ok(_) => report.imported += 1,
+                Err(err) => report.failed.push((index + 1, format!("{}", err))),
+            }
+        }
+
+        Ok(report)
+    }
+
+    // Validates the single imported entry and adds it as a new giveaway.
+    fn import_giveaway(&self, user: &DiscordUser, entry: serde_json::Value) -> Result<()> {
+        let dto = match serde_json::from_value::<GiveawayDto>(entry) {
+            Ok(dto) => dto,
+            Err(err) => {
+                let message = format!("Invalid giveaway: {}", err);
                 return Err(Error::from(ErrorKind::Giveaway(message)));
             }
+        };
+
+        if dto.description.trim().is_empty() {
+            let message = format!("The giveaway description must not be empty.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        if dto.rewards.iter().any(|reward| reward.trim().is_empty()) {
+            let message = format!("The giveaway rewards must not be empty.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        let giveaway = Giveaway::new(user).with_description(dto.description.trim());
+        for raw_reward_data in dto.rewards.iter() {
+            let reward = Reward::new(raw_reward_data);
+            giveaway.add_reward(&reward)?;
         }
+        self.add_giveaway(giveaway);
+
+        Ok(())
     }
 
-    fn rollback_reward_to_unused(
+    // Returns a list of reward for the certain giveaway. Mostly used for checks
+    // before the beginning and debugging. Available only for the owner.
+    pub fn get_giveaway_rewards(
         &self,
-        data: &mut RefMut<u64, ParticipantStats>,
-        reward: &Arc<Box<Reward>>,
-    ) -> Result<()> {
-        let pending_rewards = data.pending_rewards();
+        user: &DiscordUser,
+        index: usize,
+    ) -> Result<Vec<Arc<Box<Reward>>>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        match reward.object_state() {
-            ObjectState::Activated => {
-                let message = format!("The reward has been activated already.");
-                return Err(Error::from(ErrorKind::Giveaway(message)));
+        let rewards = giveaway
+            .get_available_rewards()
+            .iter()
+            .cloned()
+            .collect::<Vec<Arc<Box<Reward>>>>();
+        Ok(rewards)
+    }
+
+    // Returns the pending and retrieved rewards of the user in the giveaway,
+    // formatted with their numbers. The unused keys stay masked, while the
+    // rest are shown as is, because they belong to the user.
+    pub fn get_user_rewards(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        let (pending_ids, retrieved_ids) = match giveaway.stats().get(&user.id.0) {
+            Some(data) => (data.pending_rewards(), data.retrieved_rewards()),
+            None => (HashSet::new(), HashSet::new()),
+        };
+
+        let reward_formatter = giveaway.reward_formatter();
+        let guard_rewards = giveaway.read_rewards();
+        let format_reward = |position: usize, reward: &Arc<Box<Reward>>| {
+            let value = match reward.object_state() {
+                ObjectState::Activated => reward.value().to_string(),
+                _ => reward_formatter.masked_value(reward),
+            };
+            let mut text = format!("{}. {}", position + 1, value);
+            if let Some(info) = reward.object_info() {
+                text.push_str(&format!(" {}", info));
             }
-            ObjectState::Pending => match pending_rewards.contains(&reward.id()) {
-                true => {
-                    data.remove_pending_reward(reward.id());
-                    reward.set_object_state(ObjectState::Unused);
-                    Ok(())
-                }
-                false => {
-                    let message = format!("This reward can't be returned by others.");
-                    return Err(Error::from(ErrorKind::Giveaway(message)));
-                }
-            },
-            ObjectState::Unused => {
-                let message = format!("The reward must be rolled before return.");
-                return Err(Error::from(ErrorKind::Giveaway(message)));
+            if let Some(description) = reward.description() {
+                text.push_str(&format!(" -> {}", description));
+            }
+            text
+        };
+
+        let mut pending = Vec::new();
+        let mut retrieved = Vec::new();
+        for (position, reward) in guard_rewards.iter().enumerate() {
+            if pending_ids.contains(&reward.id()) {
+                pending.push(format_reward(position, reward));
+            }
+            if retrieved_ids.contains(&reward.id()) {
+                retrieved.push(format_reward(position, reward));
             }
         }
+        Ok((pending, retrieved))
     }
 
-    fn extract_pending_rewards(
-        &self,
-        stats: &Arc<DashMap<u64, ParticipantStats>>,
-    ) -> HashMap<Uuid, u64> {
-        stats
+    // Returns the number of the reward which is pending for the user, if any.
+    pub fn get_pending_reward_index(&self, user_id: u64, index: usize) -> Result<Option<usize>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        let pending_rewards = match giveaway.stats().get(&user_id) {
+            Some(data) => data.pending_rewards(),
+            None => return Ok(None),
+        };
+
+        let guard_rewards = giveaway.read_rewards();
+        let reward_index = guard_rewards
             .iter()
-            .map(|pair| {
-                let user_id = pair.key().clone();
+            .position(|reward| pending_rewards.contains(&reward.id()))
+            .map(|position| position + 1);
 
-                let mut vec = Vec::new();
-                for reward_uuid in pair.value().pending_rewards() {
-                    vec.push((reward_uuid, user_id));
+        Ok(reward_index)
+    }
+
+    // Returns the wait times for the lock on the rewards of the giveaway, or
+    // `None` when the locks profiling is disabled.
+    pub fn get_lock_stats(&self, index: usize) -> Result<Option<LockStatsSnapshot>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        match giveaway.is_lock_profiling_enabled() {
+            true => Ok(Some(giveaway.lock_stats())),
+            false => Ok(None),
+        }
+    }
+
+    // Returns the receipt with the giveaway and reward numbers. The receipts
+    // are searched only in the giveaways managed by the user.
+    pub fn find_receipt(
+        &self,
+        user: &DiscordUser,
+        receipt_id: &str,
+    ) -> Result<(usize, Option<usize>, Receipt)> {
+        let found_receipt = self
+            .get_indexed_giveaways()
+            .into_iter()
+            .filter(|(_, giveaway)| giveaway.is_manager(user.id.0))
+            .find_map(|(index, giveaway)| {
+                giveaway
+                    .find_receipt(receipt_id)
+                    .map(|receipt| (index, giveaway, receipt))
+            });
+
+        match found_receipt {
+            Some((index, giveaway, receipt)) => {
+                let reward_index = giveaway
+                    .read_rewards()
+                    .iter()
+                    .position(|reward| reward.id() == receipt.reward_id())
+                    .map(|position| position + 1);
+                Ok((index, reward_index, receipt))
+            }
+            None => {
+                let message = format!("The requested receipt was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Returns the number of unused, pending and activated rewards in the giveaway.
+    // The expired rewards aren't counted, because nobody can claim them.
+    pub fn get_reward_counts(&self, index: usize) -> Result<(usize, usize, usize)> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+
+        let guard_rewards = giveaway.read_rewards();
+        let counts = guard_rewards.iter().fold(
+            (0, 0, 0),
+            |(unused, pending, activated), reward| match reward.object_state() {
+                ObjectState::Unused => (unused + 1, pending, activated),
+                ObjectState::Pending => (unused, pending + 1, activated),
+                ObjectState::Activated => (unused, pending, activated + 1),
+                ObjectState::Expired => (unused, pending, activated),
+            },
+        );
+
+        Ok(counts)
+    }
+
+    // Returns the number of claimed and remaining rewards per store, in the
+    // order the stores appear in the giveaway. Rewards without the store go
+    // to the "Other" group. The expired rewards aren't counted at all.
+    pub fn store_claim_stats(&self, index: usize) -> Result<Vec<(String, usize, usize)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+
+        let mut stats: Vec<(String, usize, usize)> = Vec::new();
+        for reward in giveaway.read_rewards().iter() {
+            let store = match reward.object_info() {
+                Some(info) => info
+                    .trim_matches(|c: char| c == '[' || c == ']' || c.is_whitespace())
+                    .to_string(),
+                None => format!("Other"),
+            };
+
+            let position = match stats.iter().position(|(name, _, _)| *name == store) {
+                Some(position) => position,
+                None => {
+                    stats.push((store, 0, 0));
+                    stats.len() - 1
                 }
+            };
 
-                vec
-            })
-            .flatten()
-            .collect()
+            match reward.object_state() {
+                ObjectState::Unused => stats[position].2 += 1,
+                ObjectState::Pending | ObjectState::Activated => stats[position].1 += 1,
+                ObjectState::Expired => {}
+            }
+        }
+
+        Ok(stats)
     }
 
-    fn extract_retrieved_rewards(
+    // Returns a one-line status of the giveaway, without listing the rewards.
+    pub fn giveaway_summary(&self, index: usize) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        let (unused, pending, activated) = self.get_reward_counts(index)?;
+        let state = match giveaway.is_activated() {
+            true => "active",
+            false => "paused",
+        };
+
+        Ok(format!(
+            "Giveaway #{} [{}] — owner <@{}>, {} rewards ({} claimed), {} participants",
+            index,
+            state,
+            giveaway.owner().get_user_id(),
+            unused + pending + activated,
+            pending + activated,
+            giveaway.stats().len()
+        ))
+    }
+
+    // Returns the summary of the certain giveaway for the owner's dashboard.
+    pub fn owner_summary(&self, index: usize) -> Result<GiveawaySummary> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        let (unused, pending, activated) = self.get_reward_counts(index)?;
+
+        Ok(GiveawaySummary {
+            index,
+            description: giveaway.description(),
+            rewards: unused + pending + activated,
+            remaining: unused,
+            claims: giveaway.claims(),
+            participants: giveaway.stats().len(),
+        })
+    }
+
+    // Returns the summaries of all giveaways owned by the user with the totals.
+    pub fn owner_dashboard(&self, user_id: u64) -> OwnerDashboard {
+        let mut dashboard = OwnerDashboard::default();
+        let mut participants = HashSet::new();
+        for (index, giveaway) in self.get_giveaways_by_owner(user_id) {
+            let summary = match self.owner_summary(index) {
+                Ok(summary) => summary,
+                Err(_) => continue,
+            };
+
+            participants.extend(giveaway.stats().iter().map(|pair| *pair.key()));
+            dashboard.total_rewards += summary.rewards;
+            dashboard.total_remaining += summary.remaining;
+            dashboard.total_claims += summary.claims;
+            dashboard.giveaways.push(summary);
+        }
+        dashboard.total_participants = participants.len();
+        dashboard
+    }
+
+    // Returns the rewards which were taken by participants, alongside with their
+    // numbers in the giveaway and the time when they were taken. The earliest
+    // claims go first.
+    pub fn get_claimed_rewards(
         &self,
-        stats: &Arc<DashMap<u64, ParticipantStats>>,
-    ) -> HashMap<Uuid, u64> {
-        stats
+        index: usize,
+    ) -> Result<Vec<(usize, Arc<Box<Reward>>, DateTime<Utc>)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+
+        let mut claimed_rewards = giveaway
+            .get_available_rewards()
             .iter()
-            .map(|pair| {
-                let user_id = pair.key().clone();
+            .enumerate()
+            .filter_map(|(position, reward)| match reward.claimed_at() {
+                Some(claimed_at) => Some((position + 1, reward.clone(), claimed_at)),
+                None => None,
+            })
+            .collect::<Vec<(usize, Arc<Box<Reward>>, DateTime<Utc>)>>();
+        claimed_rewards.sort_by_key(|(_, _, claimed_at)| *claimed_at);
 
-                let mut vec = Vec::new();
-                for reward_uuid in pair.value().retrieved_rewards() {
-                    vec.push((reward_uuid, user_id));
-                }
+        Ok(claimed_rewards)
+    }
 
-                vec
+    // Returns the aggregated participation numbers of the giveaway. The
+    // pending and retrieved rewards are counted as claims.
+    pub fn anonymous_stats(&self, index: usize) -> Result<AnonStats> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+
+        let stats = giveaway.stats();
+        let participants = stats.len();
+        let claims = stats
+            .iter()
+            .map(|pair| {
+                pair.value().pending_rewards().len() + pair.value().retrieved_rewards().len()
             })
-            .flatten()
-            .collect()
+            .sum::<usize>();
+        let rewards_remaining = giveaway
+            .get_available_rewards()
+            .iter()
+            .filter(|reward| reward.object_state() == ObjectState::Unused)
+            .count();
+        let average_claims = match participants {
+            0 => 0.0,
+            _ => claims as f64 / participants as f64,
+        };
+
+        Ok(AnonStats {
+            participants,
+            claims,
+            rewards_remaining,
+            average_claims,
+        })
     }
 
-    fn check_giveaway_owner(&self, user: &DiscordUser, giveaway: &Giveaway) -> Result<()> {
-        if user.id.0 != giveaway.owner().get_user_id() {
-            let message = format!("For interacting with this giveaway you need to be its owner.");
-            return Err(Error::from(ErrorKind::Giveaway(message)));
-        }
+    // Returns how long each user took to confirm the rolled rewards. Pre-orders
+    // are skipped, because they are confirmed instantly. Available only for the owner.
+    pub fn get_confirm_times(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+    ) -> Result<Vec<(u64, Duration)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-        Ok(())
+        let confirm_times = giveaway
+            .get_available_rewards()
+            .iter()
+            .filter(|reward| !reward.is_preorder())
+            .filter_map(|reward| {
+                let user_id = reward.confirmed_by()?;
+                let latency = reward.confirm_latency()?;
+                Some((user_id, latency))
+            })
+            .collect::<Vec<(u64, Duration)>>();
+
+        Ok(confirm_times)
     }
 
-    fn check_giveaway_is_active(&self, giveaway: &Giveaway) -> Result<()> {
-        if !giveaway.is_activated() {
-            let message =
-                format!("The giveaway hasn't started yet or has been suspended by the owner.");
-            return Err(Error::from(ErrorKind::Giveaway(message)));
+    // Returns reward values that were added to the giveaway more than once,
+    // alongside with the reward numbers. Available only for the owner.
+    pub fn duplicate_reward_values(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+    ) -> Result<Vec<(String, Vec<usize>)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let mut reward_indices: Vec<(String, Vec<usize>)> = Vec::new();
+        for (position, reward) in giveaway.get_available_rewards().iter().enumerate() {
+            let value = reward.value().to_string();
+            match reward_indices.iter_mut().find(|(key, _)| *key == value) {
+                Some((_, indices)) => indices.push(position + 1),
+                None => reward_indices.push((value, vec![position + 1])),
+            }
         }
 
-        Ok(())
+        let duplicates = reward_indices
+            .into_iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .collect::<Vec<(String, Vec<usize>)>>();
+        Ok(duplicates)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use serenity::model::id::UserId;
-    use serenity::model::user::{CurrentUser, User as DiscordUser};
+    // Parses the messages into the certain type of reward and adds to the certain
+    // giveaway. Owners can add rewards only for their own giveaways.
+    // Returns true when the added reward looks like a malformed key.
+    pub fn add_giveaway_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        data: &str,
+    ) -> Result<bool> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
 
-    use crate::commands::giveaway::manager::GiveawayManager;
-    use crate::commands::giveaway::models::{
-        Giveaway, ObjectState, Reward, OUTPUT_AFTER_GIVEAWAY_COMMANDS,
-    };
-    use crate::error::{Error, ErrorKind};
+        let reward = Reward::new(data);
+        giveaway.add_reward(&reward)?;
 
-    fn get_user(user_id: u64, username: &str) -> DiscordUser {
-        let mut current_user = CurrentUser::default();
-        current_user.id = UserId(user_id);
-        current_user.name = username.to_owned();
-        DiscordUser::from(current_user)
+        Ok(self.is_malformed_key(&reward))
+    }
+
+    // Parses the given message into multiple reward and then adds them to the
+    // certain giveaway. The separator is the `\n` (just a new line) for the
+    // each declared reward. Owners can add rewards only for their own giveaways.
+    // Returns the number of added rewards, the number of skipped duplicates and
+    // the number of added rewards that look like malformed keys.
+    pub fn add_multiple_giveaway_rewards(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        data: &str,
+    ) -> Result<(usize, usize, usize)> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        // Blank lines are skipped, so that they don't turn into empty rewards
+        let mut added = 0;
+        let mut skipped = 0;
+        let mut malformed = 0;
+        for raw_reward_data in data.split("\n").map(|line| line.trim()) {
+            if raw_reward_data.is_empty() {
+                continue;
+            }
+
+            let reward = Reward::new(raw_reward_data);
+            match giveaway.add_reward(&reward) {
+                Ok(_) => {
+                    added += 1;
+                    if self.is_malformed_key(&reward) {
+                        malformed += 1;
+                    }
+                }
+                Err(_) => skipped += 1,
+            }
+        }
+
+        Ok((added, skipped, malformed))
+    }
+
+    // Removed the giveaway from the certain giveaways. Owners can remove rewards
+    // only for their own giveaways.
+    pub fn remove_giveaway_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+        giveaway.remove_reward_by_index(reward_index)?;
+        Ok(())
+    }
+
+    // Makes the reward available only after activating the prerequisite reward.
+    pub fn set_reward_dependency(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+        prerequisite_index: usize,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let rewards = giveaway.get_available_rewards();
+        let get_reward = |position: usize| match position > 0 && position < rewards.len() + 1 {
+            true => Ok(rewards[position - 1].clone()),
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        };
+        let reward = get_reward(reward_index)?;
+        let prerequisite = get_reward(prerequisite_index)?;
+
+        // Walk through the chain of prerequisites to avoid rewards that
+        // can never be unlocked
+        let mut next_reward_id = Some(prerequisite.id());
+        while let Some(reward_id) = next_reward_id {
+            if reward_id == reward.id() {
+                let message = format!("The reward can't depend on itself, even indirectly.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+
+            next_reward_id = rewards
+                .iter()
+                .find(|obj| obj.id() == reward_id)
+                .and_then(|obj| obj.depends_on());
+        }
+
+        reward.set_depends_on(Some(prerequisite.id()));
+        Ok(())
+    }
+
+    // Limits the time when the certain reward can be claimed. Each side of
+    // the window can be left open.
+    pub fn set_reward_claim_window(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+        from: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        if let (Some(from), Some(until)) = (from, until) {
+            if from >= until {
+                let message = format!("The claiming window must end after it starts.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        }
+
+        let rewards = giveaway.get_available_rewards();
+        match reward_index > 0 && reward_index < rewards.len() + 1 {
+            true => {
+                rewards[reward_index - 1].set_claim_window(from, until);
+                Ok(())
+            }
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Returns a reward from the requested giveaway in according with the set strategy.
+    pub fn roll_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        raw_message: &str,
+        is_booster: bool,
+    ) -> Result<Option<String>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_is_active(&giveaway)?;
+        self.check_rewards_can_be_rolled(&giveaway)?;
+        self.check_rewards_limit_per_user(user, &giveaway)?;
+        self.check_participants_limit(user, &giveaway)?;
+        self.check_hand_out_limit(&giveaway)?;
+        let now = Instant::now();
+        self.check_roll_cooldown(user, &giveaway, now)?;
+
+        self.roll_reward_for_user(user, &giveaway, raw_message, is_booster, now, false)
+    }
+
+    // Rolls a reward for the user without checking the limits of the giveaway.
+    // The rerolls don't restart the roll cooldown and don't count as a new
+    // claim, because they replace the reward that was taken earlier.
+    fn roll_reward_for_user(
+        &self,
+        user: &DiscordUser,
+        giveaway: &Arc<Box<Giveaway>>,
+        raw_message: &str,
+        is_booster: bool,
+        now: Instant,
+        is_reroll: bool,
+    ) -> Result<Option<String>> {
+        giveaway.update_actions_processed();
+
+        let participant = Participant::from(user.clone());
+        let stats = giveaway.stats();
+        let rewards = giveaway.raw_rewards();
+        let restricted_rewards = self.get_restricted_rewards(giveaway, is_booster);
+        let roll_options = RollOptions::new(&participant, &rewards, raw_message, &stats)
+            .with_restricted_rewards(restricted_rewards);
+        let strategy = giveaway.strategy();
+        let selected_reward = strategy.roll(&roll_options)?;
+
+        let user_id = participant.get_user_id();
+        if !self.roll_reward_copy(giveaway, user_id, &selected_reward)? {
+            match stats.get_mut(&user_id) {
+                Some(mut data) => {
+                    self.get_next_reward_state_after_roll(&selected_reward, &mut data)?
+                }
+                None => {
+                    stats.insert(user_id, ParticipantStats::new());
+                    let mut data = stats.get_mut(&user_id).unwrap();
+                    self.get_next_reward_state_after_roll(&selected_reward, &mut data)?
+                }
+            };
+        }
+        self.metrics.increment_rolls();
+        if !is_reroll {
+            giveaway.register_roll(user_id, now);
+        }
+
+        if !is_reroll && giveaway.register_claim() {
+            giveaway.deactivate();
+        }
+
+        let response = strategy.to_message(selected_reward);
+        Ok(response)
+    }
+
+    // Returns the user's pending reward back to the giveaway and rolls a new one
+    // in according with the set strategy. If the new roll fails, the previous
+    // reward stays pending for the user, as it was before the call.
+    pub fn reroll_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+        raw_message: &str,
+        is_booster: bool,
+    ) -> Result<Option<String>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_is_active(&giveaway)?;
+        self.check_rewards_can_be_rolled(&giveaway)?;
+
+        let previous_reward = {
+            let guard_rewards = giveaway.read_rewards();
+
+            match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
+                true => guard_rewards[reward_index - 1].clone(),
+                false => {
+                    let message = format!("The requested reward was not found.");
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+            }
+        };
+
+        let user_id = user.id.0;
+        let stats = giveaway.stats();
+        match stats.get_mut(&user_id) {
+            Some(mut data) => {
+                let is_pending = previous_reward.object_state() == ObjectState::Pending
+                    && data.pending_rewards().contains(&previous_reward.id());
+                if !is_pending {
+                    let message = format!("Only your own pending reward can be rerolled.");
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+
+                previous_reward.try_set_object_state(ObjectState::Unused)?;
+                data.remove_pending_reward(previous_reward.id());
+            }
+            None => {
+                let message = format!("Only your own pending reward can be rerolled.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        };
+
+        let result = self.roll_reward_for_user(
+            user,
+            &giveaway,
+            raw_message,
+            is_booster,
+            Instant::now(),
+            true,
+        );
+        match result {
+            Ok(response) => Ok(response),
+            Err(err) => {
+                if previous_reward
+                    .try_set_object_state(ObjectState::Pending)
+                    .is_ok()
+                {
+                    if let Some(mut data) = stats.get_mut(&user_id) {
+                        data.add_pending_reward(previous_reward.id());
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+
+    // Adds the user to the entrants of the giveaway, which draws the winners.
+    pub fn enter_giveaway(&self, user: &DiscordUser, index: usize) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_is_active(&giveaway)?;
+
+        if !giveaway.strategy().requires_draw() {
+            let message = format!("This giveaway doesn't draw the winners among the entrants.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        match giveaway.add_entrant(Participant::from(user.clone())) {
+            true => Ok(()),
+            false => {
+                let message = format!("You have already entered this giveaway.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Hands out the unused rewards to the randomly picked entrants, one reward
+    // per entrant. The winners leave the list of entrants, while the others
+    // can win in the next draw. Returns the winners with the numbers of their
+    // rewards. Available for the owner and the moderators.
+    pub fn draw_giveaway(&self, user: &DiscordUser, index: usize) -> Result<Vec<(u64, usize)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+        self.check_giveaway_is_active(&giveaway)?;
+
+        let strategy = giveaway.strategy();
+        if !strategy.requires_draw() {
+            let message = format!("This giveaway doesn't draw the winners among the entrants.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        let entrants = giveaway.entrants();
+        if entrants.is_empty() {
+            let message = format!("Nobody has entered the giveaway yet.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        // Each identical prize of the reward goes to its own entrant.
+        let unused_prizes = giveaway
+            .read_rewards()
+            .iter()
+            .filter(|reward| reward.object_state() == ObjectState::Unused)
+            .map(|reward| reward.quantity() as usize)
+            .sum::<usize>();
+        let stats = giveaway.stats();
+        let rewards = giveaway.raw_rewards();
+        let mut winners = Vec::new();
+        for participant in draw_entrants(&entrants, unused_prizes, &mut rand::thread_rng()) {
+            let roll_options = RollOptions::new(&participant, &rewards, "", &stats);
+            let selected_reward = match strategy.roll(&roll_options) {
+                Ok(reward) => reward,
+                Err(_) => continue,
+            };
+
+            let user_id = participant.get_user_id();
+            let is_copy = match self.roll_reward_copy(&giveaway, user_id, &selected_reward) {
+                Ok(is_copy) => is_copy,
+                Err(_) => continue,
+            };
+            if !is_copy {
+                if !stats.contains_key(&user_id) {
+                    stats.insert(user_id, ParticipantStats::new());
+                }
+                let next_state = {
+                    let mut data = stats.get_mut(&user_id).unwrap();
+                    self.get_next_reward_state_after_roll(&selected_reward, &mut data)
+                };
+                if next_state.is_err() {
+                    continue;
+                }
+            }
+            self.metrics.increment_rolls();
+            giveaway.remove_entrant(user_id);
+
+            let reward_index = giveaway
+                .read_rewards()
+                .iter()
+                .position(|reward| reward.id() == selected_reward.id())
+                .map(|position| position + 1)
+                .unwrap_or_default();
+            winners.push((user_id, reward_index));
+
+            if giveaway.register_claim() {
+                giveaway.deactivate();
+                break;
+            }
+        }
+
+        giveaway.update_actions_processed();
+        Ok(winners)
+    }
+
+    // Returns the rewards that the user can't roll. The rewards reserved for the
+    // server boosters become available to everyone after handing out the rest.
+    fn get_restricted_rewards(&self, giveaway: &Giveaway, is_booster: bool) -> HashSet<Uuid> {
+        if is_booster {
+            return HashSet::new();
+        }
+
+        let reserved_rewards = giveaway.reserved_rewards();
+        let has_unreserved_rewards = giveaway.get_available_rewards().iter().any(|reward| {
+            reward.object_state() == ObjectState::Unused && !reserved_rewards.contains(&reward.id())
+        });
+
+        match has_unreserved_rewards {
+            true => reserved_rewards,
+            false => HashSet::new(),
+        }
+    }
+
+    // Hands out one of the identical prizes of the reward straight to the
+    // participant, while more than one prize is left. Each participant can
+    // get only one prize of the same reward. Returns false when the reward
+    // needs to be handed out in the regular way.
+    fn roll_reward_copy(
+        &self,
+        giveaway: &Giveaway,
+        user_id: u64,
+        reward: &Arc<Box<Reward>>,
+    ) -> Result<bool> {
+        if reward.total_quantity() <= 1 {
+            return Ok(false);
+        }
+
+        let stats = giveaway.stats();
+        let has_copy = match stats.get(&user_id) {
+            Some(data) => data.retrieved_rewards().contains(&reward.id()),
+            None => false,
+        };
+        if has_copy {
+            let message = format!("You have already got one of these rewards.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+        if !reward.take_copy() {
+            return Ok(false);
+        }
+
+        match stats.get_mut(&user_id) {
+            Some(mut data) => data.add_retrieved_reward(reward.id()),
+            None => {
+                let mut data = ParticipantStats::new();
+                data.add_retrieved_reward(reward.id());
+                stats.insert(user_id, data);
+            }
+        }
+        Ok(true)
+    }
+
+    // Returns a next state that needs to be set for the rolled reward. Also
+    // updates user's statistics for tracking what have been taken.
+    fn get_next_reward_state_after_roll(
+        &self,
+        reward: &Arc<Box<Reward>>,
+        user_data: &mut RefMut<u64, ParticipantStats>,
+    ) -> Result<ObjectState> {
+        match reward.is_preorder() {
+            // Any pre-order goes to activated instanly after the roll
+            true => {
+                reward.try_set_object_state(ObjectState::Activated)?;
+                user_data.add_retrieved_reward(reward.id());
+                Ok(ObjectState::Activated)
+            }
+            // All other types needs activated manually
+            false => {
+                reward.try_set_object_state(ObjectState::Pending)?;
+                user_data.add_pending_reward(reward.id());
+                Ok(ObjectState::Pending)
+            }
+        }
+    }
+
+    // Confirm that the reward was received and has been activated. Returns the
+    // identifier of the receipt, which can be referenced later in disputes.
+    pub fn confirm_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+    ) -> Result<String> {
+        self.confirm_reward_detailed(user, index, reward_index)
+            .map(|(_, receipt_id)| receipt_id)
+    }
+
+    // Same as `confirm_reward`, but also returns the activated reward, so that
+    // the user can be told what exactly was activated.
+    pub fn confirm_reward_detailed(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+    ) -> Result<(Arc<Box<Reward>>, String)> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_is_active(&giveaway)?;
+
+        giveaway.update_actions_processed();
+
+        let guard_rewards = giveaway.read_rewards();
+
+        match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
+            true => {
+                let participant = Participant::from(user.clone());
+                let stats = giveaway.stats();
+                let user_id = participant.get_user_id();
+                let selected_reward = guard_rewards[reward_index - 1].clone();
+
+                let user_stats = stats.get_mut(&user_id);
+                match user_stats {
+                    Some(mut data) => self.move_reward_to_retrieved(&mut data, &selected_reward)?,
+                    None => {
+                        stats.insert(user_id, ParticipantStats::new());
+                        let message = format!("The reward must be rolled before confirming.");
+                        return Err(Error::from(ErrorKind::Giveaway(message)));
+                    }
+                };
+
+                let receipt = Receipt::new(selected_reward.id(), user_id);
+                let receipt_id = receipt.id();
+                giveaway.add_receipt(receipt);
+                Ok((selected_reward, receipt_id))
+            }
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Return the certain reward to the unused state and cleanup the user's stats
+    pub fn deny_reward(&self, user: &DiscordUser, index: usize, reward_index: usize) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_is_active(&giveaway)?;
+
+        giveaway.update_actions_processed();
+
+        let guard_rewards = giveaway.read_rewards();
+
+        match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
+            true => {
+                let participant = Participant::from(user.clone());
+                let stats = giveaway.stats();
+                let user_id = participant.get_user_id();
+                let selected_reward = guard_rewards[reward_index - 1].clone();
+
+                let user_stats = stats.get_mut(&user_id);
+                match user_stats {
+                    Some(mut data) => self.rollback_reward_to_unused(&mut data, &selected_reward),
+                    None => {
+                        stats.insert(user_id, ParticipantStats::new());
+                        let message = format!("The reward must be rolled before return.");
+                        return Err(Error::from(ErrorKind::Giveaway(message)));
+                    }
+                }
+            }
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Marks the reward as expired, when it doesn't work anymore, regardless of
+    // its current state. The reward is removed from the pending and retrieved
+    // rewards of the participants. Available only for the owner.
+    pub fn expire_reward(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        reward_index: usize,
+    ) -> Result<()> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let guard_rewards = giveaway.read_rewards();
+        let selected_reward = match reward_index > 0 && reward_index < guard_rewards.len() + 1 {
+            true => guard_rewards[reward_index - 1].clone(),
+            false => {
+                let message = format!("The requested reward was not found.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        };
+
+        selected_reward.try_set_object_state(ObjectState::Expired)?;
+        for mut pair in giveaway.stats().iter_mut() {
+            pair.value_mut().remove_pending_reward(selected_reward.id());
+            pair.value_mut()
+                .remove_retrieved_reward(selected_reward.id());
+        }
+        Ok(())
+    }
+
+    // Returns the pending rewards, that were rolled earlier than the timeout
+    // before the given moment, to the unused state. The rewards are removed
+    // from the pending rewards of the participants. Returns the number of
+    // released rewards.
+    pub fn release_expired_pending(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        now: DateTime<Utc>,
+        timeout: Duration,
+    ) -> Result<usize> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let released_rewards = self.collect_stale_pending(&giveaway, now, timeout);
+        for mut pair in giveaway.stats().iter_mut() {
+            for (_, reward) in released_rewards.iter() {
+                pair.value_mut().remove_pending_reward(reward.id());
+            }
+        }
+        Ok(released_rewards.len())
+    }
+
+    // Denies the pending rewards that weren't confirmed in time, for the
+    // giveaways with the configured timeout. The rewards are returned to the
+    // giveaways. Returns the giveaway index, the reward number and the user
+    // of each denied reward, so the users can be notified.
+    pub fn auto_deny_stale_pending(&self, now: DateTime<Utc>) -> Vec<(usize, usize, u64)> {
+        let mut denied = Vec::new();
+        for (index, giveaway) in self.get_indexed_giveaways() {
+            let timeout = match giveaway.auto_deny_after() {
+                Some(timeout) => match Duration::from_std(timeout) {
+                    Ok(timeout) => timeout,
+                    Err(_) => continue,
+                },
+                None => continue,
+            };
+
+            let stale_rewards = self.collect_stale_pending(&giveaway, now, timeout);
+            for mut pair in giveaway.stats().iter_mut() {
+                let user_id = *pair.key();
+                for (reward_index, reward) in stale_rewards.iter() {
+                    if pair.value().pending_rewards().contains(&reward.id()) {
+                        pair.value_mut().remove_pending_reward(reward.id());
+                        denied.push((index, *reward_index, user_id));
+                    }
+                }
+            }
+            self.metrics.add_denies(stale_rewards.len() as u64);
+        }
+        denied
+    }
+
+    // Moves the pending rewards, that were rolled earlier than the timeout
+    // before the given moment, back to the unused state. Returns the numbers
+    // of the moved rewards in the giveaway alongside with the rewards.
+    fn collect_stale_pending(
+        &self,
+        giveaway: &Arc<Box<Giveaway>>,
+        now: DateTime<Utc>,
+        timeout: Duration,
+    ) -> Vec<(usize, Arc<Box<Reward>>)> {
+        let mut stale_rewards = giveaway
+            .read_rewards()
+            .iter()
+            .enumerate()
+            .filter(|(_, reward)| reward.object_state() == ObjectState::Pending)
+            .filter(|(_, reward)| match reward.claimed_at() {
+                Some(claimed_at) => now - claimed_at >= timeout,
+                None => false,
+            })
+            .map(|(position, reward)| (position + 1, reward.clone()))
+            .collect::<Vec<(usize, Arc<Box<Reward>>)>>();
+        stale_rewards
+            .retain(|(_, reward)| reward.try_set_object_state(ObjectState::Unused).is_ok());
+        stale_rewards
+    }
+
+    // Returns the number of retrieved rewards of each user across all
+    // giveaways, sorted by the number of rewards in descending order.
+    pub fn global_leaderboard(&self) -> Vec<(u64, usize)> {
+        let mut totals: HashMap<u64, usize> = HashMap::new();
+        for giveaway in self.get_giveaways() {
+            // Copy the counts first, so the stats aren't locked while summing.
+            let counts = giveaway
+                .stats()
+                .iter()
+                .map(|pair| (*pair.key(), pair.value().retrieved_rewards().len()))
+                .collect::<Vec<(u64, usize)>>();
+
+            for (user_id, count) in counts {
+                *totals.entry(user_id).or_insert(0) += count;
+            }
+        }
+
+        let mut leaderboard = totals
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .collect::<Vec<(u64, usize)>>();
+        leaderboard.sort_by(|(user_a, count_a), (user_b, count_b)| {
+            count_b.cmp(count_a).then(user_a.cmp(user_b))
+        });
+        leaderboard
+    }
+
+    // Checks that whether the certain giveaway needs to be printed out
+    pub fn is_required_state_output(&self, index: usize) -> Result<bool> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        Ok(giveaway.is_required_state_output())
+    }
+
+    // Returns the users who retrieved rewards in the giveaway, with the list of
+    // won rewards for each of them. Available only for the owner.
+    pub fn winner_mentions(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+    ) -> Result<Vec<(u64, Vec<String>)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let retrieved_rewards = self.extract_retrieved_rewards(&giveaway.stats());
+        let mut winners: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+        for reward in giveaway.get_available_rewards().iter() {
+            for user_id in retrieved_rewards.get(&reward.id()).into_iter().flatten() {
+                winners
+                    .entry(*user_id)
+                    .or_insert_with(Vec::new)
+                    .push(giveaway.pretty_print_reward(reward));
+            }
+        }
+
+        Ok(winners.into_iter().collect())
+    }
+
+    // Simulates the draw with the strategy of the giveaway among its unused
+    // rewards, without changing the real state. Available only for the owner.
+    pub fn simulate_giveaway(
+        &self,
+        user: &DiscordUser,
+        index: usize,
+        participants: usize,
+        iterations: u64,
+    ) -> Result<DistributionReport> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_owner(user, &giveaway)?;
+
+        let unused_rewards = giveaway
+            .get_available_rewards()
+            .into_iter()
+            .filter(|reward| reward.object_state() == ObjectState::Unused)
+            .collect::<Vec<Arc<Box<Reward>>>>();
+        let strategy = giveaway.strategy();
+        simulate(
+            strategy.as_ref().as_ref(),
+            &unused_rewards,
+            participants,
+            iterations,
+            rand::random::<u64>(),
+        )
+    }
+
+    // Returns each participant who retrieved rewards in the giveaway with the
+    // identifiers of those rewards. Available only for the owner.
+    pub fn get_winners(&self, user: &DiscordUser, index: usize) -> Result<Vec<(u64, Vec<Uuid>)>> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_owner(user, &giveaway)?;
+
+        let positions = giveaway
+            .get_available_rewards()
+            .iter()
+            .enumerate()
+            .map(|(position, reward)| (reward.id(), position))
+            .collect::<HashMap<Uuid, usize>>();
+
+        let mut winners = giveaway
+            .stats()
+            .iter()
+            .map(|pair| {
+                let mut rewards = pair
+                    .value()
+                    .retrieved_rewards()
+                    .into_iter()
+                    .filter(|reward_id| positions.contains_key(reward_id))
+                    .collect::<Vec<Uuid>>();
+                rewards.sort_by_key(|reward_id| positions[reward_id]);
+                (*pair.key(), rewards)
+            })
+            .filter(|(_, rewards)| !rewards.is_empty())
+            .collect::<Vec<(u64, Vec<Uuid>)>>();
+        winners.sort_by_key(|(user_id, _)| *user_id);
+
+        Ok(winners)
+    }
+
+    // Clears the references to the giveaway messages that don't exist anymore,
+    // which is checked by the `message_exists` function. Giveaways without the
+    // channel reference are skipped. Returns the number of cleared references.
+    pub fn clean_stale_messages<F>(&self, message_exists: F) -> usize
+    where
+        F: Fn(ChannelId, MessageId) -> bool,
+    {
+        let mut cleaned = 0;
+        for giveaway in self.get_giveaways() {
+            if let (Some(channel_id), Some(message_id)) =
+                (giveaway.get_channel_id(), giveaway.get_message_id())
+            {
+                if !message_exists(channel_id, message_id) {
+                    giveaway.set_message_id(None);
+                    cleaned += 1;
+                }
+            }
+        }
+        cleaned
+    }
+
+    // Checks that the states of the rewards match the participants' statistics
+    // for each giveaway. Returns the found discrepancies per giveaway index,
+    // skipping the giveaways without issues.
+    pub fn validate_all(&self) -> Vec<(usize, Vec<String>)> {
+        self.get_indexed_giveaways()
+            .iter()
+            .map(|(index, giveaway)| (*index, self.validate_consistency(giveaway)))
+            .filter(|(_, issues)| !issues.is_empty())
+            .collect()
+    }
+
+    // Returns a list of discrepancies between the rewards and the statistics.
+    fn validate_consistency(&self, giveaway: &Giveaway) -> Vec<String> {
+        let stats = giveaway.stats();
+        let rewards = giveaway.get_available_rewards();
+        let mut issues = Vec::new();
+
+        for (position, reward) in rewards.iter().enumerate() {
+            let reward_id = reward.id();
+            let pending_by = stats
+                .iter()
+                .filter(|pair| pair.value().pending_rewards().contains(&reward_id))
+                .count();
+            let retrieved_by = stats
+                .iter()
+                .filter(|pair| pair.value().retrieved_rewards().contains(&reward_id))
+                .count();
+
+            // The identical prizes handed out as copies are retrieved
+            // while the reward itself stays unused.
+            let copies = reward.taken_copies() as usize;
+            let is_consistent = match reward.object_state() {
+                ObjectState::Unused => pending_by == 0 && retrieved_by == copies,
+                ObjectState::Pending => pending_by == 1 && retrieved_by == copies,
+                ObjectState::Activated => pending_by == 0 && retrieved_by == copies + 1,
+                ObjectState::Expired => pending_by == 0 && retrieved_by == 0,
+            };
+            if !is_consistent {
+                issues.push(format!(
+                    "The reward #{} in the {} state is pending for {} and retrieved by {} user(s).",
+                    position + 1,
+                    reward.object_state().as_str(),
+                    pending_by,
+                    retrieved_by
+                ));
+            }
+        }
+
+        let reward_ids = rewards
+            .iter()
+            .map(|reward| reward.id())
+            .collect::<HashSet<Uuid>>();
+        for pair in stats.iter() {
+            let data = pair.value();
+            let unknown_rewards = data
+                .pending_rewards()
+                .union(&data.retrieved_rewards())
+                .filter(|reward_id| !reward_ids.contains(reward_id))
+                .count();
+            if unknown_rewards > 0 {
+                issues.push(format!(
+                    "The user <@{}> has {} reward(s) missing in the giveaway.",
+                    pair.key(),
+                    unknown_rewards
+                ));
+            }
+        }
+
+        issues
+    }
+
+    // Returns a pretty print of the giveaway state
+    pub fn pretty_print_giveaway(&self, giveaway_index: usize) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(giveaway_index)?;
+        let (header, rewards) = self.pretty_print_giveaway_parts(giveaway_index, &giveaway);
+
+        let rewards_output = match rewards.is_empty() {
+            true => giveaway.empty_message(),
+            false => rewards
+                .into_iter()
+                .map(|(_, reward_output)| reward_output)
+                .collect::<Vec<String>>()
+                .join("\n"),
+        };
+
+        let response = format!("{}\n{}", header, rewards_output);
+        Ok(response)
+    }
+
+    // Returns the rewards of the giveaway as a fixed-width table, wrapped into
+    // the code block. The values are masked in according with the settings of
+    // the giveaway. Available only for the owner.
+    pub fn export_giveaway_table(&self, user: &DiscordUser, index: usize) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let mut exporter =
+            AsciiTableExporter::new(&["#", "State", "Value", "Store", "Description"]);
+        for (position, reward) in giveaway.get_available_rewards().iter().enumerate() {
+            exporter.add_row(vec![
+                format!("{}", position + 1),
+                format!("{:?}", reward.object_state()),
+                giveaway.masked_reward_value(reward),
+                reward.object_info().unwrap_or_default(),
+                reward.description().unwrap_or_default().trim().to_string(),
+            ]);
+        }
+
+        Ok(format!("```\n{}\n```", exporter.render()))
+    }
+
+    // Returns the rewards of the giveaway as tab-separated values with the
+    // ids of users who claimed them. The unclaimed keys are masked in according
+    // with the settings of the giveaway. Available only for the owner.
+    pub fn export_giveaway_tsv(&self, user: &DiscordUser, index: usize) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let retrieved_rewards = self.extract_retrieved_rewards(&giveaway.stats());
+        let mut exporter =
+            TsvExporter::new(&["#", "State", "Value", "Store", "Description", "Claimer"]);
+        for (position, reward) in giveaway.get_available_rewards().iter().enumerate() {
+            let claimer = retrieved_rewards
+                .get(&reward.id())
+                .map(|user_ids| {
+                    user_ids
+                        .iter()
+                        .map(|user_id| user_id.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            exporter.add_row(vec![
+                format!("{}", position + 1),
+                format!("{:?}", reward.object_state()),
+                giveaway.masked_reward_value(reward),
+                reward.object_info().unwrap_or_default(),
+                reward.description().unwrap_or_default().trim().to_string(),
+                claimer,
+            ]);
+        }
+
+        Ok(exporter.render())
+    }
+
+    // Returns the rewards of the giveaway as a JSON array for dashboards. The
+    // unused keys are masked. Available only for the owner.
+    pub fn export_giveaway_json(&self, user: &DiscordUser, index: usize) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(index)?;
+        self.check_giveaway_manager(user, &giveaway)?;
+
+        let formatter = JsonRewardFormatter::new();
+        let rewards = giveaway
+            .read_rewards()
+            .iter()
+            .map(|reward| formatter.pretty_print(reward))
+            .collect::<Vec<String>>();
+        Ok(format!("[{}]", rewards.join(",")))
+    }
+
+    // Returns a pretty print of the giveaway state, where the rewards are
+    // grouped by their type. Each reward keeps its number in the giveaway.
+    // Until the giveaway is started, it's available only for the owner and
+    // the moderators.
+    pub fn pretty_print_giveaway_grouped(
+        &self,
+        user: &DiscordUser,
+        giveaway_index: usize,
+    ) -> Result<String> {
+        let giveaway = self.get_giveaway_by_index(giveaway_index)?;
+        if !giveaway.is_activated() && !giveaway.is_manager(user.id.0) {
+            let message = format!("The giveaway hasn't started yet.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        let (header, rewards) = self.pretty_print_giveaway_parts(giveaway_index, &giveaway);
+        if rewards.is_empty() {
+            return Ok(format!("{}\n{}", header, giveaway.empty_message()));
+        }
+
+        let mut groups: Vec<(&str, Vec<String>)> = vec![
+            ("Keys", Vec::new()),
+            ("Links", Vec::new()),
+            ("Other", Vec::new()),
+        ];
+        for (reward, reward_output) in rewards {
+            let position = match reward.object_type() {
+                ObjectType::Key | ObjectType::KeyPreorder => 0,
+                ObjectType::Other if self.is_link(&reward) => 1,
+                ObjectType::Other => 2,
+            };
+            groups[position].1.push(reward_output);
+        }
+
+        let groups_output = groups
+            .into_iter()
+            .filter(|(_, items)| !items.is_empty())
+            .map(|(title, items)| format!("{}:\n{}", title, items.join("\n")))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let response = format!("{}\n{}", header, groups_output);
+        Ok(response)
+    }
+
+    // The keys with an unusual shape are still added, but the owner is warned
+    // about them.
+    fn is_malformed_key(&self, reward: &Reward) -> bool {
+        reward.object_type() == ObjectType::Key && !is_well_formed_key(&reward.value())
+    }
+
+    // Checks that the reward is a link to claim something.
+    fn is_link(&self, reward: &Arc<Box<Reward>>) -> bool {
+        let value = reward.value();
+        value.starts_with("http://") || value.starts_with("https://")
+    }
+
+    // Returns the header of the giveaway state and the pretty print of each
+    // reward in the order as they were added.
+    fn pretty_print_giveaway_parts(
+        &self,
+        giveaway_index: usize,
+        giveaway: &Arc<Box<Giveaway>>,
+    ) -> (String, Vec<(Arc<Box<Reward>>, String)>) {
+        let stats = giveaway.stats();
+
+        let pending_rewards = self.extract_pending_rewards(&stats);
+        let retrieved_rewards = self.extract_retrieved_rewards(&stats);
+
+        let guard_rewards = giveaway.read_rewards();
+        let total_rewards = guard_rewards.len();
+        let remaining_rewards = guard_rewards
+            .iter()
+            .filter(|reward| reward.object_state() == ObjectState::Unused)
+            .count();
+
+        let rewards_output = guard_rewards
+            .iter()
+            .enumerate()
+            .map(|(index, reward)| {
+                let reward_id = reward.id();
+                let reward_output = giveaway.pretty_print_reward(reward);
+                if let Some(user_id) = pending_rewards.get(&reward_id) {
+                    return format!(
+                        "{}. {}  [taken by <@{}>]",
+                        index + 1,
+                        reward_output,
+                        user_id
+                    );
+                }
+                // The rewards with the identical prizes show how many of them
+                // are left instead of the holders.
+                if reward.taken_copies() > 0 {
+                    let left = match reward.object_state() {
+                        ObjectState::Unused => reward.quantity(),
+                        _ => 0,
+                    };
+                    return format!(
+                        "{}. {}  ({}/{} left)",
+                        index + 1,
+                        reward_output,
+                        left,
+                        reward.total_quantity()
+                    );
+                }
+                match retrieved_rewards
+                    .get(&reward_id)
+                    .and_then(|ids| ids.first())
+                {
+                    Some(user_id) => format!(
+                        "{}. {}  [activated by <@{}>]",
+                        index + 1,
+                        reward_output,
+                        user_id
+                    ),
+                    None => format!("{}. {}", index + 1, reward_output),
+                }
+            })
+            .collect::<Vec<String>>();
+
+        let header = format!(
+            "Giveaway #{}: ({}/{} left)",
+            giveaway_index, remaining_rewards, total_rewards
+        );
+        let rewards = guard_rewards
+            .iter()
+            .cloned()
+            .zip(rewards_output)
+            .collect::<Vec<(Arc<Box<Reward>>, String)>>();
+        (header, rewards)
+    }
+
+    // A special wrapper to help with moving the reward in the retrieved group in stats
+    fn move_reward_to_retrieved(
+        &self,
+        data: &mut RefMut<u64, ParticipantStats>,
+        reward: &Arc<Box<Reward>>,
+    ) -> Result<()> {
+        let pending_rewards = data.pending_rewards();
+
+        match reward.object_state() {
+            ObjectState::Activated => {
+                let message = format!("The reward has been activated already.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+            ObjectState::Pending => match pending_rewards.contains(&reward.id()) {
+                true => {
+                    reward.try_set_object_state(ObjectState::Activated)?;
+                    reward.set_confirmed_by(*data.key());
+                    data.remove_pending_reward(reward.id());
+                    data.add_retrieved_reward(reward.id());
+                    self.metrics.increment_confirms();
+                    Ok(())
+                }
+                false => {
+                    let message = format!("This reward can't be activated by others.");
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+            },
+            ObjectState::Unused => {
+                let message = format!("The reward must be rolled before confirming.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+            ObjectState::Expired => {
+                let message = format!("The reward has expired.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        }
+    }
+
+    fn rollback_reward_to_unused(
+        &self,
+        data: &mut RefMut<u64, ParticipantStats>,
+        reward: &Arc<Box<Reward>>,
+    ) -> Result<()> {
+        let pending_rewards = data.pending_rewards();
+
+        match reward.object_state() {
+            ObjectState::Activated => {
+                let message = format!("The reward has been activated already.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+            ObjectState::Pending => match pending_rewards.contains(&reward.id()) {
+                true => {
+                    reward.try_set_object_state(ObjectState::Unused)?;
+                    data.remove_pending_reward(reward.id());
+                    self.metrics.increment_denies();
+                    Ok(())
+                }
+                false => {
+                    let message = format!("This reward can't be returned by others.");
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+            },
+            ObjectState::Unused => {
+                let message = format!("The reward must be rolled before return.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+            ObjectState::Expired => {
+                let message = format!("The reward has expired.");
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        }
+    }
+
+    fn extract_pending_rewards(
+        &self,
+        stats: &Arc<DashMap<u64, ParticipantStats>>,
+    ) -> HashMap<Uuid, u64> {
+        stats
+            .iter()
+            .map(|pair| {
+                let user_id = pair.key().clone();
+
+                let mut vec = Vec::new();
+                for reward_uuid in pair.value().pending_rewards() {
+                    vec.push((reward_uuid, user_id));
+                }
+
+                vec
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn extract_retrieved_rewards(
+        &self,
+        stats: &Arc<DashMap<u64, ParticipantStats>>,
+    ) -> HashMap<Uuid, Vec<u64>> {
+        // The identical prizes of the same reward can be retrieved by
+        // multiple users, so each reward maps onto all of its holders.
+        let mut retrieved_rewards: HashMap<Uuid, Vec<u64>> = HashMap::new();
+        for pair in stats.iter() {
+            for reward_uuid in pair.value().retrieved_rewards() {
+                retrieved_rewards
+                    .entry(reward_uuid)
+                    .or_insert_with(Vec::new)
+                    .push(*pair.key());
+            }
+        }
+        for user_ids in retrieved_rewards.values_mut() {
+            user_ids.sort();
+        }
+        retrieved_rewards
+    }
+
+    // New participants can't roll after the limit of distinct participants
+    // was reached, while the existing ones are still allowed to.
+    fn check_participants_limit(&self, user: &DiscordUser, giveaway: &Giveaway) -> Result<()> {
+        let limit = match giveaway.max_participants() {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let stats = giveaway.stats();
+        if !stats.contains_key(&user.id.0) && stats.len() >= limit {
+            let message = format!("This giveaway has reached its participant limit.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_roll_cooldown(
+        &self,
+        user: &DiscordUser,
+        giveaway: &Giveaway,
+        now: Instant,
+    ) -> Result<()> {
+        if giveaway.roll_cooldown_remaining(user.id.0, now).is_some() {
+            let message = format!("Please wait before rolling again.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_hand_out_limit(&self, giveaway: &Giveaway) -> Result<()> {
+        let limit = match giveaway.hand_out_limit() {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        // The copies of the identical prizes are handed out while the reward
+        // itself stays unused, so they're counted separately.
+        let handed_out = giveaway
+            .read_rewards()
+            .iter()
+            .map(|reward| match reward.object_state() {
+                ObjectState::Pending | ObjectState::Activated => reward.taken_copies() + 1,
+                _ => reward.taken_copies(),
+            })
+            .sum::<u64>();
+        if handed_out >= limit {
+            let message = format!("The giveaway limit has been reached.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_rewards_limit_per_user(&self, user: &DiscordUser, giveaway: &Giveaway) -> Result<()> {
+        let limit = match giveaway.max_rewards_per_user() {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let rolled_rewards = match giveaway.stats().get(&user.id.0) {
+            Some(data) => data
+                .pending_rewards()
+                .union(&data.retrieved_rewards())
+                .count(),
+            None => 0,
+        };
+
+        if rolled_rewards >= limit {
+            let message =
+                format!("You've reached the maximum number of rewards for this giveaway.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_giveaway_owner(&self, user: &DiscordUser, giveaway: &Giveaway) -> Result<()> {
+        if user.id.0 != giveaway.owner().get_user_id() {
+            let message = format!("For interacting with this giveaway you need to be its owner.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    // Same as `check_giveaway_owner`, but also allows the moderators of the giveaway.
+    fn check_giveaway_manager(&self, user: &DiscordUser, giveaway: &Giveaway) -> Result<()> {
+        if !giveaway.is_manager(user.id.0) {
+            let message = format!("For interacting with this giveaway you need to be its owner.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_no_active_giveaway_in_channel(
+        &self,
+        index: usize,
+        channel_id: ChannelId,
+    ) -> Result<()> {
+        let conflicting_index = self
+            .get_indexed_giveaways()
+            .iter()
+            .find(|(position, giveaway)| {
+                *position != index
+                    && giveaway.is_activated()
+                    && giveaway.get_channel_id() == Some(channel_id)
+            })
+            .map(|(position, _)| *position);
+
+        if let Some(conflicting_index) = conflicting_index {
+            let message = format!(
+                "The giveaway #{} is already active in this channel. Finish or \
+                deactivate it first, or use the `force` option to start anyway.",
+                conflicting_index
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_rewards_can_be_rolled(&self, giveaway: &Giveaway) -> Result<()> {
+        if giveaway.strategy().requires_draw() {
+            let message = format!(
+                "The winners of this giveaway are drawn among the entrants, use the `genter` command to participate."
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+
+    fn check_giveaway_is_active(&self, giveaway: &Giveaway) -> Result<()> {
+        if !giveaway.is_activated() {
+            let message =
+                format!("The giveaway hasn't started yet or has been suspended by the owner.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+    use std::time::{Duration as StdDuration, Instant};
+
+    use chrono::{Duration, TimeZone, Utc};
+    use serenity::model::id::{ChannelId, MessageId, UserId};
+    use serenity::model::user::{CurrentUser, User as DiscordUser};
+
+    use crate::commands::giveaway::dto::AnonStats;
+    use crate::commands::giveaway::manager::GiveawayManager;
+    use crate::commands::giveaway::models::{
+        Giveaway, ObjectState, ParticipantStats, Reward, OUTPUT_AFTER_GIVEAWAY_COMMANDS,
+    };
+    use crate::commands::giveaway::strategies::{EntryLotteryStrategy, RandomSelectStrategy};
+    use crate::error::{Error, ErrorKind};
+
+    fn get_user(user_id: u64, username: &str) -> DiscordUser {
+        let mut current_user = CurrentUser::default();
+        current_user.id = UserId(user_id);
+        current_user.name = username.to_owned();
+        DiscordUser::from(current_user)
+    }
+
+    #[test]
+    fn test_read_an_new_state() {
+        let manager = GiveawayManager::new();
+        let giveaways = manager.get_giveaways();
+
+        assert_eq!(giveaways.len(), 0);
+    }
+
+    #[test]
+    fn test_read_after_giveaway_update() {
+        let manager = GiveawayManager::new();
+
+        let mut giveaways = manager.get_giveaways();
+        assert_eq!(giveaways.len(), 0);
+
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        giveaways = manager.get_giveaways();
+        assert_eq!(giveaways.len(), 1);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_read() {
+        let manager = GiveawayManager::new();
+
+        let result = manager.get_giveaway_by_index(10);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_import_giveaways() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let data = r#"[
+            {"description": "Steam keys", "rewards": ["AAAAA-BBBBB-CCCCC", "Some text"]},
+            {"rewards": ["AAAAA-BBBBB-DDDDD"]},
+            {"description": "Without rewards"},
+            {"description": "   "},
+            {"description": "Empty reward", "rewards": [""]},
+            42
+        ]"#;
+
+        let report = manager.import_giveaways(&user, data).unwrap();
+        assert_eq!(report.imported, 2);
+        assert_eq!(
+            report
+                .failed
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<usize>>(),
+            vec![2, 4, 5, 6]
+        );
+        assert_eq!(
+            report.failed[1].1,
+            "The giveaway description must not be empty."
+        );
+        assert_eq!(
+            report.failed[2].1,
+            "The giveaway rewards must not be empty."
+        );
+
+        let giveaways = manager.get_giveaways();
+        assert_eq!(giveaways.len(), 2);
+        assert_eq!(giveaways[0].pretty_print(), "Steam keys [owner: <@1>]");
+        assert_eq!(giveaways[0].get_available_rewards().len(), 2);
+        assert_eq!(giveaways[1].pretty_print(), "Without rewards [owner: <@1>]");
+        assert_eq!(giveaways[1].get_available_rewards().len(), 0);
+    }
+
+    #[test]
+    fn test_get_error_for_not_an_array_on_import_giveaways() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+
+        let result = manager.import_giveaways(&user, r#"{"description": "Steam keys"}"#);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(manager.get_giveaways().len(), 0);
+    }
+
+    #[test]
+    fn test_update_giveaway_description() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("tset giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.update_giveaway_description(&user, 1, "test giveaway");
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.description(), "test giveaway");
+        assert_eq!(giveaway.pretty_print(), "test giveaway [owner: <@1>]");
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_update_giveaway_description() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.update_giveaway_description(&user, 1, "my giveaway");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.description(), "test giveaway");
+    }
+
+    #[test]
+    fn test_update_giveaway_reward_description_keeps_pending_state() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC -> Wrong game"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let reward_id = giveaway.get_available_rewards()[0].id();
+        let result = manager.update_giveaway_reward_description(&owner, 1, 1, "Right game");
+        assert_eq!(result.is_ok(), true);
+
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].id(), reward_id);
+        assert_eq!(rewards[0].description(), Some(format!("Right game")));
+        assert_eq!(rewards[0].object_state(), ObjectState::Pending);
+        assert_eq!(manager.confirm_reward(&user, 1, 1).is_ok(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_update_giveaway_reward_description() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.update_giveaway_reward_description(&user, 1, 1, "Some game");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_set_and_resolve_giveaway_alias() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&owner).with_description("second"));
+
+        let result = manager.set_giveaway_alias(&user, "fav", 2);
+        assert_eq!(result.is_ok(), true);
+
+        assert_eq!(manager.resolve_giveaway_index(&user, "fav").unwrap(), 2);
+        assert_eq!(manager.resolve_giveaway_index(&user, "1").unwrap(), 1);
+
+        let result = manager.resolve_giveaway_index(&owner, "fav");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The `fav` alias is not defined."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_set_giveaway_alias() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+
+        let result = manager.set_giveaway_alias(&user, "fav", 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+
+        manager.add_giveaway(Giveaway::new(&user).with_description("first"));
+        let result = manager.set_giveaway_alias(&user, "2", 1);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn test_set_giveaway_blind_mode() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game"))
+            .unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.set_giveaway_blind_mode(&owner, 1, true);
+        assert_eq!(result.is_ok(), true);
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(output, "Giveaway #1: (1/1 left)\n1. [ ] ???");
+
+        manager.set_giveaway_blind_mode(&owner, 1, false).unwrap();
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(
+            output,
+            "Giveaway #1: (1/1 left)\n1. [ ] AAAAA-BBBBB-CCCCC-xxxx [Store]"
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_set_giveaway_blind_mode() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.set_giveaway_blind_mode(&user, 1, true);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_set_giveaway_adaptive_output() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.set_giveaway_adaptive_output(&owner, 1, true);
+        assert_eq!(result.is_ok(), true);
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.is_adaptive_output(), true);
+
+        manager
+            .set_giveaway_adaptive_output(&owner, 1, false)
+            .unwrap();
+        assert_eq!(giveaway.is_adaptive_output(), false);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_set_giveaway_adaptive_output() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.set_giveaway_adaptive_output(&user, 1, true);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_delete_giveaway() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.delete_giveaway(&user, 1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), ());
+    }
+
+    #[test]
+    fn test_deleted_giveaway_is_not_listed() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        manager.add_giveaway(Giveaway::new(&user).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&user).with_description("second"));
+
+        manager.delete_giveaway(&user, 1).unwrap();
+
+        let giveaways = manager.get_giveaways();
+        assert_eq!(giveaways.len(), 1);
+        assert_eq!(giveaways[0].pretty_print(), "second [owner: <@1>]");
+
+        let indexed_giveaways = manager.get_indexed_giveaways();
+        assert_eq!(indexed_giveaways.len(), 1);
+        assert_eq!(indexed_giveaways[0].0, 2);
+    }
+
+    #[test]
+    fn test_owner_note_is_visible_only_to_the_owner_and_cohosts() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        let user = get_user(3, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        manager.add_giveaway(giveaway);
+        manager.toggle_cohost(&owner, 1, cohost.id.0).unwrap();
+        manager
+            .set_reward_owner_note(&owner, 1, 1, "from the bundle")
+            .unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let formatter = giveaway.reward_formatter();
+        let rewards = manager.get_giveaway_rewards(&cohost, 1).unwrap();
+        assert_eq!(
+            formatter.debug_print(&rewards[0]),
+            "AAAAA-BBBBB-CCCCC [Steam] -> Some game (note: from the bundle)"
+        );
+        assert_eq!(
+            formatter
+                .pretty_print(&rewards[0])
+                .contains("from the bundle"),
+            false
+        );
+        assert_eq!(manager.get_giveaway_rewards(&user, 1).is_err(), true);
+        assert_eq!(
+            manager.set_reward_owner_note(&user, 1, 1, "").is_err(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_get_user_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let another_user = get_user(3, "Another");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Game #1"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Steam] -> Game #2"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("GGGGG-HHHHH-IIIII [Steam] -> Game #3"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.confirm_reward(&user, 1, 1).unwrap();
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        manager.roll_reward(&another_user, 1, "3", false).unwrap();
+
+        let (pending, retrieved) = manager.get_user_rewards(&user, 1).unwrap();
+        assert_eq!(
+            pending,
+            vec![format!("2. DDDDD-EEEEE-FFFFF [Steam] -> Game #2")]
+        );
+        assert_eq!(
+            retrieved,
+            vec![format!("1. AAAAA-BBBBB-CCCCC [Steam] -> Game #1")]
+        );
+
+        let (pending, retrieved) = manager.get_user_rewards(&owner, 1).unwrap();
+        assert_eq!(pending.is_empty(), true);
+        assert_eq!(retrieved.is_empty(), true);
+    }
+
+    #[test]
+    fn test_clone_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("weekly giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Game #1"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("3x reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+
+        let result = manager.clone_giveaway(&owner, 1);
+        assert_eq!(result.unwrap(), 2);
+
+        let source = manager.get_giveaway_by_index(1).unwrap();
+        let cloned = manager.get_giveaway_by_index(2).unwrap();
+        let source_rewards = source.get_available_rewards();
+        let cloned_rewards = cloned.get_available_rewards();
+        assert_eq!(cloned.description(), "weekly giveaway");
+        assert_eq!(cloned.is_activated(), false);
+        assert_eq!(cloned.stats().is_empty(), true);
+        assert_eq!(cloned_rewards.len(), source_rewards.len());
+        for (source_reward, cloned_reward) in source_rewards.iter().zip(cloned_rewards.iter()) {
+            assert_ne!(cloned_reward.id(), source_reward.id());
+            assert_eq!(cloned_reward.value(), source_reward.value());
+            assert_eq!(cloned_reward.object_state(), ObjectState::Unused);
+        }
+        assert_eq!(source_rewards[0].object_state(), ObjectState::Pending);
+        assert_eq!(cloned_rewards[1].quantity(), 3);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_clone_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("weekly giveaway"));
+
+        let result = manager.clone_giveaway(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+        assert_eq!(manager.get_giveaways().len(), 1);
+    }
+
+    #[test]
+    fn test_start_scheduled_giveaways() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let now = Instant::now();
+        let giveaway = Giveaway::new(&user).with_description("scheduled");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+        manager.add_giveaway(Giveaway::new(&user).with_description("not scheduled"));
+
+        manager
+            .schedule_giveaway(&user, 1, ChannelId(1), now + StdDuration::from_secs(60))
+            .unwrap();
+        assert_eq!(manager.start_scheduled_giveaways(now).len(), 0);
+
+        let started = manager.start_scheduled_giveaways(now + StdDuration::from_secs(60));
+        assert_eq!(started.len(), 1);
+        assert_eq!(started[0].0, 1);
+        assert_eq!(started[0].1.is_activated(), true);
+        assert_eq!(started[0].1.scheduled_start(), None);
+        assert_eq!(
+            manager.get_giveaway_by_index(2).unwrap().is_activated(),
+            false
+        );
+    }
+
+    #[test]
+    fn test_cancel_schedule() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let now = Instant::now();
+        let giveaway = Giveaway::new(&user).with_description("scheduled");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        manager
+            .schedule_giveaway(&user, 1, ChannelId(1), now + StdDuration::from_secs(60))
+            .unwrap();
+        manager.cancel_schedule(&user, 1).unwrap();
+
+        let started = manager.start_scheduled_giveaways(now + StdDuration::from_secs(60));
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(started.len(), 0);
+        assert_eq!(giveaway.is_activated(), false);
+        assert_eq!(giveaway.scheduled_start(), None);
+    }
+
+    #[test]
+    fn test_get_error_for_cancel_schedule_without_schedule() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        manager.add_giveaway(Giveaway::new(&user).with_description("not scheduled"));
+
+        let result = manager.cancel_schedule(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway doesn't have a scheduled start."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_cancel_schedule() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("scheduled"));
+        manager
+            .schedule_giveaway(&owner, 1, ChannelId(1), Instant::now())
+            .unwrap();
+
+        let result = manager.cancel_schedule(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            manager
+                .get_giveaway_by_index(1)
+                .unwrap()
+                .scheduled_start()
+                .is_some(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_finish_expired_giveaways() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        manager.add_giveaway(Giveaway::new(&user).with_description("without duration"));
+        manager.add_giveaway(
+            Giveaway::new(&user)
+                .with_description("with duration")
+                .with_duration(StdDuration::from_secs(60)),
+        );
+
+        let finished = manager.finish_expired_giveaways(Instant::now());
+        assert_eq!(finished.len(), 0);
+
+        let now = Instant::now() + StdDuration::from_secs(61);
+        let finished = manager.finish_expired_giveaways(now);
+        assert_eq!(finished.len(), 1);
+        assert_eq!(finished[0].0.is_deleted(), true);
+        assert_eq!(finished[0].1.starts_with("Giveaway #2:"), true);
+
+        let indexed_giveaways = manager.get_indexed_giveaways();
+        assert_eq!(indexed_giveaways.len(), 1);
+        assert_eq!(indexed_giveaways[0].0, 1);
+        assert_eq!(manager.finish_expired_giveaways(now).len(), 0);
+    }
+
+    #[test]
+    fn test_moderator_can_add_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let moderator = get_user(2, "Moderator");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        manager.add_moderator(&owner, 1, moderator.id.0).unwrap();
+
+        let result = manager.add_giveaway_reward(&moderator, 1, "AAAAA-BBBBB -> Some game");
+        assert_eq!(result.is_ok(), true);
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.get_available_rewards().len(), 1);
+    }
+
+    #[test]
+    fn test_moderator_cannot_delete_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let moderator = get_user(2, "Moderator");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        manager.add_moderator(&owner, 1, moderator.id.0).unwrap();
+
+        let result = manager.delete_giveaway(&moderator, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For deleting this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_non_owner_on_add_moderator() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let moderator = get_user(2, "Moderator");
+        let user = get_user(3, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+        manager.add_moderator(&owner, 1, moderator.id.0).unwrap();
+
+        let result = manager.add_moderator(&moderator, 1, user.id.0);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+        let result = manager.add_giveaway_reward(&user, 1, "AAAAA-BBBBB -> Some game");
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn test_shuffle_rewards_keeps_the_same_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        for number in 1..=20 {
+            let reward = Reward::new(&format!("reward #{}", number));
+            giveaway.add_reward(&reward).unwrap();
+        }
+        manager.add_giveaway(giveaway);
+        let get_values = || {
+            manager
+                .get_giveaway_by_index(1)
+                .unwrap()
+                .get_available_rewards()
+                .iter()
+                .map(|reward| reward.value().to_string())
+                .collect::<Vec<String>>()
+        };
+        let mut old_values = get_values();
+
+        let result = manager.shuffle_rewards(&owner, 1);
+        assert_eq!(result.is_ok(), true);
+
+        let mut new_values = get_values();
+        old_values.sort();
+        new_values.sort();
+        assert_eq!(new_values, old_values);
+    }
+
+    #[test]
+    fn test_get_error_for_shuffle_rewards_in_active_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.shuffle_rewards(&owner, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The rewards can't be shuffled while the giveaway is active, because their numbers would change for the participants."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_shuffle_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.shuffle_rewards(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_clear_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.clear_giveaway_rewards(&owner, 1);
+        assert_eq!(result.unwrap(), 3);
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.get_available_rewards().is_empty(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_clear_giveaway_rewards_in_active_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.clear_giveaway_rewards(&owner, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The rewards can't be cleared while the giveaway is active. Deactivate the giveaway first."
+            )))
+        );
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.get_available_rewards().len(), 1);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_clear_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.clear_giveaway_rewards(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_cohost_can_manage_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.toggle_cohost(&owner, 1, cohost.id.0);
+        assert_eq!(result.unwrap(), true);
+
+        manager
+            .add_giveaway_reward(&cohost, 1, "AAAAA-BBBBB -> Some game")
+            .unwrap();
+        let result = manager.remove_giveaway_reward(&cohost, 1, 1);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_cohost_can_schedule_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        let now = Instant::now();
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+        manager.toggle_cohost(&owner, 1, cohost.id.0).unwrap();
+
+        let result =
+            manager.schedule_giveaway(&cohost, 1, ChannelId(1), now + StdDuration::from_secs(60));
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(
+            manager.get_giveaway_by_index(1).unwrap().scheduled_start(),
+            Some(now + StdDuration::from_secs(60))
+        );
+
+        let result = manager.cancel_schedule(&cohost, 1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(
+            manager.get_giveaway_by_index(1).unwrap().scheduled_start(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cohost_cannot_add_another_cohost() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        let user = get_user(3, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+        manager.toggle_cohost(&owner, 1, cohost.id.0).unwrap();
+
+        let result = manager.toggle_cohost(&cohost, 1, user.id.0);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.is_manager(user.id.0), false);
+    }
+
+    #[test]
+    fn test_removed_cohost_cannot_manage_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+        manager.toggle_cohost(&owner, 1, cohost.id.0).unwrap();
+
+        let result = manager.toggle_cohost(&owner, 1, cohost.id.0);
+        assert_eq!(result.unwrap(), false);
+
+        let result = manager.add_giveaway_reward(&cohost, 1, "AAAAA-BBBBB -> Some game");
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let new_owner = get_user(2, "New owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.transfer_ownership(&owner, 1, &new_owner);
+        assert_eq!(result.is_ok(), true);
+
+        let result = manager.add_giveaway_reward(&new_owner, 1, "AAAAA-BBBBB -> Some game");
+        assert_eq!(result.is_ok(), true);
+        let result = manager.add_giveaway_reward(&owner, 1, "CCCCC-DDDDD -> Another game");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.owner().get_user_id(), 2);
+    }
+
+    #[test]
+    fn test_get_error_for_non_owner_on_transfer_ownership() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.transfer_ownership(&user, 1, &user);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.owner().get_user_id(), 1);
+    }
+
+    #[test]
+    fn test_reorder_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        for description in vec!["first", "second", "third"] {
+            manager.add_giveaway(Giveaway::new(&owner).with_description(description));
+        }
+        manager.set_giveaway_alias(&owner, "last", 3).unwrap();
+        manager.set_giveaway_alias(&owner, "middle", 2).unwrap();
+
+        let result = manager.reorder_giveaway(&owner, 3, 1);
+        assert_eq!(result.is_ok(), true);
+
+        let descriptions = manager
+            .get_indexed_giveaways()
+            .iter()
+            .map(|(index, giveaway)| (*index, giveaway.description()))
+            .collect::<Vec<(usize, String)>>();
+        assert_eq!(
+            descriptions,
+            vec![
+                (1, format!("third")),
+                (2, format!("first")),
+                (3, format!("second")),
+            ]
+        );
+        assert_eq!(manager.resolve_giveaway_index(&owner, "last").unwrap(), 1);
+        assert_eq!(manager.resolve_giveaway_index(&owner, "middle").unwrap(), 3);
+
+        manager.reorder_giveaway(&owner, 1, 2).unwrap();
+        let giveaway = manager.get_giveaway_by_index(2).unwrap();
+        assert_eq!(giveaway.description(), "third");
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_position_on_reorder_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.reorder_giveaway(&owner, 1, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested position is out of the giveaways list."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_deleted_position_on_reorder_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&owner).with_description("second"));
+        manager.delete_giveaway(&owner, 2).unwrap();
+
+        let result = manager.reorder_giveaway(&owner, 1, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested position is out of the giveaways list."
+            )))
+        );
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.description(), "first");
+    }
+
+    #[test]
+    fn test_get_error_for_non_owner_on_reorder_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&user).with_description("second"));
+
+        let result = manager.reorder_giveaway(&user, 1, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_owner_dashboard() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let another_owner = get_user(2, "Another owner");
+        let first_user = get_user(3, "First");
+        let second_user = get_user(4, "Second");
+        for user in vec![&owner, &another_owner, &owner] {
+            let giveaway = Giveaway::new(user).with_description("test giveaway");
+            giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+            giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+            giveaway.activate();
+            manager.add_giveaway(giveaway);
+        }
+
+        manager.roll_reward(&first_user, 1, "1", false).unwrap();
+        manager.roll_reward(&first_user, 2, "1", false).unwrap();
+        manager.roll_reward(&first_user, 3, "1", false).unwrap();
+        manager.roll_reward(&second_user, 3, "2", false).unwrap();
+
+        let dashboard = manager.owner_dashboard(owner.id.0);
+        let indices = dashboard
+            .giveaways
+            .iter()
+            .map(|summary| (summary.index, summary.remaining))
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(indices, vec![(1, 1), (3, 0)]);
+        assert_eq!(dashboard.total_rewards, 4);
+        assert_eq!(dashboard.total_remaining, 1);
+        assert_eq!(dashboard.total_claims, 3);
+        assert_eq!(dashboard.total_participants, 2);
+    }
+
+    #[test]
+    fn test_get_giveaways_by_owner() {
+        let manager = GiveawayManager::new();
+        let user_1 = get_user(1, "Test");
+        let user_2 = get_user(2, "Other");
+        manager.add_giveaway(Giveaway::new(&user_1).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&user_2).with_description("second"));
+        manager.add_giveaway(Giveaway::new(&user_1).with_description("third"));
+        manager.add_giveaway(Giveaway::new(&user_1).with_description("fourth"));
+
+        manager.delete_giveaway(&user_1, 3).unwrap();
+
+        let giveaways = manager.get_giveaways_by_owner(1);
+        assert_eq!(giveaways.len(), 2);
+        assert_eq!(giveaways[0].0, 1);
+        assert_eq!(giveaways[0].1.pretty_print(), "first [owner: <@1>]");
+        assert_eq!(giveaways[1].0, 4);
+        assert_eq!(giveaways[1].1.pretty_print(), "fourth [owner: <@1>]");
+    }
+
+    #[test]
+    fn test_get_giveaways_by_owner_without_owned_giveaways() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        manager.add_giveaway(Giveaway::new(&user).with_description("first"));
+
+        assert_eq!(manager.get_giveaways_by_owner(2).len(), 0);
+    }
+
+    #[test]
+    fn test_get_error_for_deleted_giveaway_on_get_by_index() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        manager.add_giveaway(Giveaway::new(&user).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&user).with_description("second"));
+
+        manager.delete_giveaway(&user, 1).unwrap();
+
+        let result = manager.get_giveaway_by_index(1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(2).unwrap();
+        assert_eq!(giveaway.pretty_print(), "second [owner: <@1>]");
+
+        let result = manager.delete_giveaway(&user, 1);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_deletion() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.delete_giveaway(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For deleting this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_deletion() {
+        let manager = GiveawayManager::new();
+
+        let user = get_user(1, "Test");
+        let result = manager.delete_giveaway(&user, 10);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_activate_giveaway() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.activate_giveaway(&user, 1, ChannelId(1), false);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway_after_changes = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway_after_changes.is_activated(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_empty_manual_giveaway_on_activate() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.activate_giveaway(&user, 1, ChannelId(1), false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway can't be started without rewards. Please, add them \
+                with the `gadd` or `gaddm` commands first."
+            )))
+        );
+        assert_eq!(
+            manager.get_giveaway_by_index(1).unwrap().is_activated(),
+            false
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_activate() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.activate_giveaway(&user, 2, ChannelId(1), false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_activate() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.activate_giveaway(&user, 1, ChannelId(1), false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_another_active_giveaway_in_the_channel_on_activate() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway_1 = Giveaway::new(&user).with_description("test giveaway #1");
+        let giveaway_2 = Giveaway::new(&user).with_description("test giveaway #2");
+        giveaway_1.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway_2.add_reward(&Reward::new("reward #2")).unwrap();
+        manager.add_giveaway(giveaway_1);
+        manager.add_giveaway(giveaway_2);
+
+        manager
+            .activate_giveaway(&user, 1, ChannelId(1), false)
+            .unwrap();
+        let result = manager.activate_giveaway(&user, 2, ChannelId(1), false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway #1 is already active in this channel. Finish or \
+                deactivate it first, or use the `force` option to start anyway."
+            )))
+        );
+
+        let result = manager.activate_giveaway(&user, 2, ChannelId(2), false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_activate_giveaway_with_force_in_the_channel_with_active_giveaway() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway_1 = Giveaway::new(&user).with_description("test giveaway #1");
+        let giveaway_2 = Giveaway::new(&user).with_description("test giveaway #2");
+        giveaway_1.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway_2.add_reward(&Reward::new("reward #2")).unwrap();
+        manager.add_giveaway(giveaway_1);
+        manager.add_giveaway(giveaway_2);
+
+        manager
+            .activate_giveaway(&user, 1, ChannelId(1), false)
+            .unwrap();
+        let result = manager.activate_giveaway(&user, 2, ChannelId(1), true);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway_after_changes = manager.get_giveaway_by_index(2).unwrap();
+        assert_eq!(giveaway_after_changes.is_activated(), true);
+        assert_eq!(giveaway_after_changes.get_channel_id(), Some(ChannelId(1)));
+    }
+
+    #[test]
+    fn test_deactivate_giveaway() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.deactivate_giveaway(&user, 1);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway_after_changes = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway_after_changes.is_activated(), false);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_deactivate() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.deactivate_giveaway(&user, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_deactivate() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.deactivate_giveaway(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        manager.add_giveaway_reward(&user, 1, "test").unwrap();
+        let result = manager.get_giveaway_rewards(&user, 1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_get_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.get_giveaway_rewards(&user, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_get_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.get_giveaway_rewards(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_add_giveaway_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_giveaway_reward(&owner, 1, "test");
+        assert_eq!(result.is_ok(), true);
+
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(updated_giveaway.get_available_rewards().len(), 1);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_add_new_giveaway_reward() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_giveaway_reward(&user, 2, "");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_add_giveaway_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_giveaway_reward(&user, 1, "test");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_add_multiple_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        let text = "reward #1 \n reward #2 \n reward #3";
+
+        let result = manager.add_multiple_giveaway_rewards(&owner, 1, text);
+        assert_eq!(result.is_ok(), true);
+
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(updated_giveaway.get_available_rewards().len(), 3);
+    }
+
+    #[test]
+    fn test_add_multiple_giveaway_rewards_skips_blank_lines() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        let text = "reward #1\n\n   \n reward #2 \r\nreward #3\n\n";
+
+        let result = manager.add_multiple_giveaway_rewards(&owner, 1, text);
+        assert_eq!(result.is_ok(), true);
+
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let values = updated_giveaway
+            .get_available_rewards()
+            .iter()
+            .map(|reward| reward.value().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(values, vec!["reward #1", "reward #2", "reward #3"]);
+    }
+
+    #[test]
+    fn test_add_multiple_giveaway_rewards_skips_duplicate_keys() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        let text = "AAAAA-BBBBB -> Game #1\nCCCCC-DDDDD -> Game #2\nAAAAA-BBBBB -> Game #1";
+
+        let result = manager.add_multiple_giveaway_rewards(&owner, 1, text);
+        assert_eq!(result.unwrap(), (2, 1, 2));
+
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(updated_giveaway.get_available_rewards().len(), 2);
+    }
+
+    #[test]
+    fn test_add_multiple_giveaway_rewards_counts_malformed_keys() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        let text = "AAAAA-BBBBB-CCCCC -> Game #1\nDDDDD-EEEEE-FFF -> Game #2\njust a text";
+
+        let result = manager.add_multiple_giveaway_rewards(&owner, 1, text);
+        assert_eq!(result.unwrap(), (3, 0, 1));
+    }
+
+    #[test]
+    fn test_add_giveaway_reward_with_malformed_key() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_giveaway_reward(&owner, 1, "AAAAA-BBBBB-CCCCC -> Some game");
+        assert_eq!(result.unwrap(), false);
+        let result = manager.add_giveaway_reward(&owner, 1, "AAAA-BBBBB-CCCCC -> Another game");
+        assert_eq!(result.unwrap(), true);
+
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(updated_giveaway.get_available_rewards().len(), 2);
+    }
+
+    #[test]
+    fn test_get_error_for_duplicate_key_on_add_giveaway_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+        manager
+            .add_giveaway_reward(&owner, 1, "AAAAA-BBBBB -> Some game")
+            .unwrap();
+
+        let result = manager.add_giveaway_reward(&owner, 1, "AAAAA-BBBBB -> Another game");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward already exists in the giveaway."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_add_multiple_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_multiple_giveaway_rewards(&user, 2, "");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_add_multiple_giveaway_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.add_multiple_giveaway_rewards(&user, 1, "test");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_remove_reward() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        manager.add_giveaway_reward(&user, 1, "test").unwrap();
+        let reward_before_deletion = manager.get_giveaway_rewards(&user, 1).unwrap();
+        assert_eq!(reward_before_deletion.len(), 1);
+
+        manager.remove_giveaway_reward(&user, 1, 1).unwrap();
+        let reward_after_deletion = manager.get_giveaway_rewards(&user, 1).unwrap();
+        assert_eq!(reward_after_deletion.len(), 0);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_remove_reward() {
+        let manager = GiveawayManager::new();
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        manager.add_giveaway_reward(&user, 1, "test").unwrap();
+        let result = manager.remove_giveaway_reward(&user, 1, 2);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested reward was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_remove_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.remove_giveaway_reward(&user, 1, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_roll_reward_with_manual_select_strategy_by_default() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        let reward = Reward::new("something");
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.roll_reward(&owner, 1, "1", false);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), None);
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let updated_rewards = updated_giveaway.get_available_rewards();
+        assert_eq!(updated_rewards[0].object_state(), ObjectState::Pending);
+    }
+
+    #[test]
+    fn test_roll_preorder_reward_with_manual_select_strategy_by_default() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC -> Pre-order something");
+        assert_eq!(reward.is_preorder(), true);
+
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.roll_reward(&owner, 1, "1", false);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), None);
+        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let updated_rewards = updated_giveaway.get_available_rewards();
+        assert_eq!(updated_rewards[0].object_state(), ObjectState::Activated);
+    }
+
+    #[test]
+    fn test_get_error_for_inactive_giveaway_on_roll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.roll_reward(&owner, 1, "1", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway hasn't started yet or has been suspended by the owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_giveaway_is_paused_after_claims() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user_1 = get_user(2, "Test #1");
+        let user_2 = get_user(3, "Test #2");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_pause_after_claims(Some(2));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user_1, 1, "1", false).unwrap();
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.is_activated(), true);
+        assert_eq!(giveaway.claims(), 1);
+
+        manager.roll_reward(&user_2, 1, "2", false).unwrap();
+        assert_eq!(giveaway.is_activated(), false);
+        assert_eq!(giveaway.claims(), 2);
+
+        let result = manager.roll_reward(&owner, 1, "3", false);
+        assert_eq!(result.is_err(), true);
+    }
+
+    #[test]
+    fn test_reroll_is_not_counted_as_claim() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_pause_after_claims(Some(2));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.reroll_reward(&user, 1, 1, "2", false).unwrap();
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.is_activated(), true);
+        assert_eq!(giveaway.claims(), 1);
+    }
+
+    #[test]
+    fn test_giveaway_is_not_paused_without_claims_limit() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.is_activated(), true);
+        assert_eq!(giveaway.claims(), 1);
+    }
+
+    #[test]
+    fn test_roll_reward_under_the_rewards_limit_per_user() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_max_rewards_per_user(Some(2));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        let result = manager.roll_reward(&owner, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_roll_reward_for_existing_participant_at_the_participants_limit() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_max_participants(Some(1));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.confirm_reward(&user, 1, 1).unwrap();
+        let result = manager.roll_reward(&user, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_expire_pending_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        let result = manager.expire_reward(&owner, 1, 1);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Expired);
+        let user_stats = giveaway.stats().get(&user.id.0).unwrap().clone();
+        assert_eq!(user_stats.pending_rewards().is_empty(), true);
+        assert_eq!(manager.validate_all().is_empty(), true);
+
+        let result = manager.roll_reward(&user, 1, "1", false);
+        assert_eq!(result.is_err(), true);
+        let result = manager.roll_reward(&user, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_expire_activated_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.confirm_reward(&user, 1, 1).unwrap();
+        manager.expire_reward(&owner, 1, 1).unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Expired);
+        let user_stats = giveaway.stats().get(&user.id.0).unwrap().clone();
+        assert_eq!(user_stats.retrieved_rewards().is_empty(), true);
+        assert_eq!(manager.get_reward_counts(1).unwrap(), (0, 0, 0));
+
+        let result = manager.confirm_reward(&user, 1, 1);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!("The reward has expired.")))
+        );
+    }
+
+    #[test]
+    fn test_global_leaderboard() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user_1 = get_user(2, "First");
+        let user_2 = get_user(3, "Second");
+        for _ in 0..2 {
+            let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+            giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+            giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+            giveaway.activate();
+            manager.add_giveaway(giveaway);
+        }
+
+        manager.roll_reward(&user_1, 1, "1", false).unwrap();
+        manager.confirm_reward(&user_1, 1, 1).unwrap();
+        manager.roll_reward(&user_2, 1, "2", false).unwrap();
+        manager.confirm_reward(&user_2, 1, 2).unwrap();
+        manager.roll_reward(&user_1, 2, "1", false).unwrap();
+        manager.confirm_reward(&user_1, 2, 1).unwrap();
+        manager.roll_reward(&user_2, 2, "2", false).unwrap();
+
+        let result = manager.global_leaderboard();
+        assert_eq!(result, vec![(2, 2), (3, 1)]);
+    }
+
+    #[test]
+    fn test_global_leaderboard_without_giveaways() {
+        let manager = GiveawayManager::new();
+
+        assert_eq!(manager.global_leaderboard().is_empty(), true);
+    }
+
+    #[test]
+    fn test_auto_deny_stale_pending() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_auto_deny_after(StdDuration::from_secs(30 * 60));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+
+        let started_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        let stats = giveaway.stats();
+        for (user_id, position, delay) in vec![(2, 0, 0), (3, 1, 25)] {
+            let reward = &rewards[position];
+            reward.set_object_state_at(ObjectState::Pending, started_at + Duration::minutes(delay));
+            let mut user_stats = ParticipantStats::new();
+            user_stats.add_pending_reward(reward.id());
+            stats.insert(user_id, user_stats);
+        }
+        manager.add_giveaway(giveaway);
+
+        let denied = manager.auto_deny_stale_pending(started_at + Duration::minutes(31));
+        assert_eq!(denied, vec![(1, 1, 2)]);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+        let user_stats = giveaway.stats().get(&2).unwrap().clone();
+        assert_eq!(user_stats.pending_rewards().is_empty(), true);
+        let user_stats = giveaway.stats().get(&3).unwrap().clone();
+        assert_eq!(user_stats.pending_rewards().len(), 1);
+        assert_eq!(manager.validate_all().is_empty(), true);
+    }
+
+    #[test]
+    fn test_no_auto_deny_without_timeout() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+
+        let started_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let reward = giveaway.get_available_rewards()[0].clone();
+        reward.set_object_state_at(ObjectState::Pending, started_at);
+        let mut user_stats = ParticipantStats::new();
+        user_stats.add_pending_reward(reward.id());
+        giveaway.stats().insert(2, user_stats);
+        manager.add_giveaway(giveaway);
+
+        let denied = manager.auto_deny_stale_pending(started_at + Duration::days(1));
+        assert_eq!(denied.is_empty(), true);
+        assert_eq!(reward.object_state(), ObjectState::Pending);
+    }
+
+    #[test]
+    fn test_release_expired_pending() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+
+        let started_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        let stats = giveaway.stats();
+        for (user_id, position, delay) in vec![(2, 0, 0), (3, 1, 25)] {
+            let reward = &rewards[position];
+            reward.set_object_state_at(ObjectState::Pending, started_at + Duration::minutes(delay));
+            let mut user_stats = ParticipantStats::new();
+            user_stats.add_pending_reward(reward.id());
+            stats.insert(user_id, user_stats);
+        }
+        manager.add_giveaway(giveaway);
+
+        let now = started_at + Duration::minutes(31);
+        let result = manager.release_expired_pending(&owner, 1, now, Duration::minutes(30));
+        assert_eq!(result.unwrap(), 1);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+        assert_eq!(rewards[2].object_state(), ObjectState::Unused);
+        let user_stats = giveaway.stats().get(&2).unwrap().clone();
+        assert_eq!(user_stats.pending_rewards().is_empty(), true);
+        let user_stats = giveaway.stats().get(&3).unwrap().clone();
+        assert_eq!(user_stats.pending_rewards().len(), 1);
+        assert_eq!(manager.validate_all().is_empty(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_release_expired_pending() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.release_expired_pending(&user, 1, Utc::now(), Duration::minutes(30));
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_reward_on_expire_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.expire_reward(&owner, 1, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested reward was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_expire_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.expire_reward(&user, 1, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_reached_participants_limit_on_roll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let first_user = get_user(2, "First");
+        let second_user = get_user(3, "Second");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_max_participants(Some(1));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&first_user, 1, "1", false).unwrap();
+        let result = manager.roll_reward(&second_user, 1, "2", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This giveaway has reached its participant limit."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[1].object_state(), ObjectState::Unused);
+        assert_eq!(giveaway.stats().len(), 1);
+    }
+
+    #[test]
+    fn test_roll_reward_with_quantity() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let first_user = get_user(2, "First");
+        let second_user = get_user(3, "Second");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("3x Discord Nitro"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&first_user, 1, "1", false).unwrap();
+        manager.roll_reward(&second_user, 1, "1", false).unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[0].quantity(), 1);
+        for user_id in vec![2, 3] {
+            let user_stats = giveaway.stats().get(&user_id).unwrap().clone();
+            assert_eq!(
+                user_stats.retrieved_rewards().contains(&rewards[0].id()),
+                true
+            );
+        }
+        assert_eq!(manager.validate_all().is_empty(), true);
+
+        let result = manager.roll_reward(&first_user, 1, "1", false);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "You have already got one of these rewards."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_giveaway_with_taken_copies() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("3x Discord Nitro"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager
+            .roll_reward(&get_user(2, "First"), 1, "1", false)
+            .unwrap();
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(output.contains("(2/3 left)"), true);
+        assert_eq!(output.contains("activated by"), false);
+    }
+
+    #[test]
+    fn test_winner_mentions_with_taken_copies() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("3x Discord Nitro"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager
+            .roll_reward(&get_user(2, "First"), 1, "1", false)
+            .unwrap();
+        manager
+            .roll_reward(&get_user(3, "Second"), 1, "1", false)
+            .unwrap();
+
+        let winners = manager.winner_mentions(&owner, 1).unwrap();
+        assert_eq!(
+            winners
+                .iter()
+                .map(|(user_id, _)| *user_id)
+                .collect::<Vec<u64>>(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn test_roll_the_last_reward_with_quantity() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("2x Discord Nitro"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager
+            .roll_reward(&get_user(2, "First"), 1, "1", false)
+            .unwrap();
+        manager
+            .roll_reward(&get_user(3, "Second"), 1, "1", false)
+            .unwrap();
+        manager
+            .confirm_reward(&get_user(3, "Second"), 1, 1)
+            .unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Activated);
+        assert_eq!(manager.validate_all().is_empty(), true);
+
+        let result = manager.roll_reward(&get_user(4, "Third"), 1, "1", false);
+        assert_eq!(result.is_err(), true);
+
+        let result = manager.get_confirm_times(&owner, 1).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, 3);
+    }
+
+    #[test]
+    fn test_get_error_for_roll_reward_during_cooldown() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let another_user = get_user(3, "Another");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_strategy(Box::new(RandomSelectStrategy::new()))
+            .with_roll_cooldown(StdDuration::from_secs(60));
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB [Pre-order] -> Preorder game #1"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("CCCCC-DDDDD [Pre-order] -> Preorder game #2"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "", false).unwrap();
+        let result = manager.roll_reward(&user, 1, "", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "Please wait before rolling again."
+            )))
+        );
+        assert_eq!(
+            manager.roll_reward(&another_user, 1, "", false).is_ok(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_reached_hand_out_limit_on_roll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let first_user = get_user(2, "First");
+        let second_user = get_user(3, "Second");
+        let third_user = get_user(4, "Third");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_hand_out_limit(Some(2));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&first_user, 1, "1", false).unwrap();
+        manager.confirm_reward(&first_user, 1, 1).unwrap();
+        manager.roll_reward(&second_user, 1, "2", false).unwrap();
+        let result = manager.roll_reward(&third_user, 1, "3", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway limit has been reached."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[2].object_state(), ObjectState::Unused);
+    }
+
+    #[test]
+    fn test_get_error_for_reached_hand_out_limit_with_quantity() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_hand_out_limit(Some(2));
+        giveaway.add_reward(&Reward::new("5x Nitro")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager
+            .roll_reward(&get_user(2, "First"), 1, "1", false)
+            .unwrap();
+        manager
+            .roll_reward(&get_user(3, "Second"), 1, "1", false)
+            .unwrap();
+        let result = manager.roll_reward(&get_user(4, "Third"), 1, "1", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway limit has been reached."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].taken_copies(), 2);
+    }
+
+    #[test]
+    fn test_roll_reward_after_returned_reward_under_hand_out_limit() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let first_user = get_user(2, "First");
+        let second_user = get_user(3, "Second");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_hand_out_limit(Some(1));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&first_user, 1, "1", false).unwrap();
+        manager.deny_reward(&first_user, 1, 1).unwrap();
+        let result = manager.roll_reward(&second_user, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_reached_rewards_limit_per_user_on_roll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_max_rewards_per_user(Some(2));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        manager.roll_reward(&owner, 1, "2", false).unwrap();
+        manager.confirm_reward(&owner, 1, 2).unwrap();
+        let result = manager.roll_reward(&owner, 1, "3", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "You've reached the maximum number of rewards for this giveaway."
+            )))
+        );
+
+        let result = manager.roll_reward(&user, 1, "3", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_roll_reward_without_rewards_limit_per_user() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        for reward_index in 1..4 {
+            let reward_number = format!("{}", reward_index);
+            manager
+                .roll_reward(&owner, 1, &reward_number, false)
+                .unwrap();
+            manager.confirm_reward(&owner, 1, reward_index).unwrap();
+        }
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let stats = giveaway.stats();
+        let user_stats = stats.get(&owner.id.0).unwrap();
+        assert_eq!(user_stats.retrieved_rewards().len(), 3);
+    }
+
+    #[test]
+    fn test_dependent_reward_is_rolled_after_activating_prerequisite() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.set_reward_dependency(&owner, 1, 2, 1).unwrap();
+
+        let result = manager.roll_reward(&user, 1, "2", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward will be available after activating the reward it depends on."
+            )))
+        );
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        let result = manager.roll_reward(&owner, 1, "2", false);
+        assert_eq!(result.is_err(), true);
+
+        manager.confirm_reward(&user, 1, 1).unwrap();
+        let result = manager.roll_reward(&owner, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_random_strategy_skips_locked_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_strategy(Box::new(RandomSelectStrategy::new()));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.set_reward_dependency(&owner, 1, 1, 2).unwrap();
+        manager.roll_reward(&user, 1, "", false).unwrap();
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+
+        let result = manager.roll_reward(&owner, 1, "", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The remaining rewards will be available after activating \
+                the rewards they depend on."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_circular_reward_dependency() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        manager.set_reward_dependency(&owner, 1, 2, 1).unwrap();
+
+        let error = Error::from(ErrorKind::Giveaway(format!(
+            "The reward can't depend on itself, even indirectly."
+        )));
+        assert_eq!(
+            manager.set_reward_dependency(&owner, 1, 1, 2).unwrap_err(),
+            error
+        );
+        assert_eq!(
+            manager.set_reward_dependency(&owner, 1, 1, 1).unwrap_err(),
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_reward_claim_window() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let from = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let until = from + Duration::hours(1);
+        let result = manager.set_reward_claim_window(&owner, 1, 1, Some(from), Some(until));
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].claim_window(), (Some(from), Some(until)));
+    }
+
+    #[test]
+    fn test_get_error_for_inverted_window_on_set_reward_claim_window() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let from = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let result = manager.set_reward_claim_window(&owner, 1, 1, Some(from), Some(from));
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The claiming window must end after it starts."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_booster_can_roll_reserved_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let booster = get_user(2, "Booster");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_booster_reserved_fraction(0.5);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.roll_reward(&booster, 1, "2", true);
+        assert_eq!(result.is_ok(), true);
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+    }
+
+    #[test]
+    fn test_get_error_for_non_booster_on_roll_reserved_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_booster_reserved_fraction(0.5);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.roll_reward(&user, 1, "2", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward is reserved for the server boosters."
+            )))
+        );
+
+        let result = manager.roll_reward(&user, 1, "1", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_non_booster_can_roll_reserved_reward_after_handing_out_the_rest() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user_1 = get_user(2, "Test");
+        let user_2 = get_user(3, "Another");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_booster_reserved_fraction(0.5);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user_1, 1, "1", false).unwrap();
+
+        let result = manager.roll_reward(&user_2, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_reroll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.reroll_reward(&owner, 1, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+
+        let stats = giveaway.stats();
+        let user_stats = stats.get(&owner.id.0).unwrap();
+        assert_eq!(user_stats.pending_rewards().len(), 1);
+        assert_eq!(
+            user_stats.pending_rewards().contains(&rewards[1].id()),
+            true
+        );
+    }
+
+    #[test]
+    fn test_reroll_reward_during_cooldown() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_roll_cooldown(StdDuration::from_secs(60));
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
+        giveaway.add_reward(&Reward::new("another thing")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        let result = manager.reroll_reward(&user, 1, 1, "2", false);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Unused);
+        assert_eq!(rewards[1].object_state(), ObjectState::Pending);
+
+        let result = manager.roll_reward(&user, 1, "3", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "Please wait before rolling again."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_no_pending_reward_on_reroll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.reroll_reward(&owner, 1, 1, "1", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "Only your own pending reward can be rerolled."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_reward_of_another_user_on_reroll_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        let result = manager.reroll_reward(&user, 1, 1, "2", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "Only your own pending reward can be rerolled."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_pending_reward_is_kept_when_reroll_fails() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.reroll_reward(&owner, 1, 1, "10", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested reward was not found."
+            )))
+        );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Pending);
+        assert_eq!(rewards[1].object_state(), ObjectState::Unused);
+
+        let stats = giveaway.stats();
+        let user_stats = stats.get(&owner.id.0).unwrap();
+        assert_eq!(
+            user_stats.pending_rewards().contains(&rewards[0].id()),
+            true
+        );
+    }
+
+    #[test]
+    fn test_winner_mentions() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user_1 = get_user(2, "Test #1");
+        let user_2 = get_user(3, "Test #2");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #4")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user_2, 1, "1", false).unwrap();
+        manager.confirm_reward(&user_2, 1, 1).unwrap();
+        manager.roll_reward(&user_1, 1, "2", false).unwrap();
+        manager.confirm_reward(&user_1, 1, 2).unwrap();
+        manager.roll_reward(&user_2, 1, "3", false).unwrap();
+        manager.confirm_reward(&user_2, 1, 3).unwrap();
+        manager.roll_reward(&user_1, 1, "4", false).unwrap();
+
+        let winners = manager.winner_mentions(&owner, 1).unwrap();
+        assert_eq!(
+            winners,
+            vec![
+                (2, vec![format!("~~[+] reward #2~~")]),
+                (
+                    3,
+                    vec![format!("~~[+] reward #1~~"), format!("~~[+] reward #3~~")]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_simulate_giveaway_keeps_the_rewards_unchanged() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_strategy(Box::new(RandomSelectStrategy::new()));
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+        manager.roll_reward(&owner, 1, "", false).unwrap();
+
+        let report = manager.simulate_giveaway(&owner, 1, 1, 100).unwrap();
+        assert_eq!(report.wins().len(), 2);
+        assert_eq!(report.wins().iter().map(|(_, wins)| wins).sum::<u64>(), 100);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let unused_rewards = giveaway
+            .get_available_rewards()
+            .iter()
+            .filter(|reward| reward.object_state() == ObjectState::Unused)
+            .count();
+        assert_eq!(unused_rewards, 2);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_simulate_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.simulate_giveaway(&user, 1, 1, 100);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_reward_counts() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #4")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        manager.deactivate_giveaway(&owner, 1).unwrap();
+
+        let result = manager.get_reward_counts(1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), (2, 1, 1));
+    }
+
+    #[test]
+    fn test_get_reward_counts_without_expired_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        manager.expire_reward(&owner, 1, 2).unwrap();
+
+        let result = manager.get_reward_counts(1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), (1, 0, 0));
+    }
+
+    #[test]
+    fn test_store_claim_stats() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Game #1"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Epic] -> Game #2"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("GGGGG-HHHHH-IIIII [Steam] -> Game #3"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("JJJJJ-KKKKK-LLLLL [Steam] -> Game #4"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("reward #5")).unwrap();
+        {
+            let rewards = giveaway.read_rewards();
+            rewards[0].set_object_state(ObjectState::Activated);
+            rewards[1].set_object_state(ObjectState::Pending);
+            rewards[3].set_object_state(ObjectState::Pending);
+        }
+        manager.add_giveaway(giveaway);
+
+        let result = manager.store_claim_stats(1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(
+            result.unwrap(),
+            vec![
+                (format!("Steam"), 2, 1),
+                (format!("Epic"), 1, 0),
+                (format!("Other"), 0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_store_claim_stats_without_expired_rewards() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Game #1"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Steam] -> Game #2"))
+            .unwrap();
+        manager.add_giveaway(giveaway);
+
+        manager.expire_reward(&owner, 1, 1).unwrap();
+
+        let result = manager.store_claim_stats(1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap(), vec![(format!("Steam"), 0, 1)]);
+    }
+
+    #[test]
+    fn test_giveaway_summary() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.confirm_reward(&user, 1, 1).unwrap();
+
+        let result = manager.giveaway_summary(1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(
+            result.unwrap(),
+            "Giveaway #1 [active] — owner <@1>, 3 rewards (1 claimed), 1 participants"
+        );
+
+        manager.deactivate_giveaway(&owner, 1).unwrap();
+        assert_eq!(
+            manager.giveaway_summary(1).unwrap(),
+            "Giveaway #1 [paused] — owner <@1>, 3 rewards (1 claimed), 1 participants"
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_get_reward_counts() {
+        let manager = GiveawayManager::new();
+
+        let result = manager.get_reward_counts(1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested giveaway was not found."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_winners() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user_1 = get_user(2, "Test #1");
+        let user_2 = get_user(3, "Test #2");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #4")).unwrap();
+        giveaway.activate();
+        let rewards = giveaway.get_available_rewards();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user_2, 1, "3", false).unwrap();
+        manager.confirm_reward(&user_2, 1, 3).unwrap();
+        manager.roll_reward(&user_1, 1, "2", false).unwrap();
+        manager.confirm_reward(&user_1, 1, 2).unwrap();
+        manager.roll_reward(&user_2, 1, "1", false).unwrap();
+        manager.confirm_reward(&user_2, 1, 1).unwrap();
+        manager.roll_reward(&user_1, 1, "4", false).unwrap();
+
+        let winners = manager.get_winners(&owner, 1).unwrap();
+        assert_eq!(
+            winners,
+            vec![
+                (2, vec![rewards[1].id()]),
+                (3, vec![rewards[0].id(), rewards[2].id()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_winners_for_giveaway_without_winners() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let winners = manager.get_winners(&owner, 1).unwrap();
+        assert_eq!(winners.len(), 0);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_get_winners() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.get_winners(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_winner_mentions() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.winner_mentions(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_validate_all() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+
+        let healthy_giveaway = Giveaway::new(&owner).with_description("healthy giveaway");
+        healthy_giveaway
+            .add_reward(&Reward::new("reward #1"))
+            .unwrap();
+        healthy_giveaway
+            .add_reward(&Reward::new("reward #2"))
+            .unwrap();
+        healthy_giveaway
+            .add_reward(&Reward::new("reward #3"))
+            .unwrap();
+        healthy_giveaway.activate();
+        manager.add_giveaway(healthy_giveaway);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        manager.roll_reward(&owner, 1, "2", false).unwrap();
+
+        let broken_giveaway = Giveaway::new(&owner).with_description("broken giveaway");
+        broken_giveaway
+            .add_reward(&Reward::new("reward #1"))
+            .unwrap();
+        broken_giveaway
+            .add_reward(&Reward::new("reward #2"))
+            .unwrap();
+        broken_giveaway.activate();
+        manager.add_giveaway(broken_giveaway);
+        manager.roll_reward(&owner, 2, "1", false).unwrap();
+        let giveaway = manager.get_giveaway_by_index(2).unwrap();
+        giveaway.get_available_rewards()[1].set_object_state(ObjectState::Activated);
+        giveaway.remove_reward_by_index(1).unwrap();
+
+        let result = manager.validate_all();
+        assert_eq!(
+            result,
+            vec![(
+                2,
+                vec![
+                    format!("The reward #1 in the [+] state is pending for 0 and retrieved by 0 user(s)."),
+                    format!("The user <@1> has 1 reward(s) missing in the giveaway."),
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_get_confirm_times() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB -> Some game"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("CCCCC-DDDDD -> Another game"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("EEEEE-FFFFF -> Preorder game"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("GGGGG-HHHHH -> Pending game"))
+            .unwrap();
+
+        let started_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let records = vec![(2, 0, 30), (3, 1, 90), (2, 2, 0)];
+        let rewards = giveaway.get_available_rewards();
+        let stats = giveaway.stats();
+        for (user_id, position, latency) in records {
+            let reward = &rewards[position];
+            reward.set_object_state_at(ObjectState::Pending, started_at);
+            reward.set_object_state_at(
+                ObjectState::Activated,
+                started_at + Duration::seconds(latency),
+            );
+            reward.set_confirmed_by(user_id);
+            let mut user_stats = ParticipantStats::new();
+            if let Some(data) = stats.get(&user_id) {
+                user_stats = data.clone();
+            }
+            user_stats.add_retrieved_reward(reward.id());
+            stats.insert(user_id, user_stats);
+        }
+        rewards[3].set_object_state_at(ObjectState::Pending, started_at);
+        manager.add_giveaway(giveaway);
+
+        let result = manager.get_confirm_times(&owner, 1).unwrap();
+        assert_eq!(
+            result,
+            vec![(2, Duration::seconds(30)), (3, Duration::seconds(90))]
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_get_confirm_times() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let user = get_user(2, "Test");
+        let result = manager.get_confirm_times(&user, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "For interacting with this giveaway you need to be its owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_confirm_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        let reward = Reward::new("something");
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.confirm_reward(&owner, 1, 1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(result.unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_confirm_reward_detailed_returns_activated_reward() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        let (reward, receipt_id) = manager.confirm_reward_detailed(&user, 1, 1).unwrap();
+        assert_eq!(reward.object_state(), ObjectState::Activated);
+        assert_eq!(reward.value().as_str(), "AAAAA-BBBBB-CCCCC");
+        assert_eq!(reward.description().unwrap().trim(), "Some game");
+        assert_eq!(receipt_id.len(), 8);
     }
 
     #[test]
-    fn test_read_an_new_state() {
+    fn test_find_receipt_after_confirm_reward() {
         let manager = GiveawayManager::new();
-        let giveaways = manager.get_giveaways();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
 
-        assert_eq!(giveaways.len(), 0);
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        let receipt_id = manager.confirm_reward(&user, 1, 2).unwrap();
+
+        let (index, reward_index, receipt) = manager.find_receipt(&owner, &receipt_id).unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(reward_index, Some(2));
+        assert_eq!(receipt.id(), receipt_id);
+        assert_eq!(receipt.user_id(), 2);
     }
 
     #[test]
-    fn test_read_after_giveaway_update() {
+    fn test_get_error_for_receipt_of_another_giveaway() {
         let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
 
-        let mut giveaways = manager.get_giveaways();
-        assert_eq!(giveaways.len(), 0);
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        let receipt_id = manager.confirm_reward(&user, 1, 1).unwrap();
 
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
-        giveaways = manager.get_giveaways();
-        assert_eq!(giveaways.len(), 1);
+        let result = manager.find_receipt(&user, &receipt_id);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested receipt was not found."
+            )))
+        );
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_read() {
+    fn test_get_error_for_invalid_giveaway_index_on_confirm_reward() {
         let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
 
-        let result = manager.get_giveaway_by_index(10);
+        let result = manager.confirm_reward(&owner, 2, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -519,237 +5400,377 @@ mod tests {
     }
 
     #[test]
-    fn test_delete_giveaway() {
+    fn test_get_error_for_giveaway_in_the_inactive_state_on_confirm_reward() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
         manager.add_giveaway(giveaway);
 
-        let result = manager.delete_giveaway(&user, 1);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), ());
+        let result = manager.confirm_reward(&owner, 1, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway hasn't started yet or has been suspended by the owner."
+            )))
+        );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_deletion() {
+    fn test_get_error_for_invalid_reward_index_on_confirm_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
+        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let user = get_user(2, "Test");
-        let result = manager.delete_giveaway(&user, 1);
+        let result = manager.confirm_reward(&owner, 1, 10);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "For deleting this giveaway you need to be its owner."
+                "The requested reward was not found."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_deletion() {
+    fn test_get_error_for_already_activated_reward_on_confirm_reward() {
         let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let reward = Reward::new("something");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
 
-        let user = get_user(1, "Test");
-        let result = manager.delete_giveaway(&user, 10);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        let result = manager.confirm_reward(&owner, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
+                "The reward has been activated already."
             )))
         );
     }
 
     #[test]
-    fn test_activate_giveaway() {
+    fn test_get_error_for_invalid_user_on_confirm_reward() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "SomeUser");
+        let reward_1 = Reward::new("something");
+        let reward_2 = Reward::new("something else");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&reward_1).unwrap();
+        giveaway.add_reward(&reward_2).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.activate_giveaway(&user, 1);
-        assert_eq!(result.is_ok(), true);
-
-        let giveaway_after_changes = manager.get_giveaway_by_index(1).unwrap();
-        assert_eq!(giveaway_after_changes.is_activated(), true);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        let result = manager.confirm_reward(&user, 1, 1);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward can't be activated by others."
+            )))
+        );
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_activate() {
+    fn test_get_error_for_unused_reward_on_confirm_reward() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let reward_1 = Reward::new("something");
+        let reward_2 = Reward::new("something else");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&reward_1).unwrap();
+        giveaway.add_reward(&reward_2).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.activate_giveaway(&user, 2);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.confirm_reward(&owner, 1, 2);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
+                "The reward must be rolled before confirming."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_activate() {
+    fn test_get_error_for_first_command_by_user_in_giveaway_on_confirm_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "Test");
+        let user = get_user(2, "SomeUser");
+        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&reward).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.activate_giveaway(&user, 1);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.confirm_reward(&user, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "For interacting with this giveaway you need to be its owner."
+                "The reward must be rolled before confirming."
             )))
         );
     }
 
     #[test]
-    fn test_deactivate_giveaway() {
+    fn test_deny_reward() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        let reward = Reward::new("something");
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deactivate_giveaway(&user, 1);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.deny_reward(&owner, 1, 1);
         assert_eq!(result.is_ok(), true);
-
-        let giveaway_after_changes = manager.get_giveaway_by_index(1).unwrap();
-        assert_eq!(giveaway_after_changes.is_activated(), false);
+        assert_eq!(result.unwrap(), ());
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_deactivate() {
+    fn test_metrics_after_roll_confirm_and_deny() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deactivate_giveaway(&user, 2);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.confirm_reward(&owner, 1, 1).unwrap();
+        manager.roll_reward(&owner, 1, "2", false).unwrap();
+        manager.deny_reward(&owner, 1, 2).unwrap();
+        let _ = manager.deny_reward(&owner, 1, 2);
+
+        let metrics = manager.metrics().read();
+        assert_eq!(metrics.rolls, 2);
+        assert_eq!(metrics.confirms, 1);
+        assert_eq!(metrics.denies, 1);
+    }
+
+    fn get_lottery_giveaway(owner: &DiscordUser, rewards: usize) -> Giveaway {
+        let giveaway = Giveaway::new(owner)
+            .with_description("test giveaway")
+            .with_strategy(Box::new(EntryLotteryStrategy::new()));
+        for number in 1..=rewards {
+            let reward = Reward::new(&format!("reward #{}", number));
+            giveaway.add_reward(&reward).unwrap();
+        }
+        giveaway.activate();
+        giveaway
+    }
+
+    #[test]
+    fn test_enter_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
+
+        let result = manager.enter_giveaway(&user, 1);
+        assert_eq!(result.is_ok(), true);
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.entrants().len(), 1);
+        assert_eq!(giveaway.entrants()[0].get_user_id(), 2);
+    }
+
+    #[test]
+    fn test_get_error_for_entering_giveaway_twice() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
+        manager.enter_giveaway(&user, 1).unwrap();
+
+        let result = manager.enter_giveaway(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
+                "You have already entered this giveaway."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_deactivate() {
+    fn test_get_error_for_entering_giveaway_without_draw() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let user = get_user(2, "Test");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deactivate_giveaway(&user, 1);
+        let result = manager.enter_giveaway(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "For interacting with this giveaway you need to be its owner."
+                "This giveaway doesn't draw the winners among the entrants."
             )))
         );
     }
 
     #[test]
-    fn test_get_giveaway_rewards() {
+    fn test_get_error_for_rolling_reward_in_lottery_giveaway() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
 
-        manager.add_giveaway_reward(&user, 1, "test").unwrap();
-        let result = manager.get_giveaway_rewards(&user, 1);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap().len(), 1);
+        let result = manager.roll_reward(&user, 1, "", false);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The winners of this giveaway are drawn among the entrants, use the `genter` command to participate."
+            )))
+        );
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_get_giveaway_rewards() {
+    fn test_draw_giveaway_assigns_rewards_to_distinct_entrants() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 2));
+        for user_id in 2..6 {
+            manager
+                .enter_giveaway(&get_user(user_id, "Test"), 1)
+                .unwrap();
+        }
 
-        let result = manager.get_giveaway_rewards(&user, 2);
-        assert_eq!(result.is_err(), true);
+        let winners = manager.draw_giveaway(&owner, 1).unwrap();
+        let winner_ids = winners
+            .iter()
+            .map(|(user_id, _)| *user_id)
+            .collect::<HashSet<u64>>();
+        let reward_indices = winners
+            .iter()
+            .map(|(_, reward_index)| *reward_index)
+            .collect::<HashSet<usize>>();
+        assert_eq!(winner_ids.len(), 2);
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
-            )))
+            reward_indices,
+            vec![1, 2].into_iter().collect::<HashSet<usize>>()
         );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.entrants().len(), 2);
+        assert_eq!(manager.get_reward_counts(1).unwrap(), (0, 2, 0));
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_get_giveaway_rewards() {
+    fn test_draw_giveaway_with_quantity() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "Test");
-        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        let giveaway = Giveaway::new(&owner)
+            .with_description("test giveaway")
+            .with_strategy(Box::new(EntryLotteryStrategy::new()));
+        giveaway
+            .add_reward(&Reward::new("3x Discord Nitro"))
+            .unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
+        for user_id in 2..6 {
+            manager
+                .enter_giveaway(&get_user(user_id, "Test"), 1)
+                .unwrap();
+        }
 
-        let result = manager.get_giveaway_rewards(&user, 1);
-        assert_eq!(result.is_err(), true);
+        let winners = manager.draw_giveaway(&owner, 1).unwrap();
+        let winner_ids = winners
+            .iter()
+            .map(|(user_id, _)| *user_id)
+            .collect::<HashSet<u64>>();
+        assert_eq!(winners.len(), 3);
+        assert_eq!(winner_ids.len(), 3);
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "For interacting with this giveaway you need to be its owner."
-            )))
+            winners.iter().all(|(_, reward_index)| *reward_index == 1),
+            true
         );
+
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].object_state(), ObjectState::Pending);
+        assert_eq!(rewards[0].taken_copies(), 2);
+        assert_eq!(giveaway.entrants().len(), 1);
+        assert_eq!(manager.validate_all().is_empty(), true);
     }
 
     #[test]
-    fn test_add_giveaway_reward() {
+    fn test_draw_giveaway_with_fewer_entrants_than_rewards() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        manager.add_giveaway(get_lottery_giveaway(&owner, 3));
+        manager.enter_giveaway(&get_user(2, "Test"), 1).unwrap();
 
-        let result = manager.add_giveaway_reward(&owner, 1, "test");
-        assert_eq!(result.is_ok(), true);
+        let winners = manager.draw_giveaway(&owner, 1).unwrap();
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].0, 2);
+        assert_eq!(manager.get_reward_counts(1).unwrap(), (2, 1, 0));
 
-        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
-        assert_eq!(updated_giveaway.get_available_rewards().len(), 1);
+        let giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(giveaway.entrants().is_empty(), true);
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_add_new_giveaway_reward() {
+    fn test_get_error_for_draw_without_entrants() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
 
-        let result = manager.add_giveaway_reward(&user, 2, "");
+        let result = manager.draw_giveaway(&owner, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
+                "Nobody has entered the giveaway yet."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_add_giveaway_reward() {
+    fn test_cohost_can_draw_giveaway() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let cohost = get_user(2, "Co-host");
+        let user = get_user(3, "Test");
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
+        manager.toggle_cohost(&owner, 1, cohost.id.0).unwrap();
+        manager.enter_giveaway(&user, 1).unwrap();
+
+        let winners = manager.draw_giveaway(&cohost, 1).unwrap();
+        assert_eq!(winners, vec![(user.id.0, 1)]);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_draw_giveaway() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let user = get_user(2, "Test");
-        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        manager.add_giveaway(get_lottery_giveaway(&owner, 1));
+        manager.enter_giveaway(&user, 1).unwrap();
 
-        let result = manager.add_giveaway_reward(&user, 1, "test");
+        let result = manager.draw_giveaway(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -760,46 +5781,101 @@ mod tests {
     }
 
     #[test]
-    fn test_add_multiple_giveaway_rewards() {
+    fn test_export_giveaway_table() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("Discord Nitro")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.export_giveaway_table(&owner, 1).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                "```",
+                "+---+--------+-------------------+---------+-------------+",
+                "| # | State  | Value             | Store   | Description |",
+                "+---+--------+-------------------+---------+-------------+",
+                "| 1 | Unused | AAAAA-BBBBB-xxxxx | [Steam] | Some game   |",
+                "| 2 | Unused | Discord Nitro     |         |             |",
+                "+---+--------+-------------------+---------+-------------+",
+                "```",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_export_giveaway_tsv() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Steam] -> Other game"))
+            .unwrap();
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        manager.confirm_reward(&user, 1, 2).unwrap();
+
+        let result = manager.export_giveaway_tsv(&owner, 1).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                "#\tState\tValue\tStore\tDescription\tClaimer",
+                "1\tUnused\tAAAAA-BBBBB-xxxxx\t[Steam]\tSome game\t",
+                "2\tActivated\tDDDDD-EEEEE-FFFFF\t[Steam]\tOther game\t2",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_export_giveaway_json() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("just a text")).unwrap();
         manager.add_giveaway(giveaway);
-        let text = "reward #1 \n reward #2 \n reward #3";
-
-        let result = manager.add_multiple_giveaway_rewards(&owner, 1, text);
-        assert_eq!(result.is_ok(), true);
 
-        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
-        assert_eq!(updated_giveaway.get_available_rewards().len(), 3);
+        let result = manager.export_giveaway_json(&owner, 1).unwrap();
+        let data: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let items = data.as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["value"], "AAAAA-BBBBB-xxxxx");
+        assert_eq!(items[1]["value"], "just a text");
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_add_multiple_giveaway_rewards() {
+    fn test_get_error_for_invalid_owner_on_export_giveaway_json() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
         manager.add_giveaway(giveaway);
 
-        let result = manager.add_multiple_giveaway_rewards(&user, 2, "");
+        let result = manager.export_giveaway_json(&get_user(2, "Test"), 1);
         assert_eq!(result.is_err(), true);
-        assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
-            )))
-        );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_add_multiple_giveaway_rewards() {
+    fn test_get_error_for_invalid_owner_on_export_giveaway_tsv() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let user = get_user(2, "Test");
-        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
 
-        let result = manager.add_multiple_giveaway_rewards(&user, 1, "test");
+        let result = manager.export_giveaway_tsv(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -810,137 +5886,188 @@ mod tests {
     }
 
     #[test]
-    fn test_remove_reward() {
+    fn test_export_giveaway_table_in_blind_mode() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway.set_blind(true);
         manager.add_giveaway(giveaway);
 
-        manager.add_giveaway_reward(&user, 1, "test").unwrap();
-        let reward_before_deletion = manager.get_giveaway_rewards(&user, 1).unwrap();
-        assert_eq!(reward_before_deletion.len(), 1);
-
-        manager.remove_giveaway_reward(&user, 1, 1).unwrap();
-        let reward_after_deletion = manager.get_giveaway_rewards(&user, 1).unwrap();
-        assert_eq!(reward_after_deletion.len(), 0);
+        let result = manager.export_giveaway_table(&owner, 1).unwrap();
+        assert_eq!(result.contains("| 1 | Unused | ???   |"), true);
+        assert_eq!(result.contains("AAAAA"), false);
     }
 
     #[test]
-    fn test_get_error_for_invalid_index_on_remove_reward() {
+    fn test_get_error_for_invalid_owner_on_export_giveaway_table() {
         let manager = GiveawayManager::new();
-        let user = get_user(1, "Test");
-        let giveaway = Giveaway::new(&user).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
 
-        manager.add_giveaway_reward(&user, 1, "test").unwrap();
-        let result = manager.remove_giveaway_reward(&user, 1, 2);
+        let result = manager.export_giveaway_table(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested reward was not found."
+                "For interacting with this giveaway you need to be its owner."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_owner_on_remove_reward() {
+    fn test_get_giveaway_index_by_message() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "Test");
-        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        manager.add_giveaway(giveaway);
+        manager.add_giveaway(Giveaway::new(&owner).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&owner).with_description("second"));
+        let giveaway = manager.get_giveaway_by_index(2).unwrap();
+        giveaway.set_message_id(Some(MessageId(42)));
 
-        let result = manager.remove_giveaway_reward(&user, 1, 1);
-        assert_eq!(result.is_err(), true);
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "For interacting with this giveaway you need to be its owner."
-            )))
+            manager.get_giveaway_index_by_message(MessageId(42)),
+            Some(2)
         );
+        assert_eq!(manager.get_giveaway_index_by_message(MessageId(43)), None);
     }
 
     #[test]
-    fn test_roll_reward_with_manual_select_strategy_by_default() {
+    fn test_add_claim_reaction_on_confirm() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        let reward = Reward::new("something");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.set_channel_id(Some(ChannelId(5)));
+        giveaway.set_message_id(Some(MessageId(10)));
         giveaway.activate();
         manager.add_giveaway(giveaway);
-
-        let result = manager.roll_reward(&owner, 1, "1");
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), None);
-        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
-        let updated_rewards = updated_giveaway.get_available_rewards();
-        assert_eq!(updated_rewards[0].object_state(), ObjectState::Pending);
+        manager
+            .set_giveaway_claim_emoji(&owner, 1, Some("🎉"))
+            .unwrap();
+
+        manager.roll_reward(&user, 1, "1", false).unwrap();
+        manager.confirm_reward(&user, 1, 1).unwrap();
+        let reactions = RefCell::new(Vec::new());
+        let is_added = manager.add_claim_reaction(1, |channel_id, message_id, emoji| {
+            reactions
+                .borrow_mut()
+                .push((channel_id, message_id, emoji.to_string()));
+            true
+        });
+
+        assert_eq!(is_added, true);
+        assert_eq!(
+            reactions.into_inner(),
+            vec![(ChannelId(5), MessageId(10), "🎉".to_string())]
+        );
     }
 
     #[test]
-    fn test_roll_preorder_reward_with_manual_select_strategy_by_default() {
+    fn test_skip_claim_reaction_without_state_message() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        let reward = Reward::new("AAAAA-BBBBB-CCCCC -> Pre-order something");
-        assert_eq!(reward.is_preorder(), true);
-
-        giveaway.add_reward(&reward);
-        giveaway.activate();
+        giveaway.set_channel_id(Some(ChannelId(5)));
         manager.add_giveaway(giveaway);
+        manager
+            .set_giveaway_claim_emoji(&owner, 1, Some("🎉"))
+            .unwrap();
 
-        let result = manager.roll_reward(&owner, 1, "1");
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), None);
-        let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
-        let updated_rewards = updated_giveaway.get_available_rewards();
-        assert_eq!(updated_rewards[0].object_state(), ObjectState::Activated);
+        let is_added = manager.add_claim_reaction(1, |_, _, _| true);
+        assert_eq!(is_added, false);
     }
 
     #[test]
-    fn test_get_error_for_inactive_giveaway_on_roll_reward() {
+    fn test_skip_claim_reaction_without_emoji() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.set_channel_id(Some(ChannelId(5)));
+        giveaway.set_message_id(Some(MessageId(10)));
         manager.add_giveaway(giveaway);
 
-        let result = manager.roll_reward(&owner, 1, "1");
+        let is_added = manager.add_claim_reaction(1, |_, _, _| true);
+        assert_eq!(is_added, false);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_set_giveaway_claim_emoji() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("test giveaway"));
+
+        let result = manager.set_giveaway_claim_emoji(&user, 1, Some("🎉"));
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The giveaway hasn't started yet or has been suspended by the owner."
+                "For interacting with this giveaway you need to be its owner."
             )))
         );
     }
 
     #[test]
-    fn test_confirm_reward() {
+    fn test_clean_stale_messages() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        manager.add_giveaway(Giveaway::new(&owner).with_description("first"));
+        manager.add_giveaway(Giveaway::new(&owner).with_description("second"));
+        manager.add_giveaway(Giveaway::new(&owner).with_description("third"));
+        for (index, message_id) in vec![(1, 10), (2, 20)] {
+            let giveaway = manager.get_giveaway_by_index(index).unwrap();
+            giveaway.set_channel_id(Some(ChannelId(5)));
+            giveaway.set_message_id(Some(MessageId(message_id)));
+        }
+
+        let deleted_messages = vec![MessageId(20)];
+        let cleaned = manager.clean_stale_messages(|_channel_id, message_id| {
+            !deleted_messages.contains(&message_id)
+        });
+
+        assert_eq!(cleaned, 1);
+        let first_giveaway = manager.get_giveaway_by_index(1).unwrap();
+        assert_eq!(first_giveaway.get_message_id(), Some(MessageId(10)));
+        let second_giveaway = manager.get_giveaway_by_index(2).unwrap();
+        assert_eq!(second_giveaway.get_message_id(), None);
+        let third_giveaway = manager.get_giveaway_by_index(3).unwrap();
+        assert_eq!(third_giveaway.get_message_id(), None);
+    }
+
+    #[test]
+    fn test_get_pending_reward_index() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
+        let participant = get_user(2, "Participant");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        let reward = Reward::new("something");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&Reward::new("something")).unwrap();
+        giveaway.add_reward(&Reward::new("something else")).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        let result = manager.confirm_reward(&owner, 1, 1);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), ());
+        assert_eq!(manager.get_pending_reward_index(2, 1).unwrap(), None);
+
+        manager.roll_reward(&participant, 1, "2", false).unwrap();
+        assert_eq!(manager.get_pending_reward_index(2, 1).unwrap(), Some(2));
+        assert_eq!(manager.get_pending_reward_index(1, 1).unwrap(), None);
+
+        manager.confirm_reward(&participant, 1, 2).unwrap();
+        assert_eq!(manager.get_pending_reward_index(2, 1).unwrap(), None);
     }
 
     #[test]
-    fn test_get_error_for_invalid_giveaway_index_on_confirm_reward() {
+    fn test_get_error_for_invalid_giveaway_index_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.confirm_reward(&owner, 2, 1);
+        let result = manager.deny_reward(&owner, 2, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -951,13 +6078,13 @@ mod tests {
     }
 
     #[test]
-    fn test_get_error_for_giveaway_in_the_inactive_state_on_confirm_reward() {
+    fn test_get_error_for_giveaway_in_the_inactive_state_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
         manager.add_giveaway(giveaway);
 
-        let result = manager.confirm_reward(&owner, 1, 1);
+        let result = manager.deny_reward(&owner, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -968,16 +6095,16 @@ mod tests {
     }
 
     #[test]
-    fn test_get_error_for_invalid_reward_index_on_confirm_reward() {
+    fn test_get_error_for_invalid_reward_index_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.confirm_reward(&owner, 1, 10);
+        let result = manager.deny_reward(&owner, 1, 10);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -988,18 +6115,18 @@ mod tests {
     }
 
     #[test]
-    fn test_get_error_for_already_activated_reward_on_confirm_reward() {
+    fn test_get_error_for_already_activated_reward_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
         manager.confirm_reward(&owner, 1, 1).unwrap();
-        let result = manager.confirm_reward(&owner, 1, 1);
+        let result = manager.deny_reward(&owner, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
@@ -1010,234 +6137,356 @@ mod tests {
     }
 
     #[test]
-    fn test_get_error_for_invalid_user_on_confirm_reward() {
+    fn test_get_error_for_invalid_user_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let user = get_user(2, "SomeUser");
         let reward_1 = Reward::new("something");
         let reward_2 = Reward::new("something else");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward_1);
-        giveaway.add_reward(&reward_2);
+        giveaway.add_reward(&reward_1).unwrap();
+        giveaway.add_reward(&reward_2).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        manager.roll_reward(&user, 1, "2").unwrap();
-        let result = manager.confirm_reward(&user, 1, 1);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        manager.roll_reward(&user, 1, "2", false).unwrap();
+        let result = manager.deny_reward(&user, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "This reward can't be activated by others."
+                "This reward can't be returned by others."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_unused_reward_on_confirm_reward() {
+    fn test_get_error_for_unused_reward_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let reward_1 = Reward::new("something");
-        let reward_2 = Reward::new("something else");
+        let user = get_user(2, "SomeUser");
+        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward_1);
-        giveaway.add_reward(&reward_2);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        let result = manager.confirm_reward(&owner, 1, 2);
+        let result = manager.deny_reward(&user, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The reward must be rolled before confirming."
+                "The reward must be rolled before return."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_first_command_by_user_in_giveaway_on_confirm_reward() {
+    fn test_get_error_for_first_command_by_user_in_giveaway_on_deny_reward() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let user = get_user(2, "SomeUser");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        let result = manager.confirm_reward(&user, 1, 1);
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let result = manager.deny_reward(&user, 1, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The reward must be rolled before confirming."
+                "The reward must be rolled before return."
             )))
         );
     }
 
     #[test]
-    fn test_deny_reward() {
+    fn test_duplicate_reward_values() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        let reward = Reward::new("something");
-        giveaway.add_reward(&reward);
-        giveaway.activate();
+        giveaway.add_reward(&Reward::new("Discord Nitro")).unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Steam] -> Game #2"))
+            .unwrap();
+        giveaway.add_reward(&Reward::new("Discord Nitro")).unwrap();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        let result = manager.deny_reward(&owner, 1, 1);
-        assert_eq!(result.is_ok(), true);
-        assert_eq!(result.unwrap(), ());
+        let result = manager.duplicate_reward_values(&owner, 1).unwrap();
+        assert_eq!(result, vec![(format!("Discord Nitro"), vec![1, 3])]);
     }
 
     #[test]
-    fn test_get_error_for_invalid_giveaway_index_on_deny_reward() {
+    fn test_duplicate_reward_values_without_duplicates() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.activate();
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deny_reward(&owner, 2, 1);
+        let result = manager.duplicate_reward_values(&owner, 1).unwrap();
+        assert_eq!(result.is_empty(), true);
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_owner_on_duplicate_reward_values() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let user = get_user(2, "Test");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        manager.add_giveaway(giveaway);
+
+        let result = manager.duplicate_reward_values(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The requested giveaway was not found."
+                "For interacting with this giveaway you need to be its owner."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_giveaway_in_the_inactive_state_on_deny_reward() {
+    fn test_get_claimed_rewards() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deny_reward(&owner, 1, 1);
+        let claimed_rewards = manager.get_claimed_rewards(1).unwrap();
+        assert_eq!(claimed_rewards.is_empty(), true);
+
+        manager.roll_reward(&owner, 1, "2", false).unwrap();
+        let claimed_rewards = manager.get_claimed_rewards(1).unwrap();
+        assert_eq!(claimed_rewards.len(), 1);
+        assert_eq!(claimed_rewards[0].0, 2);
+        assert_eq!(claimed_rewards[0].1.value().as_str(), "reward #2");
+        assert_eq!(
+            claimed_rewards[0].1.claimed_at(),
+            Some(claimed_rewards[0].2)
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_get_claimed_rewards() {
+        let manager = GiveawayManager::new();
+
+        let result = manager.get_claimed_rewards(1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The giveaway hasn't started yet or has been suspended by the owner."
+                "The requested giveaway was not found."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_reward_index_on_deny_reward() {
+    fn test_get_anonymous_stats() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
-        giveaway.activate();
+        for value in vec!["reward #1", "reward #2", "reward #3", "reward #4"] {
+            giveaway.add_reward(&Reward::new(value)).unwrap();
+        }
+        let rewards = giveaway.get_available_rewards();
+        rewards[0].set_object_state(ObjectState::Activated);
+        rewards[1].set_object_state(ObjectState::Pending);
+        rewards[2].set_object_state(ObjectState::Activated);
+
+        let mut first_stats = ParticipantStats::new();
+        first_stats.add_retrieved_reward(rewards[0].id());
+        first_stats.add_pending_reward(rewards[1].id());
+        let mut second_stats = ParticipantStats::new();
+        second_stats.add_retrieved_reward(rewards[2].id());
+        giveaway.stats().insert(2, first_stats);
+        giveaway.stats().insert(3, second_stats);
         manager.add_giveaway(giveaway);
 
-        let result = manager.deny_reward(&owner, 1, 10);
-        assert_eq!(result.is_err(), true);
+        let result = manager.anonymous_stats(1).unwrap();
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "The requested reward was not found."
-            )))
+            result,
+            AnonStats {
+                participants: 2,
+                claims: 3,
+                rewards_remaining: 1,
+                average_claims: 1.5,
+            }
         );
     }
 
     #[test]
-    fn test_get_error_for_already_activated_reward_on_deny_reward() {
+    fn test_get_anonymous_stats_without_participants() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.anonymous_stats(1).unwrap();
+        assert_eq!(result.participants, 0);
+        assert_eq!(result.rewards_remaining, 1);
+        assert_eq!(result.average_claims, 0.0);
+    }
+
+    #[test]
+    fn test_pretty_print_giveaway_header_contains_remaining_rewards() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #3")).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        manager.confirm_reward(&owner, 1, 1).unwrap();
-        let result = manager.deny_reward(&owner, 1, 1);
-        assert_eq!(result.is_err(), true);
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(output.starts_with("Giveaway #1: (3/3 left)\n"), true);
+
+        manager.roll_reward(&owner, 1, "1", false).unwrap();
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(output.starts_with("Giveaway #1: (2/3 left)\n"), true);
+    }
+
+    #[test]
+    fn test_pretty_print_giveaway_without_rewards_shows_placeholder() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway.activate();
+        manager.add_giveaway(giveaway);
+
+        let output = manager.pretty_print_giveaway(1).unwrap();
+        assert_eq!(output, "Giveaway #1: (0/0 left)\nRewards coming soon!");
+
+        manager
+            .set_giveaway_empty_message(&owner, 1, "Keys will be added at 18:00 UTC")
+            .unwrap();
+        let output = manager.pretty_print_giveaway(1).unwrap();
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "The reward has been activated already."
-            )))
+            output,
+            "Giveaway #1: (0/0 left)\nKeys will be added at 18:00 UTC"
         );
     }
 
     #[test]
-    fn test_get_error_for_invalid_user_on_deny_reward() {
+    fn test_get_error_for_invalid_owner_on_set_giveaway_empty_message() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "SomeUser");
-        let reward_1 = Reward::new("something");
-        let reward_2 = Reward::new("something else");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward_1);
-        giveaway.add_reward(&reward_2);
-        giveaway.activate();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        manager.roll_reward(&user, 1, "2").unwrap();
-        let result = manager.deny_reward(&user, 1, 1);
+        let user = get_user(2, "Test");
+        let result = manager.set_giveaway_empty_message(&user, 1, "Soon");
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "This reward can't be returned by others."
+                "For interacting with this giveaway you need to be its owner."
             )))
         );
     }
 
     #[test]
-    fn test_get_error_for_unused_reward_on_deny_reward() {
+    fn test_pretty_print_giveaway_grouped_by_reward_type() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "SomeUser");
-        let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
-        giveaway.activate();
+        giveaway
+            .add_reward(&Reward::new("https://example.com/gift/AAAA"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("plain text reward"))
+            .unwrap();
+        giveaway
+            .add_reward(&Reward::new("DDDDD-EEEEE-FFFFF [Steam] -> Another game"))
+            .unwrap();
         manager.add_giveaway(giveaway);
 
-        let result = manager.deny_reward(&user, 1, 1);
-        assert_eq!(result.is_err(), true);
+        let output = manager.pretty_print_giveaway_grouped(&owner, 1).unwrap();
+        let lines = output.split("\n").collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[0], "Giveaway #1: (4/4 left)");
+        assert_eq!(lines[1], "Keys:");
+        assert_eq!(lines[2].starts_with("2. "), true);
+        assert_eq!(lines[3].starts_with("4. "), true);
+        assert_eq!(lines[4], "Links:");
+        assert_eq!(lines[5].starts_with("1. "), true);
+        assert_eq!(lines[6], "Other:");
+        assert_eq!(lines[7].starts_with("3. "), true);
+    }
+
+    #[test]
+    fn test_pretty_print_giveaway_grouped_skips_empty_groups() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("plain text reward"))
+            .unwrap();
+        manager.add_giveaway(giveaway);
+
+        let output = manager.pretty_print_giveaway_grouped(&owner, 1).unwrap();
         assert_eq!(
-            result.unwrap_err(),
-            Error::from(ErrorKind::Giveaway(format!(
-                "The reward must be rolled before return."
-            )))
+            output,
+            "Giveaway #1: (1/1 left)\nOther:\n1. [ ] plain text reward"
         );
     }
 
     #[test]
-    fn test_get_error_for_first_command_by_user_in_giveaway_on_deny_reward() {
+    fn test_get_error_for_pretty_print_giveaway_grouped_before_start() {
         let manager = GiveawayManager::new();
         let owner = get_user(1, "Owner");
-        let user = get_user(2, "SomeUser");
-        let reward = Reward::new("something");
+        let user = get_user(2, "Test");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
-        giveaway.activate();
+        giveaway
+            .add_reward(&Reward::new("plain text reward"))
+            .unwrap();
         manager.add_giveaway(giveaway);
 
-        manager.roll_reward(&owner, 1, "1").unwrap();
-        let result = manager.deny_reward(&user, 1, 1);
+        let result = manager.pretty_print_giveaway_grouped(&user, 1);
         assert_eq!(result.is_err(), true);
         assert_eq!(
             result.unwrap_err(),
             Error::from(ErrorKind::Giveaway(format!(
-                "The reward must be rolled before return."
+                "The giveaway hasn't started yet."
             )))
         );
+
+        manager
+            .activate_giveaway(&owner, 1, ChannelId(1), false)
+            .unwrap();
+        let result = manager.pretty_print_giveaway_grouped(&user, 1);
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_owner_can_pretty_print_giveaway_grouped_before_start() {
+        let manager = GiveawayManager::new();
+        let owner = get_user(1, "Owner");
+        let giveaway = Giveaway::new(&owner).with_description("test giveaway");
+        giveaway
+            .add_reward(&Reward::new("plain text reward"))
+            .unwrap();
+        manager.add_giveaway(giveaway);
+
+        let result = manager.pretty_print_giveaway_grouped(&owner, 1);
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(
+            manager.get_giveaway_by_index(1).unwrap().is_activated(),
+            false
+        );
     }
 
     #[test]
@@ -1246,12 +6495,12 @@ mod tests {
         let owner = get_user(1, "Owner");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
         for _ in 0..OUTPUT_AFTER_GIVEAWAY_COMMANDS {
-            manager.roll_reward(&owner, 1, "1").ok();
+            manager.roll_reward(&owner, 1, "1", false).ok();
         }
 
         let updated_giveaway = manager.get_giveaway_by_index(1).unwrap();
@@ -1264,7 +6513,7 @@ mod tests {
         let owner = get_user(1, "Owner");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
@@ -1282,7 +6531,7 @@ mod tests {
         let owner = get_user(1, "Owner");
         let reward = Reward::new("something");
         let giveaway = Giveaway::new(&owner).with_description("test giveaway");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
         manager.add_giveaway(giveaway);
 
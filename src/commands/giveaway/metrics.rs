@@ -0,0 +1,97 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Counters of the users activity in giveaways since the bot start
+// or the latest reset.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    rolls: AtomicU64,
+    confirms: AtomicU64,
+    denies: AtomicU64,
+}
+
+// A point-in-time copy of the metrics counters.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MetricsSnapshot {
+    pub rolls: u64,
+    pub confirms: u64,
+    pub denies: u64,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics::default()
+    }
+
+    pub fn increment_rolls(&self) {
+        self.rolls.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn increment_confirms(&self) {
+        self.confirms.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn increment_denies(&self) {
+        self.add_denies(1);
+    }
+
+    pub fn add_denies(&self, count: u64) {
+        self.denies.fetch_add(count, Ordering::SeqCst);
+    }
+
+    // Returns the current values of the counters.
+    pub fn read(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            rolls: self.rolls.load(Ordering::SeqCst),
+            confirms: self.confirms.load(Ordering::SeqCst),
+            denies: self.denies.load(Ordering::SeqCst),
+        }
+    }
+
+    // Sets all counters to zero and returns the values before the reset.
+    pub fn reset(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            rolls: self.rolls.swap(0, Ordering::SeqCst),
+            confirms: self.confirms.swap(0, Ordering::SeqCst),
+            denies: self.denies.swap(0, Ordering::SeqCst),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::giveaway::metrics::{Metrics, MetricsSnapshot};
+
+    #[test]
+    fn test_read_a_new_state() {
+        let metrics = Metrics::new();
+
+        assert_eq!(metrics.read(), MetricsSnapshot::default());
+    }
+
+    #[test]
+    fn test_read_and_reset_counters() {
+        let metrics = Metrics::new();
+        metrics.increment_rolls();
+        metrics.increment_rolls();
+        metrics.increment_confirms();
+        metrics.increment_denies();
+
+        let expected = MetricsSnapshot {
+            rolls: 2,
+            confirms: 1,
+            denies: 1,
+        };
+        assert_eq!(metrics.read(), expected);
+        assert_eq!(metrics.reset(), expected);
+        assert_eq!(metrics.read(), MetricsSnapshot::default());
+    }
+
+    #[test]
+    fn test_add_multiple_denies() {
+        let metrics = Metrics::new();
+        metrics.increment_denies();
+        metrics.add_denies(3);
+
+        assert_eq!(metrics.read().denies, 4);
+    }
+}
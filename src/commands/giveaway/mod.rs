@@ -1,8 +1,14 @@
+pub mod dto;
 pub mod formatters;
 pub mod handlers;
+pub mod latency;
+pub mod locks;
 pub mod manager;
+pub mod metrics;
 pub mod models;
 pub mod parser;
+pub mod reactions;
+pub mod scheduler;
 pub mod strategies;
 pub mod utils;
 
@@ -1,22 +1,40 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration as StdDuration, Instant};
 
+use chrono::{DateTime, Duration, Utc};
 use crossbeam::atomic::AtomicCell;
 use dashmap::DashMap;
-use serenity::model::id::MessageId;
+use serde::Serialize;
+use serenity::model::id::{ChannelId, MessageId};
 use serenity::model::user::User as DiscordUser;
 use uuid::Uuid;
 
 use crate::commands::giveaway::formatters::{DefaultRewardFormatter, RewardFormatter};
+use crate::commands::giveaway::locks::{LockStats, LockStatsSnapshot};
 use crate::commands::giveaway::parser::parse_message;
 use crate::commands::giveaway::strategies::{GiveawayStrategy, ManualSelectStrategy};
 use crate::error::{Error, ErrorKind, Result};
 
 pub type ConcurrencyReward = Arc<Box<Reward>>;
-pub type ConcurrencyRewardsVec = Arc<Mutex<Box<Vec<ConcurrencyReward>>>>;
+pub type ConcurrencyRewardsVec = Arc<RwLock<Vec<ConcurrencyReward>>>;
 pub const OUTPUT_AFTER_GIVEAWAY_COMMANDS: u64 = 15;
+pub const DEFAULT_EMPTY_MESSAGE: &str = "Rewards coming soon!";
+// How many latest receipts are kept for each giveaway.
+pub const MAX_RECEIPTS_PER_GIVEAWAY: usize = 500;
+// The longest time a giveaway can live before being finished automatically.
+pub const MAX_GIVEAWAY_DURATION: StdDuration = StdDuration::from_secs(30 * 24 * 60 * 60);
+// Actions coming faster than this make the adaptive output less frequent.
+pub const ADAPTIVE_OUTPUT_BUSY_INTERVAL: StdDuration = StdDuration::from_secs(5);
+// Actions coming slower than this make the adaptive output more frequent.
+pub const ADAPTIVE_OUTPUT_IDLE_INTERVAL: StdDuration = StdDuration::from_secs(60);
+// How many times the adaptive output interval can exceed the configured one.
+pub const ADAPTIVE_OUTPUT_MAX_FACTOR: u64 = 4;
+// How long the reward can stay pending before it can be released back.
+pub const PENDING_REWARD_TIMEOUT: StdDuration = StdDuration::from_secs(30 * 60);
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Participant {
@@ -45,6 +63,48 @@ impl From<DiscordUser> for Participant {
     }
 }
 
+// A proof that the reward was activated by the user, which can be
+// referenced later in disputes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    id: String,
+    reward_id: Uuid,
+    user_id: u64,
+    created_at: DateTime<Utc>,
+}
+
+impl Receipt {
+    pub fn new(reward_id: Uuid, user_id: u64) -> Self {
+        let id = Uuid::new_v4().simple().to_string()[..8].to_uppercase();
+        Receipt {
+            id,
+            reward_id,
+            user_id,
+            created_at: Utc::now(),
+        }
+    }
+
+    // Returns a short unique identifier of the receipt.
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    // Returns the identifier of the activated reward.
+    pub fn reward_id(&self) -> Uuid {
+        self.reward_id
+    }
+
+    // Returns the identifier of the user who activated the reward.
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    // Returns the time when the reward was activated.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ParticipantStats {
     pending_rewards: HashSet<Uuid>,
@@ -83,6 +143,11 @@ impl ParticipantStats {
     pub fn add_retrieved_reward(&mut self, value: Uuid) {
         self.retrieved_rewards.insert(value);
     }
+
+    // Deletes retrieved reward from the hashset
+    pub fn remove_retrieved_reward(&mut self, value: Uuid) {
+        self.retrieved_rewards.remove(&value);
+    }
 }
 
 #[derive(Clone)]
@@ -92,9 +157,9 @@ pub struct Giveaway {
     // false - The giveaway in edit / pause phase
     active: Arc<AtomicBool>,
     // A reference to the owner / create of the giveaway
-    owner: Participant,
+    owner: Arc<Mutex<Participant>>,
     // A giveaway description.
-    description: String,
+    description: Arc<Mutex<String>>,
     // A list of attached rewards
     rewards: ConcurrencyRewardsVec,
     // Collected stats for each users participated in the giveaway
@@ -104,42 +169,404 @@ pub struct Giveaway {
     // A reference to the message which needs to update during the
     // active giveaway phase.
     message_id: Arc<AtomicCell<Option<MessageId>>>,
+    // A reference to the channel where the giveaway has been started.
+    channel_id: Arc<AtomicCell<Option<ChannelId>>>,
     // Defines how many actions are required for printing the current
     // state of the giveaway.
-    actions_required_to_output: u64,
+    output_interval: u64,
+    // The current number of actions required for printing the state. Differs
+    // from `output_interval` only when the adaptive output is enabled.
+    actions_required_to_output: Arc<AtomicU64>,
+    // A flag that adjusts the output interval to the activity in the giveaway.
+    adaptive_output: Arc<AtomicBool>,
+    // The moment of the latest processed action.
+    last_action_at: Arc<AtomicCell<Option<Instant>>>,
     // An internal counter for periodic output the state of
     // the giveaway.
     actions_processed: Arc<AtomicU64>,
     // The formatter instance used for generating output for each
     // added or updated reward.
     reward_formatter: Arc<Box<dyn RewardFormatter + Send + Sync>>,
+    // A flag that hides any details about the rewards, until they
+    // haven't been activated.
+    blind: Arc<AtomicBool>,
+    // A flag that marks the giveaway as deleted. Deleted giveaways are kept
+    // in the storage, so that indices of the other giveaways stay the same.
+    // Only the `gmove` command shifts the numbers of the giveaways in between.
+    deleted: Arc<AtomicBool>,
+    // The number of successful rolls after which the giveaway
+    // will be paused automatically.
+    pause_after_claims: Option<u64>,
+    // Total number of successful rolls in the giveaway.
+    claims: Arc<AtomicU64>,
+    // The maximum number of rewards that a single participant can roll.
+    max_rewards_per_user: Option<usize>,
+    // The maximum number of distinct participants that can roll the rewards.
+    max_participants: Option<usize>,
+    // The maximum number of rewards handed out (pending or activated) in total.
+    hand_out_limit: Option<u64>,
+    // The minimal time between two rolls of the same participant.
+    roll_cooldown: Option<StdDuration>,
+    // The moment of the latest successful roll of each participant. Kept only
+    // while the bot is running.
+    last_rolls: Arc<Mutex<HashMap<u64, Instant>>>,
+    // The time after which the unconfirmed pending rewards are denied
+    // automatically and returned to the giveaway.
+    auto_deny_after: Option<StdDuration>,
+    // The moment after which the giveaway will be finished automatically.
+    deadline: Option<Instant>,
+    // The moment when the paused giveaway will be started automatically.
+    scheduled_start: Arc<AtomicCell<Option<Instant>>>,
+    // The share of rewards (from the end of the list) that the server
+    // boosters can roll before everyone else.
+    booster_reserved_fraction: f32,
+    // A message shown instead of the rewards, while nothing was added.
+    empty_message: Arc<Mutex<String>>,
+    // An emoji which the bot puts on the state message after each claim.
+    claim_emoji: Arc<Mutex<Option<String>>>,
+    // Users that are allowed to manage the giveaway together with the owner.
+    moderators: Arc<Mutex<HashSet<u64>>>,
+    // Users waiting for the draw, in the order they entered the giveaway.
+    entrants: Arc<Mutex<Vec<Participant>>>,
+    // Wait times for the lock on the rewards, when the profiling is enabled.
+    lock_stats: Arc<LockStats>,
+    // The latest receipts of the activated rewards, the oldest go first.
+    receipts: Arc<Mutex<VecDeque<Receipt>>>,
 }
 
 impl Giveaway {
     pub fn new(discord_user: &DiscordUser) -> Self {
         Giveaway {
             active: Arc::new(AtomicBool::new(false)),
-            owner: Participant::from(discord_user.clone()),
-            description: String::from(""),
-            rewards: Arc::new(Mutex::new(Box::new(Vec::new()))),
+            owner: Arc::new(Mutex::new(Participant::from(discord_user.clone()))),
+            description: Arc::new(Mutex::new(String::from(""))),
+            rewards: Arc::new(RwLock::new(Vec::new())),
             stats: Arc::new(DashMap::new()),
             strategy: Arc::new(Box::new(ManualSelectStrategy::new())),
             message_id: Arc::new(AtomicCell::new(None)),
-            actions_required_to_output: OUTPUT_AFTER_GIVEAWAY_COMMANDS,
+            channel_id: Arc::new(AtomicCell::new(None)),
+            output_interval: OUTPUT_AFTER_GIVEAWAY_COMMANDS,
+            actions_required_to_output: Arc::new(AtomicU64::new(OUTPUT_AFTER_GIVEAWAY_COMMANDS)),
+            adaptive_output: Arc::new(AtomicBool::new(false)),
+            last_action_at: Arc::new(AtomicCell::new(None)),
             actions_processed: Arc::new(AtomicU64::new(0)),
             reward_formatter: Arc::new(Box::new(DefaultRewardFormatter::new())),
+            blind: Arc::new(AtomicBool::new(false)),
+            deleted: Arc::new(AtomicBool::new(false)),
+            pause_after_claims: None,
+            claims: Arc::new(AtomicU64::new(0)),
+            max_rewards_per_user: None,
+            max_participants: None,
+            hand_out_limit: None,
+            roll_cooldown: None,
+            last_rolls: Arc::new(Mutex::new(HashMap::new())),
+            auto_deny_after: None,
+            deadline: None,
+            scheduled_start: Arc::new(AtomicCell::new(None)),
+            booster_reserved_fraction: 0.0,
+            empty_message: Arc::new(Mutex::new(DEFAULT_EMPTY_MESSAGE.to_string())),
+            claim_emoji: Arc::new(Mutex::new(None)),
+            moderators: Arc::new(Mutex::new(HashSet::new())),
+            entrants: Arc::new(Mutex::new(Vec::new())),
+            lock_stats: Arc::new(LockStats::new()),
+            receipts: Arc::new(Mutex::new(VecDeque::new())),
         }
     }
 
     // Returns a text description about the giveaway.
     pub fn with_description(mut self, description: &str) -> Self {
-        self.description = description.to_string();
+        self.description = Arc::new(Mutex::new(description.to_string()));
+        self
+    }
+
+    // Overrides the algorithm for distributing rewards.
+    pub fn with_strategy(mut self, strategy: Box<dyn GiveawayStrategy>) -> Self {
+        self.strategy = Arc::new(strategy);
+        self
+    }
+
+    // Uses the same strategy instance as another giveaway.
+    pub fn with_shared_strategy(mut self, strategy: Arc<Box<dyn GiveawayStrategy>>) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    // Pauses the giveaway automatically after the given number of claims.
+    pub fn with_pause_after_claims(mut self, claims: Option<u64>) -> Self {
+        self.pause_after_claims = claims;
+        self
+    }
+
+    // Overrides how many actions are required for printing the current state
+    // of the giveaway. The interval can't be less than one action.
+    pub fn with_output_interval(mut self, n: u64) -> Self {
+        self.output_interval = n.max(1);
+        self.actions_required_to_output = Arc::new(AtomicU64::new(self.output_interval));
+        self
+    }
+
+    // Finishes the giveaway automatically after the given duration. Durations
+    // longer than `MAX_GIVEAWAY_DURATION` are clamped to the maximum.
+    pub fn with_duration(mut self, duration: StdDuration) -> Self {
+        self.deadline = Some(Instant::now() + duration.min(MAX_GIVEAWAY_DURATION));
+        self
+    }
+
+    // Reserves the given share of rewards for the server boosters. The value
+    // is clamped to the range from 0.0 to 1.0.
+    pub fn with_booster_reserved_fraction(mut self, fraction: f32) -> Self {
+        self.booster_reserved_fraction = fraction.max(0.0).min(1.0);
+        self
+    }
+
+    // Limits the number of rewards that a single participant can roll.
+    pub fn with_max_rewards_per_user(mut self, limit: Option<usize>) -> Self {
+        self.max_rewards_per_user = limit;
+        self
+    }
+
+    // Returns the maximum number of rewards per participant (if has any).
+    pub fn max_rewards_per_user(&self) -> Option<usize> {
+        self.max_rewards_per_user
+    }
+
+    // Limits the number of distinct participants that can roll the rewards.
+    pub fn with_max_participants(mut self, limit: Option<usize>) -> Self {
+        self.max_participants = limit;
+        self
+    }
+
+    // Returns the maximum number of distinct participants (if has any).
+    pub fn max_participants(&self) -> Option<usize> {
+        self.max_participants
+    }
+
+    // Limits the total number of rewards handed out, regardless of how many
+    // rewards are left.
+    pub fn with_hand_out_limit(mut self, limit: Option<u64>) -> Self {
+        self.hand_out_limit = limit;
+        self
+    }
+
+    // Returns the maximum number of handed out rewards (if has any).
+    pub fn hand_out_limit(&self) -> Option<u64> {
+        self.hand_out_limit
+    }
+
+    // Requires the participants to wait the given time between the rolls.
+    pub fn with_roll_cooldown(mut self, cooldown: StdDuration) -> Self {
+        self.roll_cooldown = Some(cooldown);
+        self
+    }
+
+    // Returns the minimal time between the rolls (if has any).
+    pub fn roll_cooldown(&self) -> Option<StdDuration> {
+        self.roll_cooldown
+    }
+
+    // Returns how long the participant needs to wait before the next roll.
+    pub fn roll_cooldown_remaining(&self, user_id: u64, now: Instant) -> Option<StdDuration> {
+        let cooldown = self.roll_cooldown?;
+        let last_roll = *self.last_rolls.lock().unwrap().get(&user_id)?;
+        let elapsed = now.saturating_duration_since(last_roll);
+        match elapsed < cooldown {
+            true => Some(cooldown - elapsed),
+            false => None,
+        }
+    }
+
+    // Remembers the moment of the participant's roll for the cooldown.
+    pub fn register_roll(&self, user_id: u64, now: Instant) {
+        if self.roll_cooldown.is_some() {
+            self.last_rolls.lock().unwrap().insert(user_id, now);
+        }
+    }
+
+    // Denies the pending rewards automatically, when they weren't confirmed
+    // during the given time.
+    pub fn with_auto_deny_after(mut self, timeout: StdDuration) -> Self {
+        self.auto_deny_after = Some(timeout);
         self
     }
 
-    // Returns information about who created the giveaway.
-    pub fn owner(&self) -> &Participant {
-        &self.owner
+    // Returns the time after which the pending rewards are denied (if has any).
+    pub fn auto_deny_after(&self) -> Option<StdDuration> {
+        self.auto_deny_after
+    }
+
+    // Returns the number of claims after which the giveaway will be paused.
+    pub fn pause_after_claims(&self) -> Option<u64> {
+        self.pause_after_claims
+    }
+
+    // Returns the share of rewards reserved for the server boosters.
+    pub fn booster_reserved_fraction(&self) -> f32 {
+        self.booster_reserved_fraction
+    }
+
+    // Returns identifiers of the rewards reserved for the server boosters.
+    // The reserved rewards are taken from the end of the list.
+    pub fn reserved_rewards(&self) -> HashSet<Uuid> {
+        let guard_rewards = self.read_rewards();
+        let total = guard_rewards.len();
+        let reserved = (total as f32 * self.booster_reserved_fraction).ceil() as usize;
+
+        guard_rewards
+            .iter()
+            .skip(total - reserved.min(total))
+            .map(|reward| reward.id())
+            .collect()
+    }
+
+    // Returns the moment after which the giveaway will be finished automatically.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    // Checks that the giveaway has reached its deadline. Giveaways without
+    // a duration never expire.
+    pub fn is_expired(&self, now: Instant) -> bool {
+        match self.deadline {
+            Some(deadline) => now >= deadline,
+            None => false,
+        }
+    }
+
+    // Returns the moment when the giveaway will be started automatically.
+    pub fn scheduled_start(&self) -> Option<Instant> {
+        self.scheduled_start.load()
+    }
+
+    // Sets or clears the moment of the automatic start.
+    pub fn set_scheduled_start(&self, value: Option<Instant>) {
+        self.scheduled_start.store(value);
+    }
+
+    // Checks that the paused giveaway needs to be started at the given moment.
+    pub fn is_start_due(&self, now: Instant) -> bool {
+        match self.scheduled_start() {
+            Some(start_at) => !self.is_activated() && now >= start_at,
+            None => false,
+        }
+    }
+
+    // Returns the total number of successful rolls in the giveaway.
+    pub fn claims(&self) -> u64 {
+        self.claims.load(Ordering::SeqCst)
+    }
+
+    // Increments the counter of claims. Returns `true` when the counter
+    // reached the threshold and the giveaway needs to be paused.
+    pub fn register_claim(&self) -> bool {
+        let claims = self.claims.fetch_add(1, Ordering::SeqCst) + 1;
+        self.pause_after_claims == Some(claims)
+    }
+
+    // Returns the current description of the giveaway.
+    pub fn description(&self) -> String {
+        self.description.lock().unwrap().clone()
+    }
+
+    // Replaces the description of the giveaway.
+    pub fn set_description(&self, description: &str) {
+        let mut guard_description = self.description.lock().unwrap();
+        *guard_description = description.to_string();
+    }
+
+    // Returns the message shown when the giveaway doesn't have any rewards.
+    pub fn empty_message(&self) -> String {
+        self.empty_message.lock().unwrap().clone()
+    }
+
+    // Replaces the message shown when the giveaway doesn't have any rewards.
+    pub fn set_empty_message(&self, message: &str) {
+        let mut guard_message = self.empty_message.lock().unwrap();
+        *guard_message = message.to_string();
+    }
+
+    // Returns the emoji added on the state message after each claim (if set).
+    pub fn claim_emoji(&self) -> Option<String> {
+        self.claim_emoji.lock().unwrap().clone()
+    }
+
+    // Replaces the emoji added after each claim. Passing `None` disables it.
+    pub fn set_claim_emoji(&self, emoji: Option<String>) {
+        let mut guard_emoji = self.claim_emoji.lock().unwrap();
+        *guard_emoji = emoji;
+    }
+
+    // Returns information about who owns the giveaway.
+    pub fn owner(&self) -> Participant {
+        self.owner.lock().unwrap().clone()
+    }
+
+    // Hands the giveaway over to another user.
+    pub fn set_owner(&self, owner: Participant) {
+        let mut guard_owner = self.owner.lock().unwrap();
+        *guard_owner = owner;
+    }
+
+    // Allows the user to manage the giveaway together with the owner.
+    pub fn add_moderator(&self, user_id: u64) {
+        self.moderators.lock().unwrap().insert(user_id);
+    }
+
+    // Takes away the right to manage the giveaway. Returns false when the user
+    // wasn't one of the moderators.
+    pub fn remove_moderator(&self, user_id: u64) -> bool {
+        self.moderators.lock().unwrap().remove(&user_id)
+    }
+
+    // Adds the user to the entrants of the draw. Returns false when the user
+    // has entered the giveaway before.
+    pub fn add_entrant(&self, participant: Participant) -> bool {
+        let mut guard_entrants = self.entrants.lock().unwrap();
+        let user_id = participant.get_user_id();
+        if guard_entrants
+            .iter()
+            .any(|entrant| entrant.get_user_id() == user_id)
+        {
+            return false;
+        }
+
+        guard_entrants.push(participant);
+        true
+    }
+
+    // Removes the user from the entrants of the draw.
+    pub fn remove_entrant(&self, user_id: u64) {
+        let mut guard_entrants = self.entrants.lock().unwrap();
+        guard_entrants.retain(|participant| participant.get_user_id() != user_id);
+    }
+
+    // Stores the receipt of the activated reward. When the limit is reached,
+    // the oldest receipt is dropped.
+    pub fn add_receipt(&self, receipt: Receipt) {
+        let mut guard_receipts = self.receipts.lock().unwrap();
+        if guard_receipts.len() >= MAX_RECEIPTS_PER_GIVEAWAY {
+            guard_receipts.pop_front();
+        }
+        guard_receipts.push_back(receipt);
+    }
+
+    // Returns the receipt by its identifier. The search is case-insensitive.
+    pub fn find_receipt(&self, receipt_id: &str) -> Option<Receipt> {
+        self.receipts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|receipt| receipt.id().eq_ignore_ascii_case(receipt_id))
+            .cloned()
+    }
+
+    // Returns the users waiting for the draw.
+    pub fn entrants(&self) -> Vec<Participant> {
+        self.entrants.lock().unwrap().clone()
+    }
+
+    // Checks that the user is the owner or one of the moderators.
+    pub fn is_manager(&self, user_id: u64) -> bool {
+        self.owner().get_user_id() == user_id || self.moderators.lock().unwrap().contains(&user_id)
     }
 
     // Returns latest statistics in according with the requested giveaway.
@@ -152,6 +579,26 @@ impl Giveaway {
         self.rewards.clone()
     }
 
+    // Acquires the lock on the rewards for reading.
+    pub fn read_rewards(&self) -> RwLockReadGuard<'_, Vec<ConcurrencyReward>> {
+        self.lock_stats.timed(|| self.rewards.read().unwrap())
+    }
+
+    // Acquires the lock on the rewards for changing them.
+    pub fn write_rewards(&self) -> RwLockWriteGuard<'_, Vec<ConcurrencyReward>> {
+        self.lock_stats.timed(|| self.rewards.write().unwrap())
+    }
+
+    // Returns the wait times for the lock on the rewards.
+    pub fn lock_stats(&self) -> LockStatsSnapshot {
+        self.lock_stats.read()
+    }
+
+    // Returns true when the wait times for the lock on the rewards are recorded.
+    pub fn is_lock_profiling_enabled(&self) -> bool {
+        self.lock_stats.is_enabled()
+    }
+
     // Returns a reference to the message that must be updated
     pub fn get_message_id(&self) -> Option<MessageId> {
         self.message_id.load()
@@ -162,6 +609,16 @@ impl Giveaway {
         self.message_id.store(message_id)
     }
 
+    // Returns a reference to the channel where the giveaway is running.
+    pub fn get_channel_id(&self) -> Option<ChannelId> {
+        self.channel_id.load()
+    }
+
+    // Binds the giveaway to the certain channel.
+    pub fn set_channel_id(&self, channel_id: Option<ChannelId>) {
+        self.channel_id.store(channel_id)
+    }
+
     // Returns a current strategy for distributing rewards.
     pub fn strategy(&self) -> Arc<Box<dyn GiveawayStrategy>> {
         self.strategy.clone()
@@ -185,9 +642,37 @@ impl Giveaway {
 
     // Increase the action processed counter by one.
     pub fn update_actions_processed(&self) {
+        self.update_actions_processed_at(Instant::now());
+    }
+
+    // Increase the action processed counter by one, for the action that
+    // happened at the given moment. In the adaptive mode the output interval
+    // grows while the actions come quickly and shrinks when they are rare.
+    pub fn update_actions_processed_at(&self, now: Instant) {
         let current_value = self.actions_processed.load(Ordering::SeqCst);
         self.actions_processed
             .store(current_value + 1, Ordering::SeqCst);
+
+        let last_action_at = self.last_action_at.swap(Some(now));
+        if !self.is_adaptive_output() {
+            return;
+        }
+
+        if let Some(last_action_at) = last_action_at {
+            let elapsed = now.saturating_duration_since(last_action_at);
+            let threshold = self.actions_required_to_output.load(Ordering::SeqCst);
+            let new_threshold = if elapsed < ADAPTIVE_OUTPUT_BUSY_INTERVAL {
+                (threshold + 1).min(self.output_interval * ADAPTIVE_OUTPUT_MAX_FACTOR)
+            } else if elapsed > ADAPTIVE_OUTPUT_IDLE_INTERVAL {
+                threshold
+                    .saturating_sub(1)
+                    .max((self.output_interval / 2).max(1))
+            } else {
+                threshold
+            };
+            self.actions_required_to_output
+                .store(new_threshold, Ordering::SeqCst);
+        }
     }
 
     // Resets the action processed counter to zero.
@@ -195,11 +680,31 @@ impl Giveaway {
         self.actions_processed.store(0, Ordering::SeqCst);
     }
 
-    // Checks that the `action_processed` counter is equal to the
+    // Checks that the `action_processed` counter has reached the
     // defined limits stored in `actions_required_to_output` field.
     pub fn is_required_state_output(&self) -> bool {
         let current_value = self.actions_processed.load(Ordering::SeqCst);
-        current_value == self.actions_required_to_output
+        current_value >= self.actions_required_to_output.load(Ordering::SeqCst)
+    }
+
+    // Returns the current number of actions required for printing the state.
+    pub fn actions_required_to_output(&self) -> u64 {
+        self.actions_required_to_output.load(Ordering::SeqCst)
+    }
+
+    // Checks that the output interval adapts to the activity in the giveaway.
+    pub fn is_adaptive_output(&self) -> bool {
+        self.adaptive_output.load(Ordering::SeqCst)
+    }
+
+    // Enables or disables the adaptive output. After disabling the output
+    // interval is restored to the configured one.
+    pub fn set_adaptive_output(&self, value: bool) {
+        self.adaptive_output.store(value, Ordering::SeqCst);
+        if !value {
+            self.actions_required_to_output
+                .store(self.output_interval, Ordering::SeqCst);
+        }
     }
 
     // Return a reward formatter.
@@ -207,30 +712,72 @@ impl Giveaway {
         self.reward_formatter.clone()
     }
 
+    // Checks that the rewards must be hidden until the activation.
+    pub fn is_blind(&self) -> bool {
+        self.blind.load(Ordering::SeqCst)
+    }
+
+    // Enables or disables hiding rewards until the activation.
+    pub fn set_blind(&self, value: bool) {
+        self.blind.store(value, Ordering::SeqCst)
+    }
+
+    // Checks that the giveaway was deleted by the owner.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.load(Ordering::SeqCst)
+    }
+
+    // Marks the giveaway as deleted and stops it.
+    pub fn mark_as_deleted(&self) {
+        self.deactivate();
+        self.deleted.store(true, Ordering::SeqCst)
+    }
+
+    // Stylized print of the reward for the users in the channel. In the blind
+    // mode shows only a placeholder for rewards that haven't been activated.
+    pub fn pretty_print_reward(&self, reward: &Arc<Box<Reward>>) -> String {
+        match self.is_blind() && reward.object_state() != ObjectState::Activated {
+            true => format!("{} ???", reward.object_state().as_str()),
+            false => self.reward_formatter.pretty_print(reward),
+        }
+    }
+
+    // Returns the value of the reward masked in according with the settings
+    // of the giveaway. In the blind mode hides the rewards that haven't been
+    // activated.
+    pub fn masked_reward_value(&self, reward: &Arc<Box<Reward>>) -> String {
+        match self.is_blind() && reward.object_state() != ObjectState::Activated {
+            true => format!("???"),
+            false => self.reward_formatter.masked_value(reward),
+        }
+    }
+
     // Returns a list of all available rewards.
     pub fn get_available_rewards(&self) -> Vec<Arc<Box<Reward>>> {
-        self.rewards
-            .clone()
-            .lock()
-            .unwrap()
-            .iter()
-            .cloned()
-            .collect()
+        self.read_rewards().iter().cloned().collect()
     }
 
-    // Adds a new reward in the list of available rewards.
-    pub fn add_reward(&self, obj: &Reward) {
-        self.rewards
-            .clone()
-            .lock()
-            .unwrap()
-            .push(Arc::new(Box::new(obj.clone())));
+    // Adds a new reward in the list of available rewards. Keys with the same
+    // value can't be added twice, but the other rewards can be repeated.
+    pub fn add_reward(&self, obj: &Reward) -> Result<()> {
+        let mut guard_rewards = self.write_rewards();
+
+        let is_duplicate = obj.object_type() != ObjectType::Other
+            && guard_rewards
+                .iter()
+                .any(|reward| reward.value() == obj.value());
+        if is_duplicate {
+            let message = format!("This reward already exists in the giveaway.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        guard_rewards.push(Arc::new(Box::new(obj.clone())));
+        Ok(())
     }
 
     // Removes the reward by index from the list of available rewards.
     pub fn remove_reward_by_index(&self, index: usize) -> Result<()> {
-        let ref_giveaways = self.rewards.clone();
-        let mut guard_giveaways = ref_giveaways.lock().unwrap();
+        let mut guard_giveaways = self.write_rewards();
 
         match index > 0 && index < guard_giveaways.len() + 1 {
             true => {
@@ -245,12 +792,54 @@ impl Giveaway {
         Ok(())
     }
 
+    // Replaces the description of the reward by index. The reward keeps its
+    // identifier and state.
+    pub fn update_reward_description(&self, index: usize, description: &str) -> Result<()> {
+        let guard_rewards = self.read_rewards();
+
+        match index > 0 && index < guard_rewards.len() + 1 {
+            true => {
+                guard_rewards[index - 1].set_description(description);
+                Ok(())
+            }
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Changes the note of the reward by index, visible only to the owner
+    // and the co-hosts.
+    pub fn update_reward_owner_note(&self, index: usize, note: &str) -> Result<()> {
+        let guard_rewards = self.read_rewards();
+
+        match index > 0 && index < guard_rewards.len() + 1 {
+            true => {
+                guard_rewards[index - 1].set_owner_note(note);
+                Ok(())
+            }
+            false => {
+                let message = format!("The requested reward was not found.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+
+    // Removes all rewards from the giveaway. Returns the number of removed rewards.
+    pub fn clear_rewards(&self) -> usize {
+        let mut guard_rewards = self.write_rewards();
+        let removed = guard_rewards.len();
+        guard_rewards.clear();
+        removed
+    }
+
     // Pretty-print of the giveaway in the text messages.
     pub fn pretty_print(&self) -> String {
         format!(
             "{} [owner: <@{}>]",
-            self.description,
-            self.owner.get_user_id(),
+            self.description(),
+            self.owner().get_user_id(),
         )
     }
 }
@@ -259,8 +848,8 @@ impl fmt::Debug for Giveaway {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Giveaway")
             .field("active", &self.active.clone())
-            .field("owner", &self.owner.clone())
-            .field("description", &self.description.clone())
+            .field("owner", &self.owner())
+            .field("description", &self.description())
             .field("stats", &self.stats.clone())
             .finish()
     }
@@ -272,15 +861,15 @@ impl PartialEq for Giveaway {
     fn eq(&self, other: &Self) -> bool {
         let self_giveaway_rewards;
         {
-            self_giveaway_rewards = self.rewards.lock().unwrap().clone();
+            self_giveaway_rewards = self.rewards.read().unwrap().clone();
         }
 
         let other_giveaway_rewards;
         {
-            other_giveaway_rewards = other.rewards.lock().unwrap().clone();
+            other_giveaway_rewards = other.rewards.read().unwrap().clone();
         }
 
-        self.description == other.description && self_giveaway_rewards == other_giveaway_rewards
+        self.description() == other.description() && self_giveaway_rewards == other_giveaway_rewards
     }
 }
 
@@ -288,10 +877,14 @@ impl PartialEq for Giveaway {
 pub struct Reward {
     // A unique identifier of the reward in the giveaway(s)
     id: Uuid,
+    // The text the reward was created from.
+    source: Arc<String>,
     // The actual prize.
     value: Arc<String>,
     // Reward description
-    description: Option<String>,
+    description: Mutex<Option<String>>,
+    // A note visible only to the owner and the co-hosts of the giveaway.
+    owner_note: Mutex<Option<String>>,
     // Store an additional information about the reward (e.g. the online store,
     // where the key can be activated)
     object_info: Option<String>,
@@ -300,6 +893,26 @@ pub struct Reward {
     object_type: ObjectType,
     // Current state of the rewards (was activated, unused, etc.)
     object_state: AtomicCell<ObjectState>,
+    // The time when the reward was taken by someone.
+    claimed_at: AtomicCell<Option<DateTime<Utc>>>,
+    // The time when the reward was confirmed as activated.
+    confirmed_at: AtomicCell<Option<DateTime<Utc>>>,
+    // The user who confirmed the reward as activated.
+    confirmed_by: AtomicCell<Option<u64>>,
+    // Defines how often the reward is rolled by the random strategies. The
+    // rewards with the lower weight are rolled less often.
+    weight: u32,
+    // The reward that needs to be activated before this one can be rolled.
+    depends_on: AtomicCell<Option<Uuid>>,
+    // How many identical prizes the reward represents.
+    total_quantity: u64,
+    // How many of the identical prizes are left, including the one that
+    // is currently pending.
+    quantity: AtomicU64,
+    // The time since which the reward can be claimed (if limited).
+    claimable_from: AtomicCell<Option<DateTime<Utc>>>,
+    // The time until which the reward can be claimed (if limited).
+    claimable_until: AtomicCell<Option<DateTime<Utc>>>,
 }
 
 impl Reward {
@@ -308,11 +921,22 @@ impl Reward {
 
         Reward {
             id: Uuid::new_v4(),
+            source: Arc::new(value.to_string()),
             value: Arc::new(parse_result.value.clone()),
-            description: parse_result.description.clone(),
+            description: Mutex::new(parse_result.description.clone()),
+            owner_note: Mutex::new(None),
             object_info: parse_result.object_info.clone(),
             object_type: parse_result.object_type,
             object_state: AtomicCell::new(ObjectState::Unused),
+            claimed_at: AtomicCell::new(None),
+            confirmed_at: AtomicCell::new(None),
+            confirmed_by: AtomicCell::new(None),
+            weight: parse_result.weight,
+            depends_on: AtomicCell::new(None),
+            total_quantity: parse_result.quantity,
+            quantity: AtomicU64::new(parse_result.quantity),
+            claimable_from: AtomicCell::new(None),
+            claimable_until: AtomicCell::new(None),
         }
     }
 
@@ -321,6 +945,11 @@ impl Reward {
         self.id.clone()
     }
 
+    // Returns the text the reward was created from.
+    pub fn source(&self) -> Arc<String> {
+        self.source.clone()
+    }
+
     // Returns the reward's store key or a plain text
     pub fn value(&self) -> Arc<String> {
         self.value.clone()
@@ -328,7 +957,58 @@ impl Reward {
 
     // Returns the description of the item (if has any)
     pub fn description(&self) -> Option<String> {
-        self.description.clone()
+        self.description.lock().unwrap().clone()
+    }
+
+    // Replaces the description of the item. An empty text removes it.
+    pub fn set_description(&self, description: &str) {
+        let mut guard_description = self.description.lock().unwrap();
+        *guard_description = match description.trim() {
+            "" => None,
+            text => Some(text.to_string()),
+        };
+    }
+
+    // Returns the note for the owner and the co-hosts (if has any).
+    pub fn owner_note(&self) -> Option<String> {
+        self.owner_note.lock().unwrap().clone()
+    }
+
+    // Replaces the note for the owner and the co-hosts. An empty text removes it.
+    pub fn set_owner_note(&self, note: &str) {
+        let mut guard_note = self.owner_note.lock().unwrap();
+        *guard_note = match note.trim() {
+            "" => None,
+            text => Some(text.to_string()),
+        };
+    }
+
+    // Returns the identifier of the reward that needs to be activated first.
+    pub fn depends_on(&self) -> Option<Uuid> {
+        self.depends_on.load()
+    }
+
+    // Makes the reward available only after activating the given one.
+    pub fn set_depends_on(&self, reward_id: Option<Uuid>) {
+        self.depends_on.store(reward_id)
+    }
+
+    // Returns the time window when the reward can be claimed. Each side of
+    // the window can be open.
+    pub fn claim_window(&self) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        (self.claimable_from.load(), self.claimable_until.load())
+    }
+
+    // Limits the time when the reward can be claimed.
+    pub fn set_claim_window(&self, from: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) {
+        self.claimable_from.store(from);
+        self.claimable_until.store(until);
+    }
+
+    // Checks that the reward can be claimed at the given moment.
+    pub fn is_claimable_at(&self, now: DateTime<Utc>) -> bool {
+        let (from, until) = self.claim_window();
+        from.map_or(true, |from| now >= from) && until.map_or(true, |until| now < until)
     }
 
     // Returns an additional object information (e.g. for what store the key is)
@@ -336,6 +1016,15 @@ impl Reward {
         self.object_info.clone()
     }
 
+    // Returns the platform where the reward can be activated, based on
+    // the object information.
+    pub fn platform(&self) -> Platform {
+        match &self.object_info {
+            Some(info) => Platform::from_object_info(info),
+            None => Platform::Other,
+        }
+    }
+
     // Returns the object type. It can be a game / store key or just a plain text.
     pub fn object_type(&self) -> ObjectType {
         self.object_type
@@ -346,49 +1035,267 @@ impl Reward {
         self.object_state.load()
     }
 
-    // Overrides the object state onto the new one.
+    // Overrides the object state onto the new one without checking the
+    // transition. Used only for preparing the state in tests.
+    #[doc(hidden)]
     pub fn set_object_state(&self, state: ObjectState) {
-        self.object_state.store(state);
+        self.set_object_state_at(state, Utc::now())
     }
 
-    // Checks that the reward has been defined as the pre-order type.
-    pub fn is_preorder(&self) -> bool {
-        match self.object_type {
-            ObjectType::KeyPreorder => true,
+    // Same as `set_object_state`, but uses the given time as the current one.
+    #[doc(hidden)]
+    pub fn set_object_state_at(&self, state: ObjectState, now: DateTime<Utc>) {
+        let previous_state = self.object_state.swap(state);
+        self.update_state_timestamps(previous_state, state, now);
+    }
+
+    // Checks that the reward can be moved from one state to another. The
+    // pre-orders are activated right after the roll, without confirmation.
+    pub fn is_legal_transition(&self, from: ObjectState, to: ObjectState) -> bool {
+        match (from, to) {
+            (_, ObjectState::Expired) => true,
+            (ObjectState::Unused, ObjectState::Pending) => true,
+            (ObjectState::Pending, ObjectState::Activated) => true,
+            (ObjectState::Pending, ObjectState::Unused) => true,
+            (ObjectState::Unused, ObjectState::Activated) => self.is_preorder(),
             _ => false,
         }
     }
-}
 
-impl Clone for Reward {
-    fn clone(&self) -> Self {
-        Reward {
-            id: self.id.clone(),
-            value: self.value.clone(),
-            description: self.description.clone(),
-            object_info: self.object_info.clone(),
-            object_type: self.object_type,
-            object_state: AtomicCell::new(self.object_state.load()),
+    // Moves the reward onto the new state, if the transition is allowed. Also
+    // tracks the time when the reward was taken and resets it after returning
+    // the reward back.
+    pub fn try_set_object_state(&self, state: ObjectState) -> Result<()> {
+        let previous_state = self.object_state();
+        if !self.is_legal_transition(previous_state, state) {
+            let message = format!(
+                "The reward can't be moved from the {:?} state to the {:?} state.",
+                previous_state, state
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
         }
-    }
-}
 
-impl Eq for Reward {}
+        if self
+            .object_state
+            .compare_exchange(previous_state, state)
+            .is_err()
+        {
+            let message = format!("The reward has been changed by someone else.");
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
 
-impl PartialEq for Reward {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
+        self.update_state_timestamps(previous_state, state, Utc::now());
+        Ok(())
     }
-}
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum ObjectType {
-    Key,
-    KeyPreorder,
-    Other,
-}
+    fn update_state_timestamps(
+        &self,
+        previous_state: ObjectState,
+        state: ObjectState,
+        now: DateTime<Utc>,
+    ) {
+        if state == ObjectState::Unused {
+            self.claimed_at.store(None);
+            self.confirmed_at.store(None);
+            self.confirmed_by.store(None);
+            return;
+        }
+
+        if previous_state == ObjectState::Unused {
+            self.claimed_at.store(Some(now));
+        }
+        if state == ObjectState::Activated && previous_state != ObjectState::Activated {
+            self.confirmed_at.store(Some(now));
+        }
+    }
+
+    // Returns the time when the reward was taken (if it was).
+    pub fn claimed_at(&self) -> Option<DateTime<Utc>> {
+        self.claimed_at.load()
+    }
+
+    // Returns the time when the reward was confirmed (if it was).
+    pub fn confirmed_at(&self) -> Option<DateTime<Utc>> {
+        self.confirmed_at.load()
+    }
+
+    // Returns the user who confirmed the reward (if anyone did).
+    pub fn confirmed_by(&self) -> Option<u64> {
+        self.confirmed_by.load()
+    }
+
+    // Remembers the user who confirmed the reward.
+    pub fn set_confirmed_by(&self, user_id: u64) {
+        self.confirmed_by.store(Some(user_id));
+    }
+
+    // Returns how long it took to confirm the reward after the roll.
+    pub fn confirm_latency(&self) -> Option<Duration> {
+        match (self.claimed_at(), self.confirmed_at()) {
+            (Some(claimed_at), Some(confirmed_at)) => Some(confirmed_at - claimed_at),
+            _ => None,
+        }
+    }
+
+    // Returns the weight of the reward for the random strategies.
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    // Returns how many identical prizes the reward represents.
+    pub fn total_quantity(&self) -> u64 {
+        self.total_quantity
+    }
+
+    // Returns how many of the identical prizes are left.
+    pub fn quantity(&self) -> u64 {
+        self.quantity.load(Ordering::SeqCst)
+    }
+
+    // Returns the number of the identical prizes handed out as copies, while
+    // the reward itself stays unused.
+    pub fn taken_copies(&self) -> u64 {
+        self.total_quantity - self.quantity()
+    }
+
+    // Hands out one of the identical prizes, if this isn't the last one.
+    // The last prize is handed out as a regular reward. Returns true when
+    // a copy was taken.
+    pub fn take_copy(&self) -> bool {
+        self.quantity
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |quantity| {
+                match quantity > 1 {
+                    true => Some(quantity - 1),
+                    false => None,
+                }
+            })
+            .is_ok()
+    }
+
+    // Checks that the reward has been defined as the pre-order type.
+    pub fn is_preorder(&self) -> bool {
+        match self.object_type {
+            ObjectType::KeyPreorder => true,
+            _ => false,
+        }
+    }
+}
+
+impl Clone for Reward {
+    fn clone(&self) -> Self {
+        Reward {
+            id: self.id.clone(),
+            source: self.source.clone(),
+            value: self.value.clone(),
+            description: Mutex::new(self.description()),
+            owner_note: Mutex::new(self.owner_note()),
+            object_info: self.object_info.clone(),
+            object_type: self.object_type,
+            object_state: AtomicCell::new(self.object_state.load()),
+            claimed_at: AtomicCell::new(self.claimed_at.load()),
+            confirmed_at: AtomicCell::new(self.confirmed_at.load()),
+            confirmed_by: AtomicCell::new(self.confirmed_by.load()),
+            weight: self.weight,
+            depends_on: AtomicCell::new(self.depends_on.load()),
+            total_quantity: self.total_quantity,
+            quantity: AtomicU64::new(self.quantity()),
+            claimable_from: AtomicCell::new(self.claimable_from.load()),
+            claimable_until: AtomicCell::new(self.claimable_until.load()),
+        }
+    }
+}
+
+impl Eq for Reward {}
+
+impl PartialEq for Reward {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum ObjectType {
+    Key,
+    KeyPreorder,
+    Other,
+}
+
+impl ObjectType {
+    // The name of the object type, which can be parsed back.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ObjectType::Key => "Key",
+            ObjectType::KeyPreorder => "KeyPreorder",
+            ObjectType::Other => "Other",
+        }
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ObjectType {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Key" => Ok(ObjectType::Key),
+            "KeyPreorder" => Ok(ObjectType::KeyPreorder),
+            "Other" => Ok(ObjectType::Other),
+            _ => {
+                let message = format!("Unknown object type: {}.", value);
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+}
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    Steam,
+    Epic,
+    GoG,
+    Origin,
+    Uplay,
+    Other,
+}
+
+impl Platform {
+    // Detects the platform by the object information of the reward
+    // (e.g. "[Steam]" or "[Epic Games]").
+    pub fn from_object_info(info: &str) -> Self {
+        let name = info
+            .trim_matches(|c: char| c == '[' || c == ']' || c.is_whitespace())
+            .to_lowercase();
+
+        match name.as_str() {
+            value if value.contains("steam") => Platform::Steam,
+            value if value.contains("epic") || value == "egs" => Platform::Epic,
+            value if value.contains("gog") => Platform::GoG,
+            value if value.contains("origin") || value == "ea app" => Platform::Origin,
+            value if value.contains("uplay") || value.contains("ubisoft") => Platform::Uplay,
+            _ => Platform::Other,
+        }
+    }
+
+    // Pretty-print for the platform in text messages. Unknown platforms
+    // don't have any label.
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Platform::Steam => Some("(Steam)"),
+            Platform::Epic => Some("(Epic)"),
+            Platform::GoG => Some("(GOG)"),
+            Platform::Origin => Some("(Origin)"),
+            Platform::Uplay => Some("(Uplay)"),
+            Platform::Other => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub enum ObjectState {
     // The reward has been activated by someone an works without any issues.
     Activated,
@@ -396,6 +1303,8 @@ pub enum ObjectState {
     Pending,
     // The reward hasn't been taken by anyone.
     Unused,
+    // The reward was handed out, but turned out to be not working anymore.
+    Expired,
 }
 
 impl ObjectState {
@@ -405,6 +1314,7 @@ impl ObjectState {
             ObjectState::Activated => "[+]",
             ObjectState::Pending => "[?]",
             ObjectState::Unused => "[ ]",
+            ObjectState::Expired => "[x]",
         }
     }
 }
@@ -413,13 +1323,17 @@ impl ObjectState {
 mod tests {
     use std::sync::atomic::Ordering;
     use std::sync::Arc;
+    use std::time::{Duration as StdDuration, Instant};
 
+    use chrono::{Duration, TimeZone, Utc};
     use serenity::model::id::UserId;
     use serenity::model::user::{CurrentUser, User as DiscordUser};
 
     use crate::commands::giveaway::models::{
-        Giveaway, ObjectState, ObjectType, Reward, OUTPUT_AFTER_GIVEAWAY_COMMANDS,
+        Giveaway, ObjectState, ObjectType, Platform, Receipt, Reward, MAX_GIVEAWAY_DURATION,
+        MAX_RECEIPTS_PER_GIVEAWAY, OUTPUT_AFTER_GIVEAWAY_COMMANDS,
     };
+    use crate::error::{Error, ErrorKind};
 
     fn get_user(user_id: u64, username: &str) -> DiscordUser {
         let mut current_user = CurrentUser::default();
@@ -444,9 +1358,9 @@ mod tests {
         let expected_item_1 = formatter.pretty_print(&concurrecy_reward_1);
         let expected_item_2 = formatter.pretty_print(&concurrecy_reward_2);
         let expected_item_3 = formatter.pretty_print(&concurrecy_reward_3);
-        giveaway.add_reward(&reward_1);
-        giveaway.add_reward(&reward_2);
-        giveaway.add_reward(&reward_3);
+        giveaway.add_reward(&reward_1).unwrap();
+        giveaway.add_reward(&reward_2).unwrap();
+        giveaway.add_reward(&reward_3).unwrap();
 
         let rewards = giveaway
             .get_available_rewards()
@@ -479,7 +1393,7 @@ mod tests {
         let old_giveaway_rewards = giveaway.get_available_rewards();
         assert_eq!(old_giveaway_rewards.is_empty(), true);
 
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         let updated_giveaway_rewards = giveaway
             .get_available_rewards()
             .iter()
@@ -488,6 +1402,33 @@ mod tests {
         assert_eq!(updated_giveaway_rewards.contains(&expected_item), true);
     }
 
+    #[test]
+    fn test_get_error_for_duplicate_key_in_the_giveaway() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"))
+            .unwrap();
+
+        let result = giveaway.add_reward(&Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game"));
+        assert_eq!(result.is_err(), true);
+        assert_eq!(giveaway.get_available_rewards().len(), 1);
+
+        let result = giveaway.add_reward(&Reward::new("aaaaa-bbbbb-ccccc [Steam] -> Some game"));
+        assert_eq!(result.is_ok(), true);
+    }
+
+    #[test]
+    fn test_add_duplicate_other_rewards_to_the_giveaway() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+
+        giveaway.add_reward(&Reward::new("Discord Nitro")).unwrap();
+        let result = giveaway.add_reward(&Reward::new("Discord Nitro"));
+        assert_eq!(result.is_ok(), true);
+        assert_eq!(giveaway.get_available_rewards().len(), 2);
+    }
+
     #[test]
     fn test_remove_giveaway_reward_by_index_from_the_giveaway() {
         let user = get_user(1, "Test");
@@ -500,7 +1441,7 @@ mod tests {
         let old_giveaway_rewards = giveaway.get_available_rewards();
         assert_eq!(old_giveaway_rewards.is_empty(), true);
 
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         let updated_giveaway_rewards = giveaway
             .get_available_rewards()
             .iter()
@@ -518,12 +1459,84 @@ mod tests {
         assert_eq!(latest_giveaway_rewards.is_empty(), true);
     }
 
+    #[test]
+    fn test_roll_cooldown() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_roll_cooldown(StdDuration::from_secs(10));
+        let now = Instant::now();
+
+        assert_eq!(giveaway.roll_cooldown_remaining(2, now), None);
+        giveaway.register_roll(2, now);
+        assert_eq!(
+            giveaway.roll_cooldown_remaining(2, now + StdDuration::from_secs(4)),
+            Some(StdDuration::from_secs(6))
+        );
+        assert_eq!(
+            giveaway.roll_cooldown_remaining(2, now + StdDuration::from_secs(10)),
+            None
+        );
+        assert_eq!(giveaway.roll_cooldown_remaining(3, now), None);
+    }
+
+    #[test]
+    fn test_no_roll_cooldown_by_default() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        let now = Instant::now();
+
+        giveaway.register_roll(2, now);
+        assert_eq!(giveaway.roll_cooldown(), None);
+        assert_eq!(giveaway.roll_cooldown_remaining(2, now), None);
+    }
+
+    #[test]
+    fn test_clear_giveaway_rewards() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+        giveaway.add_reward(&Reward::new("reward #2")).unwrap();
+
+        assert_eq!(giveaway.clear_rewards(), 2);
+        assert_eq!(giveaway.get_available_rewards().is_empty(), true);
+        assert_eq!(giveaway.clear_rewards(), 0);
+    }
+
+    #[test]
+    fn test_update_reward_description() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway
+            .add_reward(&Reward::new("AAAAA-BBBBB-CCCCC -> Wrong game"))
+            .unwrap();
+
+        let reward_id = giveaway.get_available_rewards()[0].id();
+        giveaway.update_reward_description(1, "Right game").unwrap();
+
+        let rewards = giveaway.get_available_rewards();
+        assert_eq!(rewards[0].id(), reward_id);
+        assert_eq!(rewards[0].description(), Some(format!("Right game")));
+    }
+
+    #[test]
+    fn test_get_error_for_invalid_index_on_update_reward_description() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+
+        let result = giveaway.update_reward_description(1, "Some game");
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The requested reward was not found."
+            )))
+        );
+    }
+
     #[test]
     fn test_update_giveaway_actions_processed_counter() {
         let user = get_user(1, "Test");
         let giveaway = Giveaway::new(&user);
         let reward = Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
 
         assert_eq!(giveaway.actions_processed.load(Ordering::SeqCst), 0);
@@ -539,7 +1552,7 @@ mod tests {
         let user = get_user(1, "Test");
         let giveaway = Giveaway::new(&user);
         let reward = Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
 
         assert_eq!(giveaway.actions_processed.load(Ordering::SeqCst), 0);
@@ -558,7 +1571,7 @@ mod tests {
         let user = get_user(1, "Test");
         let giveaway = Giveaway::new(&user);
         let reward = Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
 
         assert_eq!(giveaway.actions_processed.load(Ordering::SeqCst), 0);
@@ -577,7 +1590,7 @@ mod tests {
         let user = get_user(1, "Test");
         let giveaway = Giveaway::new(&user);
         let reward = Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
 
         assert_eq!(giveaway.actions_processed.load(Ordering::SeqCst), 0);
@@ -599,7 +1612,7 @@ mod tests {
         let user = get_user(1, "Test");
         let giveaway = Giveaway::new(&user);
         let reward = Reward::new("AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game");
-        giveaway.add_reward(&reward);
+        giveaway.add_reward(&reward).unwrap();
         giveaway.activate();
 
         assert_eq!(giveaway.actions_processed.load(Ordering::SeqCst), 0);
@@ -615,6 +1628,239 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pretty_print_reward_in_the_blind_mode() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway.set_blind(true);
+        let reward = Arc::new(Box::new(Reward::new(
+            "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game",
+        )));
+
+        assert_eq!(giveaway.pretty_print_reward(&reward), "[ ] ???");
+
+        reward.set_object_state(ObjectState::Pending);
+        assert_eq!(giveaway.pretty_print_reward(&reward), "[?] ???");
+    }
+
+    #[test]
+    fn test_pretty_print_reward_in_the_blind_mode_after_activation() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway.set_blind(true);
+        let reward = Arc::new(Box::new(Reward::new(
+            "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game",
+        )));
+
+        reward.set_object_state(ObjectState::Activated);
+        assert_eq!(
+            giveaway.pretty_print_reward(&reward),
+            "~~[+] AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game~~"
+        );
+    }
+
+    #[test]
+    fn test_pretty_print_reward_without_the_blind_mode() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        let reward = Arc::new(Box::new(Reward::new(
+            "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game",
+        )));
+
+        assert_eq!(giveaway.is_blind(), false);
+        assert_eq!(
+            giveaway.pretty_print_reward(&reward),
+            "[ ] AAAAA-BBBBB-CCCCC-xxxx [Store]"
+        );
+    }
+
+    #[test]
+    fn test_giveaway_without_duration_is_never_expired() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+
+        assert_eq!(giveaway.deadline(), None);
+        assert_eq!(
+            giveaway.is_expired(Instant::now() + StdDuration::from_secs(86400)),
+            false
+        );
+    }
+
+    #[test]
+    fn test_giveaway_start_is_due_after_the_scheduled_moment() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        let now = Instant::now();
+
+        assert_eq!(giveaway.is_start_due(now), false);
+        giveaway.set_scheduled_start(Some(now + StdDuration::from_secs(60)));
+        assert_eq!(giveaway.is_start_due(now), false);
+        assert_eq!(
+            giveaway.is_start_due(now + StdDuration::from_secs(60)),
+            true
+        );
+
+        giveaway.activate();
+        assert_eq!(
+            giveaway.is_start_due(now + StdDuration::from_secs(60)),
+            false
+        );
+    }
+
+    #[test]
+    fn test_giveaway_with_duration_is_expired_after_the_deadline() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_duration(StdDuration::from_secs(60));
+        let deadline = giveaway.deadline().unwrap();
+
+        assert_eq!(
+            giveaway.is_expired(deadline - StdDuration::from_secs(1)),
+            false
+        );
+        assert_eq!(giveaway.is_expired(deadline), true);
+    }
+
+    #[test]
+    fn test_find_receipt_by_id() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game");
+        let receipt = Receipt::new(reward.id(), 2);
+        giveaway.add_receipt(receipt.clone());
+
+        assert_eq!(receipt.id().len(), 8);
+        assert_eq!(giveaway.find_receipt(&receipt.id()), Some(receipt.clone()));
+        assert_eq!(
+            giveaway.find_receipt(&receipt.id().to_lowercase()),
+            Some(receipt)
+        );
+        assert_eq!(giveaway.find_receipt("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn test_oldest_receipt_is_dropped_after_reaching_the_limit() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        let reward = Reward::new("reward #1");
+        let first_receipt = Receipt::new(reward.id(), 2);
+        giveaway.add_receipt(first_receipt.clone());
+        for _ in 0..MAX_RECEIPTS_PER_GIVEAWAY {
+            giveaway.add_receipt(Receipt::new(reward.id(), 2));
+        }
+
+        assert_eq!(giveaway.find_receipt(&first_receipt.id()), None);
+    }
+
+    #[test]
+    fn test_required_state_output_with_custom_interval() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_output_interval(3);
+
+        giveaway.update_actions_processed();
+        giveaway.update_actions_processed();
+        assert_eq!(giveaway.is_required_state_output(), false);
+
+        giveaway.update_actions_processed();
+        assert_eq!(giveaway.is_required_state_output(), true);
+
+        giveaway.reset_actions_processed();
+        assert_eq!(giveaway.is_required_state_output(), false);
+    }
+
+    #[test]
+    fn test_output_interval_is_at_least_one_action() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_output_interval(0);
+
+        giveaway.update_actions_processed();
+        assert_eq!(giveaway.is_required_state_output(), true);
+    }
+
+    #[test]
+    fn test_adaptive_output_interval_grows_under_high_activity() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_output_interval(4);
+        giveaway.set_adaptive_output(true);
+
+        let started_at = Instant::now();
+        for second in 0..20 {
+            giveaway.update_actions_processed_at(started_at + StdDuration::from_secs(second));
+        }
+
+        assert_eq!(giveaway.actions_required_to_output(), 16);
+    }
+
+    #[test]
+    fn test_adaptive_output_interval_shrinks_under_low_activity() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_output_interval(4);
+        giveaway.set_adaptive_output(true);
+
+        let started_at = Instant::now();
+        for minute in 0..5 {
+            giveaway.update_actions_processed_at(started_at + StdDuration::from_secs(minute * 120));
+        }
+
+        assert_eq!(giveaway.actions_required_to_output(), 2);
+        assert_eq!(giveaway.is_required_state_output(), true);
+    }
+
+    #[test]
+    fn test_disabled_adaptive_output_restores_the_interval() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_output_interval(4);
+        giveaway.set_adaptive_output(true);
+
+        let started_at = Instant::now();
+        for second in 0..3 {
+            giveaway.update_actions_processed_at(started_at + StdDuration::from_secs(second));
+        }
+        assert_eq!(giveaway.actions_required_to_output(), 6);
+
+        giveaway.set_adaptive_output(false);
+        giveaway.update_actions_processed_at(started_at + StdDuration::from_secs(3));
+        assert_eq!(giveaway.actions_required_to_output(), 4);
+    }
+
+    #[test]
+    fn test_giveaway_with_too_long_duration_is_clamped_to_the_maximum() {
+        let user = get_user(1, "Test");
+        let started_at = Instant::now();
+        let giveaway = Giveaway::new(&user).with_duration(MAX_GIVEAWAY_DURATION * 2);
+        let deadline = giveaway.deadline().unwrap();
+
+        assert_eq!(deadline >= started_at + MAX_GIVEAWAY_DURATION, true);
+        assert_eq!(deadline <= Instant::now() + MAX_GIVEAWAY_DURATION, true);
+    }
+
+    #[test]
+    fn test_reserved_rewards_are_taken_from_the_end() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user).with_booster_reserved_fraction(0.5);
+        let reward_1 = Reward::new("reward #1");
+        let reward_2 = Reward::new("reward #2");
+        let reward_3 = Reward::new("reward #3");
+        giveaway.add_reward(&reward_1).unwrap();
+        giveaway.add_reward(&reward_2).unwrap();
+        giveaway.add_reward(&reward_3).unwrap();
+
+        let reserved = giveaway.reserved_rewards();
+        assert_eq!(reserved.len(), 2);
+        assert_eq!(reserved.contains(&reward_1.id()), false);
+        assert_eq!(reserved.contains(&reward_2.id()), true);
+        assert_eq!(reserved.contains(&reward_3.id()), true);
+    }
+
+    #[test]
+    fn test_no_reserved_rewards_by_default() {
+        let user = get_user(1, "Test");
+        let giveaway = Giveaway::new(&user);
+        giveaway.add_reward(&Reward::new("reward #1")).unwrap();
+
+        assert_eq!(giveaway.booster_reserved_fraction(), 0.0);
+        assert_eq!(giveaway.reserved_rewards().len(), 0);
+    }
+
     // ---- GiveawayObject struct tests ----
 
     #[test]
@@ -641,6 +1887,53 @@ mod tests {
         assert_eq!(reward.object_type(), ObjectType::Key)
     }
 
+    #[test]
+    fn test_get_reward_platform_for_steam() {
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC [Steam] -> Some game");
+
+        assert_eq!(reward.platform(), Platform::Steam);
+    }
+
+    #[test]
+    fn test_get_reward_platform_for_epic_games() {
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC [Epic Games] -> Some game");
+
+        assert_eq!(reward.platform(), Platform::Epic);
+    }
+
+    #[test]
+    fn test_get_reward_platform_for_unknown_store() {
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC [Store] -> Some game");
+
+        assert_eq!(reward.platform(), Platform::Other);
+    }
+
+    #[test]
+    fn test_object_type_round_trip() {
+        for object_type in vec![ObjectType::Key, ObjectType::KeyPreorder, ObjectType::Other] {
+            let parsed = object_type.as_str().parse::<ObjectType>();
+            assert_eq!(parsed.unwrap(), object_type);
+            assert_eq!(object_type.to_string(), object_type.as_str());
+        }
+    }
+
+    #[test]
+    fn test_get_error_for_unknown_object_type() {
+        let result = "Gift".parse::<ObjectType>();
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!("Unknown object type: Gift.")))
+        );
+    }
+
+    #[test]
+    fn test_get_reward_platform_without_object_info() {
+        let reward = Reward::new("AAAAA-BBBBB-CCCCC -> Some game");
+
+        assert_eq!(reward.platform(), Platform::Other);
+    }
+
     #[test]
     fn test_get_reward_type_value_for_other_type() {
         let text = "just a text";
@@ -685,6 +1978,115 @@ mod tests {
         assert_eq!(reward.object_state(), ObjectState::Pending);
     }
 
+    #[test]
+    fn test_try_set_reward_state() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+
+        assert_eq!(
+            reward.try_set_object_state(ObjectState::Pending).is_ok(),
+            true
+        );
+        assert_eq!(
+            reward.try_set_object_state(ObjectState::Activated).is_ok(),
+            true
+        );
+        assert_eq!(
+            reward.try_set_object_state(ObjectState::Expired).is_ok(),
+            true
+        );
+        assert_eq!(reward.object_state(), ObjectState::Expired);
+    }
+
+    #[test]
+    fn test_try_set_reward_state_from_unused_to_activated() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+
+        let result = reward.try_set_object_state(ObjectState::Activated);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "The reward can't be moved from the Unused state to the Activated state."
+        );
+        assert_eq!(reward.object_state(), ObjectState::Unused);
+    }
+
+    #[test]
+    fn test_try_set_reward_state_from_activated_to_unused() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+        reward.set_object_state(ObjectState::Activated);
+
+        let result = reward.try_set_object_state(ObjectState::Unused);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(reward.object_state(), ObjectState::Activated);
+    }
+
+    #[test]
+    fn test_try_set_preorder_state_from_unused_to_activated() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD -> Preorder game key";
+        let reward = Reward::new(text);
+
+        assert_eq!(
+            reward.try_set_object_state(ObjectState::Activated).is_ok(),
+            true
+        );
+        assert_eq!(reward.object_state(), ObjectState::Activated);
+    }
+
+    #[test]
+    fn test_claimed_at_is_empty_for_a_new_reward() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+
+        assert_eq!(reward.claimed_at(), None);
+    }
+
+    #[test]
+    fn test_claimed_at_is_recorded_after_taking_the_reward() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+
+        reward.set_object_state(ObjectState::Pending);
+        let claimed_at = reward.claimed_at();
+        assert_eq!(claimed_at.is_some(), true);
+
+        reward.set_object_state(ObjectState::Activated);
+        assert_eq!(reward.claimed_at(), claimed_at);
+    }
+
+    #[test]
+    fn test_claimed_at_is_reset_after_returning_the_reward() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+
+        reward.set_object_state(ObjectState::Pending);
+        assert_eq!(reward.claimed_at().is_some(), true);
+
+        reward.set_object_state(ObjectState::Unused);
+        assert_eq!(reward.claimed_at(), None);
+    }
+
+    #[test]
+    fn test_confirm_latency_is_recorded_after_the_activation() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game";
+        let reward = Reward::new(text);
+        let rolled_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let confirmed_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 1, 30).unwrap();
+
+        reward.set_object_state_at(ObjectState::Pending, rolled_at);
+        assert_eq!(reward.confirm_latency(), None);
+
+        reward.set_object_state_at(ObjectState::Activated, confirmed_at);
+        assert_eq!(reward.confirmed_at(), Some(confirmed_at));
+        assert_eq!(reward.confirm_latency(), Some(Duration::seconds(90)));
+
+        reward.set_object_state(ObjectState::Unused);
+        assert_eq!(reward.confirmed_at(), None);
+        assert_eq!(reward.confirm_latency(), None);
+    }
+
     #[test]
     fn test_is_pre_order_key_returns_true() {
         let text = "AAAAA-BBBBB-CCCCC-DDDD -> Preorder game key";
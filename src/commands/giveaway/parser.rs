@@ -7,17 +7,60 @@ lazy_static! {
     static ref KEY_REGEX: Regex =
         Regex::new(r"^(?P<value>[^\[]+)?(?P<object_info>\[.+\])?\s*->\s*(?P<description>.+)?")
             .unwrap();
+    static ref WEIGHT_REGEX: Regex = Regex::new(r"\s*\{weight=(?P<weight>\d+)\}").unwrap();
+    static ref QUANTITY_REGEX: Regex = Regex::new(r"^(?P<quantity>\d+)x\s+").unwrap();
 }
 
+pub const DEFAULT_REWARD_WEIGHT: u32 = 1;
+pub const DEFAULT_REWARD_QUANTITY: u64 = 1;
+
 #[readonly::make]
 pub struct ParsedInput {
     pub value: String,
     pub description: Option<String>,
     pub object_info: Option<String>,
     pub object_type: ObjectType,
+    pub weight: u32,
+    pub quantity: u64,
+}
+
+// Extracts the `{weight=N}` token from the text. Returns the text without the
+// token and the parsed weight (or the default one, if it wasn't specified).
+fn parse_weight(text: &str) -> (String, u32) {
+    match WEIGHT_REGEX.captures(text) {
+        Some(captures) => {
+            let weight = captures["weight"]
+                .parse::<u32>()
+                .unwrap_or(DEFAULT_REWARD_WEIGHT)
+                .max(DEFAULT_REWARD_WEIGHT);
+            let text_without_weight = WEIGHT_REGEX.replace(text, "").trim().to_string();
+            (text_without_weight, weight)
+        }
+        None => (text.to_string(), DEFAULT_REWARD_WEIGHT),
+    }
 }
 
-pub fn parse_message(text: &str) -> ParsedInput {
+// Extracts the leading `Nx ` token (e.g. "5x Nitro") from the text. Returns
+// the text without the token and the parsed quantity (or the default one).
+fn parse_quantity(text: &str) -> (String, u64) {
+    match QUANTITY_REGEX.captures(text) {
+        Some(captures) => {
+            let quantity = captures["quantity"]
+                .parse::<u64>()
+                .unwrap_or(DEFAULT_REWARD_QUANTITY)
+                .max(DEFAULT_REWARD_QUANTITY);
+            let text_without_quantity = QUANTITY_REGEX.replace(text, "").trim().to_string();
+            (text_without_quantity, quantity)
+        }
+        None => (text.to_string(), DEFAULT_REWARD_QUANTITY),
+    }
+}
+
+pub fn parse_message(raw_text: &str) -> ParsedInput {
+    let (text_without_weight, weight) = parse_weight(raw_text);
+    let (text_without_quantity, quantity) = parse_quantity(&text_without_weight);
+    let text = text_without_quantity.as_str();
+
     match text.contains("->") {
         true => {
             let captures = KEY_REGEX.captures(text).unwrap();
@@ -49,6 +92,8 @@ pub fn parse_message(text: &str) -> ParsedInput {
                 description: parsed_description,
                 object_info: parsed_object_info,
                 object_type: parsed_object_type,
+                weight,
+                quantity,
             }
         }
         false => ParsedInput {
@@ -56,6 +101,8 @@ pub fn parse_message(text: &str) -> ParsedInput {
             description: None,
             object_info: None,
             object_type: ObjectType::Other,
+            weight,
+            quantity,
         },
     }
 }
@@ -63,7 +110,9 @@ pub fn parse_message(text: &str) -> ParsedInput {
 #[cfg(test)]
 mod tests {
     use crate::commands::giveaway::models::ObjectType;
-    use crate::commands::giveaway::parser::parse_message;
+    use crate::commands::giveaway::parser::{
+        parse_message, DEFAULT_REWARD_QUANTITY, DEFAULT_REWARD_WEIGHT,
+    };
 
     #[test]
     fn test_parse_empty_string() {
@@ -209,4 +258,91 @@ mod tests {
         assert_eq!(parsed_input.object_info, None);
         assert_eq!(parsed_input.object_type, ObjectType::KeyPreorder);
     }
+
+    #[test]
+    fn test_parse_pre_order_key_case_3() {
+        let text = "AAAAA-BBBBB -> Pre-order game";
+        let parsed_input = parse_message(text);
+
+        assert_eq!(parsed_input.value, "AAAAA-BBBBB");
+        assert_eq!(parsed_input.description, Some(format!("Pre-order game")));
+        assert_eq!(parsed_input.object_info, None);
+        assert_eq!(parsed_input.object_type, ObjectType::KeyPreorder);
+    }
+
+    #[test]
+    fn test_parse_pre_order_key_in_lowercase() {
+        let text = "AAAAA -> preorder";
+        let parsed_input = parse_message(text);
+
+        assert_eq!(parsed_input.value, "AAAAA");
+        assert_eq!(parsed_input.description, Some(format!("preorder")));
+        assert_eq!(parsed_input.object_info, None);
+        assert_eq!(parsed_input.object_type, ObjectType::KeyPreorder);
+    }
+
+    #[test]
+    fn test_parse_regular_key_is_not_pre_order() {
+        let text = "AAAAA-BBBBB -> Ordered game";
+        let parsed_input = parse_message(text);
+
+        assert_eq!(parsed_input.value, "AAAAA-BBBBB");
+        assert_eq!(parsed_input.description, Some(format!("Ordered game")));
+        assert_eq!(parsed_input.object_info, None);
+        assert_eq!(parsed_input.object_type, ObjectType::Key);
+    }
+
+    #[test]
+    fn test_parse_key_with_weight() {
+        let text = "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game {weight=5}";
+        let parsed_input = parse_message(text);
+
+        assert_eq!(parsed_input.value, "AAAAA-BBBBB-CCCCC-DDDD");
+        assert_eq!(parsed_input.description, Some(format!("Some game")));
+        assert_eq!(parsed_input.object_info, Some(format!("[Store]")));
+        assert_eq!(parsed_input.object_type, ObjectType::Key);
+        assert_eq!(parsed_input.weight, 5);
+    }
+
+    #[test]
+    fn test_parse_raw_text_with_weight() {
+        let text = "{weight=3} Just a regular text.";
+        let parsed_input = parse_message(text);
+
+        assert_eq!(parsed_input.value, "Just a regular text.");
+        assert_eq!(parsed_input.object_type, ObjectType::Other);
+        assert_eq!(parsed_input.weight, 3);
+    }
+
+    #[test]
+    fn test_parse_default_weight() {
+        let parsed_input = parse_message("AAAAA-BBBBB-CCCCC-DDDD -> Some game");
+        assert_eq!(parsed_input.weight, DEFAULT_REWARD_WEIGHT);
+
+        let parsed_input = parse_message("AAAAA-BBBBB-CCCCC-DDDD -> Some game {weight=0}");
+        assert_eq!(parsed_input.weight, DEFAULT_REWARD_WEIGHT);
+    }
+
+    #[test]
+    fn test_parse_raw_text_with_quantity() {
+        let parsed_input = parse_message("5x Discord Nitro");
+
+        assert_eq!(parsed_input.value, "Discord Nitro");
+        assert_eq!(parsed_input.object_type, ObjectType::Other);
+        assert_eq!(parsed_input.quantity, 5);
+    }
+
+    #[test]
+    fn test_parse_default_quantity() {
+        let parsed_input = parse_message("Discord Nitro");
+        assert_eq!(parsed_input.quantity, DEFAULT_REWARD_QUANTITY);
+
+        let parsed_input = parse_message("0x Discord Nitro");
+        assert_eq!(parsed_input.value, "Discord Nitro");
+        assert_eq!(parsed_input.quantity, DEFAULT_REWARD_QUANTITY);
+
+        let parsed_input = parse_message("2x2 puzzle");
+        assert_eq!(parsed_input.value, "2x2 puzzle");
+        assert_eq!(parsed_input.quantity, DEFAULT_REWARD_QUANTITY);
+    }
 }
@@ -0,0 +1,221 @@
+use std::fmt;
+
+use dashmap::DashMap;
+use serenity::model::channel::ReactionType;
+use serenity::model::id::EmojiId;
+
+use crate::error::{Error, ErrorKind, Result};
+
+pub const DEFAULT_ROLL_EMOJI: &str = "🎲";
+pub const DEFAULT_CONFIRM_EMOJI: &str = "✅";
+pub const DEFAULT_DENY_EMOJI: &str = "❌";
+
+// Actions that can be triggered by reacting on the giveaway message.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ReactionAction {
+    Roll,
+    Confirm,
+    Deny,
+}
+
+impl ReactionAction {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "roll" => Some(ReactionAction::Roll),
+            "confirm" => Some(ReactionAction::Confirm),
+            "deny" => Some(ReactionAction::Deny),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionAction::Roll => "roll",
+            ReactionAction::Confirm => "confirm",
+            ReactionAction::Deny => "deny",
+        }
+    }
+}
+
+impl fmt::Display for ReactionAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// Maps the emojis to the actions with the giveaway. Every action has
+// exactly one emoji, which can be changed by the bot owner.
+#[derive(Debug)]
+pub struct ReactionControls {
+    actions: DashMap<String, ReactionAction>,
+}
+
+impl ReactionControls {
+    pub fn new() -> Self {
+        let actions = DashMap::new();
+        actions.insert(DEFAULT_ROLL_EMOJI.to_string(), ReactionAction::Roll);
+        actions.insert(DEFAULT_CONFIRM_EMOJI.to_string(), ReactionAction::Confirm);
+        actions.insert(DEFAULT_DENY_EMOJI.to_string(), ReactionAction::Deny);
+        ReactionControls { actions }
+    }
+
+    // Returns the action bound to the emoji, if any.
+    pub fn resolve(&self, emoji: &str) -> Option<ReactionAction> {
+        self.actions.get(emoji).map(|action| *action)
+    }
+
+    // Returns the emoji bound to the action.
+    pub fn emoji(&self, action: ReactionAction) -> Option<String> {
+        self.actions
+            .iter()
+            .find(|pair| *pair.value() == action)
+            .map(|pair| pair.key().clone())
+    }
+
+    // Binds the action to the new emoji instead of the previous one.
+    pub fn bind(&self, action: ReactionAction, emoji: &str) -> Result<()> {
+        if let Some(existing_action) = self.resolve(emoji) {
+            if existing_action != action {
+                let message = format!(
+                    "The emoji is already used for the `{}` action.",
+                    existing_action
+                );
+                return Err(Error::from(ErrorKind::Giveaway(message)));
+            }
+        }
+
+        self.actions
+            .retain(|_, bound_action| *bound_action != action);
+        self.actions.insert(emoji.to_string(), action);
+        Ok(())
+    }
+}
+
+// Converts the emoji into the reaction type. The custom emojis are written
+// as `<:name:id>` (or `<a:name:id>` for animated ones), anything else is
+// treated as an unicode emoji.
+pub fn parse_reaction_type(emoji: &str) -> ReactionType {
+    let custom_emoji = emoji
+        .strip_prefix('<')
+        .and_then(|value| value.strip_suffix('>'))
+        .map(|value| value.split(':').collect::<Vec<&str>>());
+
+    match custom_emoji.as_ref().map(|parts| parts.as_slice()) {
+        Some([animated, name, id]) if *animated == "" || *animated == "a" => {
+            match id.parse::<u64>() {
+                Ok(id) => ReactionType::Custom {
+                    animated: *animated == "a",
+                    id: EmojiId(id),
+                    name: Some(name.to_string()),
+                },
+                Err(_) => ReactionType::Unicode(emoji.to_string()),
+            }
+        }
+        _ => ReactionType::Unicode(emoji.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::model::channel::ReactionType;
+    use serenity::model::id::EmojiId;
+
+    use crate::commands::giveaway::reactions::{
+        parse_reaction_type, ReactionAction, ReactionControls,
+    };
+    use crate::error::{Error, ErrorKind};
+
+    #[test]
+    fn test_resolve_default_emojis() {
+        let controls = ReactionControls::new();
+
+        assert_eq!(controls.resolve("🎲"), Some(ReactionAction::Roll));
+        assert_eq!(controls.resolve("✅"), Some(ReactionAction::Confirm));
+        assert_eq!(controls.resolve("❌"), Some(ReactionAction::Deny));
+        assert_eq!(controls.resolve("👍"), None);
+    }
+
+    #[test]
+    fn test_resolve_custom_emoji() {
+        let controls = ReactionControls::new();
+
+        controls
+            .bind(ReactionAction::Roll, "<:nightsong:123456>")
+            .unwrap();
+
+        assert_eq!(
+            controls.resolve("<:nightsong:123456>"),
+            Some(ReactionAction::Roll)
+        );
+        assert_eq!(controls.resolve("🎲"), None);
+        assert_eq!(
+            controls.emoji(ReactionAction::Roll),
+            Some("<:nightsong:123456>".to_string())
+        );
+        assert_eq!(controls.resolve("✅"), Some(ReactionAction::Confirm));
+    }
+
+    #[test]
+    fn test_get_error_for_emoji_bound_to_another_action() {
+        let controls = ReactionControls::new();
+
+        let result = controls.bind(ReactionAction::Roll, "✅");
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The emoji is already used for the `confirm` action."
+            )))
+        );
+        assert_eq!(controls.resolve("🎲"), Some(ReactionAction::Roll));
+    }
+
+    #[test]
+    fn test_parse_action_name() {
+        assert_eq!(
+            ReactionAction::from_name("roll"),
+            Some(ReactionAction::Roll)
+        );
+        assert_eq!(
+            ReactionAction::from_name("confirm"),
+            Some(ReactionAction::Confirm)
+        );
+        assert_eq!(
+            ReactionAction::from_name("deny"),
+            Some(ReactionAction::Deny)
+        );
+        assert_eq!(ReactionAction::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_parse_unicode_reaction_type() {
+        assert_eq!(
+            parse_reaction_type("🎉"),
+            ReactionType::Unicode("🎉".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_custom_reaction_type() {
+        assert_eq!(
+            parse_reaction_type("<:nightsong:123456>"),
+            ReactionType::Custom {
+                animated: false,
+                id: EmojiId(123456),
+                name: Some("nightsong".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_reaction_type("<a:nightsong:123456>"),
+            ReactionType::Custom {
+                animated: true,
+                id: EmojiId(123456),
+                name: Some("nightsong".to_string()),
+            }
+        );
+        assert_eq!(
+            parse_reaction_type("<:nightsong:abc>"),
+            ReactionType::Unicode("<:nightsong:abc>".to_string())
+        );
+    }
+}
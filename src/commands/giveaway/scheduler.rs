@@ -0,0 +1,159 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serenity::model::id::{ChannelId, MessageId};
+
+// How many state messages can be edited per second across all giveaways.
+pub const DEFAULT_EDITS_PER_SECOND: u64 = 2;
+
+// The new content of the giveaway state message. Without the message
+// reference a new message is sent in the channel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MessageEdit {
+    pub channel_id: ChannelId,
+    pub message_id: Option<MessageId>,
+    pub content: String,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    // The edits waiting for the flush, at most one per giveaway.
+    queue: VecDeque<(usize, MessageEdit)>,
+    // The moment when the latest edit was handed out.
+    last_edit_at: Option<Instant>,
+}
+
+// Queues the edits of the giveaway state messages and hands them out no
+// faster than the configured rate. Repeated edits of the same giveaway are
+// coalesced, so only the latest content is sent.
+#[derive(Debug)]
+pub struct EditScheduler {
+    state: Mutex<SchedulerState>,
+    edits_per_second: AtomicU64,
+}
+
+impl EditScheduler {
+    pub fn new(edits_per_second: u64) -> Self {
+        EditScheduler {
+            state: Mutex::new(SchedulerState::default()),
+            edits_per_second: AtomicU64::new(edits_per_second.max(1)),
+        }
+    }
+
+    // Returns how many edits can be sent per second.
+    pub fn edits_per_second(&self) -> u64 {
+        self.edits_per_second.load(Ordering::SeqCst)
+    }
+
+    // Changes how many edits can be sent per second. The rate can't be less
+    // than one edit per second.
+    pub fn set_edits_per_second(&self, value: u64) {
+        self.edits_per_second.store(value.max(1), Ordering::SeqCst);
+    }
+
+    // Adds the edit in the queue. When the giveaway already has a queued edit,
+    // it is replaced by the new one and keeps its place in the queue.
+    pub fn enqueue(&self, index: usize, edit: MessageEdit) {
+        let mut state = self.state.lock().unwrap();
+        match state.queue.iter_mut().find(|(queued, _)| *queued == index) {
+            Some((_, queued_edit)) => *queued_edit = edit,
+            None => state.queue.push_back((index, edit)),
+        }
+    }
+
+    // Returns the number of queued edits.
+    pub fn pending(&self) -> usize {
+        self.state.lock().unwrap().queue.len()
+    }
+
+    // Takes the oldest queued edit, if the rate allows sending it at the
+    // given moment.
+    pub fn next_edit(&self, now: Instant) -> Option<(usize, MessageEdit)> {
+        let interval = Duration::from_secs(1) / self.edits_per_second() as u32;
+        let mut state = self.state.lock().unwrap();
+        if let Some(last_edit_at) = state.last_edit_at {
+            if now.saturating_duration_since(last_edit_at) < interval {
+                return None;
+            }
+        }
+
+        let next = state.queue.pop_front()?;
+        state.last_edit_at = Some(now);
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use serenity::model::id::{ChannelId, MessageId};
+
+    use crate::commands::giveaway::scheduler::{EditScheduler, MessageEdit};
+
+    fn get_edit(content: &str) -> MessageEdit {
+        MessageEdit {
+            channel_id: ChannelId(1),
+            message_id: Some(MessageId(2)),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_coalesce_rapid_edits_of_the_same_giveaway() {
+        let scheduler = EditScheduler::new(2);
+        let now = Instant::now();
+
+        scheduler.enqueue(1, get_edit("first"));
+        scheduler.enqueue(1, get_edit("second"));
+
+        assert_eq!(scheduler.pending(), 1);
+        assert_eq!(scheduler.next_edit(now), Some((1, get_edit("second"))));
+        assert_eq!(scheduler.next_edit(now + Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn test_edits_of_different_giveaways_keep_the_order() {
+        let scheduler = EditScheduler::new(2);
+        let now = Instant::now();
+
+        scheduler.enqueue(1, get_edit("first"));
+        scheduler.enqueue(2, get_edit("second"));
+        scheduler.enqueue(1, get_edit("third"));
+
+        assert_eq!(scheduler.next_edit(now), Some((1, get_edit("third"))));
+        assert_eq!(
+            scheduler.next_edit(now + Duration::from_millis(500)),
+            Some((2, get_edit("second")))
+        );
+    }
+
+    #[test]
+    fn test_edits_are_limited_by_the_rate() {
+        let scheduler = EditScheduler::new(2);
+        let now = Instant::now();
+
+        scheduler.enqueue(1, get_edit("first"));
+        scheduler.enqueue(2, get_edit("second"));
+
+        assert_eq!(scheduler.next_edit(now).is_some(), true);
+        assert_eq!(scheduler.next_edit(now + Duration::from_millis(100)), None);
+        assert_eq!(
+            scheduler
+                .next_edit(now + Duration::from_millis(500))
+                .is_some(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_rate_is_at_least_one_edit_per_second() {
+        let scheduler = EditScheduler::new(0);
+
+        assert_eq!(scheduler.edits_per_second(), 1);
+        scheduler.set_edits_per_second(5);
+        assert_eq!(scheduler.edits_per_second(), 5);
+    }
+}
@@ -1,9 +1,13 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
+use rand::rngs::StdRng;
+use uuid::Uuid;
 
 use crate::commands::giveaway::models::{
-    ConcurrencyRewardsVec, Participant, ParticipantStats, Reward,
+    ConcurrencyReward, ConcurrencyRewardsVec, Giveaway, Participant, ParticipantStats, Reward,
 };
 use crate::error::Result;
 
@@ -12,6 +16,8 @@ pub struct RollOptions<'a> {
     rewards: &'a ConcurrencyRewardsVec,
     raw_message: &'a str,
     stats: Arc<DashMap<u64, ParticipantStats>>,
+    restricted_rewards: HashSet<Uuid>,
+    now: DateTime<Utc>,
 }
 
 impl<'a> RollOptions<'a> {
@@ -26,9 +32,24 @@ impl<'a> RollOptions<'a> {
             rewards,
             raw_message,
             stats: stats.clone(),
+            restricted_rewards: HashSet::new(),
+            now: Utc::now(),
         }
     }
 
+    // Overrides the moment of the roll, which is checked against the claiming
+    // windows of the rewards.
+    pub fn with_now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    // Forbids to roll the given rewards (e.g. reserved for the server boosters).
+    pub fn with_restricted_rewards(mut self, rewards: HashSet<Uuid>) -> Self {
+        self.restricted_rewards = rewards;
+        self
+    }
+
     // Returns the initiator of the roll command.
     pub fn user(&self) -> &'a Participant {
         self.user
@@ -48,6 +69,16 @@ impl<'a> RollOptions<'a> {
     pub fn stats(&self) -> Arc<DashMap<u64, ParticipantStats>> {
         self.stats.clone()
     }
+
+    // Returns the moment of the roll.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    // Checks that the initiator of the roll command can't take the reward.
+    pub fn is_restricted(&self, reward: &Reward) -> bool {
+        self.restricted_rewards.contains(&reward.id())
+    }
 }
 
 pub trait GiveawayStrategy: Send + Sync {
@@ -57,4 +88,32 @@ pub trait GiveawayStrategy: Send + Sync {
     // Converts the reward instance into the text message. Returns None when
     // no need to send a message to user.
     fn to_message(&self, reward: Arc<Box<Reward>>) -> Option<String>;
+
+    // Picks one of the given rewards with the passed random generator, without
+    // changing any state. Returns None for strategies that can't be simulated.
+    fn simulate_pick(
+        &self,
+        _rewards: &[ConcurrencyReward],
+        _rng: &mut StdRng,
+    ) -> Option<ConcurrencyReward> {
+        None
+    }
+
+    // Checks that the giveaway can be started with this strategy. Called
+    // before the giveaway gets activated.
+    fn validate_start(&self, _giveaway: &Giveaway) -> Result<()> {
+        Ok(())
+    }
+
+    // Returns true when the rewards are handed out only by drawing the winners
+    // among the entrants, instead of rolling them by the participants.
+    fn requires_draw(&self) -> bool {
+        false
+    }
+
+    // Returns true when the participants have to pass the number of the
+    // reward to roll it, so it can't be rolled with a reaction.
+    fn requires_reward_number(&self) -> bool {
+        false
+    }
 }
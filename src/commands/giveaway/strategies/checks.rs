@@ -0,0 +1,119 @@
+// Common checks shared between the giveaway strategies
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::commands::giveaway::models::{ConcurrencyReward, ObjectState};
+use crate::commands::giveaway::strategies::base::RollOptions;
+use crate::error::{Error, ErrorKind, Result};
+
+// Checks that the owner has added at least one reward to the giveaway.
+pub fn check_rewards_are_defined(options: &RollOptions) -> Result<()> {
+    if options.rewards().read().unwrap().len() == 0 {
+        let message = format!(
+            "The giveaway doesn't have any rewards. Please, add rewards \
+            or ask to do an owner."
+        );
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    Ok(())
+}
+
+// Checks that the user doesn't have any rewards in the pending state.
+pub fn check_user_has_pending_rewards(options: &RollOptions) -> Result<()> {
+    let user_id = options.user().get_user_id();
+    let pending_rewards = match options.stats().get(&user_id) {
+        Some(pair) => pair.value().pending_rewards(),
+        None => HashSet::new(),
+    };
+
+    let pending_rewards = options
+        .rewards()
+        .clone()
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|obj| {
+            let reward_id = obj.id();
+            let is_pending = obj.object_state() == ObjectState::Pending;
+            is_pending && pending_rewards.contains(&reward_id)
+        })
+        .map(|reward| reward.clone())
+        .collect::<Vec<ConcurrencyReward>>();
+
+    if pending_rewards.len() > 0 {
+        let message = format!(
+            "It's not possible to have more than one reward in \
+            the pending state. Please, activate the previous reward, \
+            or invoke the `!greroll` command."
+        );
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    Ok(())
+}
+
+// Checks that at least one reward can be handed out.
+pub fn check_no_unused_rewards(options: &RollOptions) -> Result<()> {
+    let ref_rewards = options.rewards().clone();
+    let guard_rewards = ref_rewards.read().unwrap();
+    let unused_rewards = guard_rewards
+        .iter()
+        .filter(|obj| obj.object_state() == ObjectState::Unused)
+        .map(|reward| reward.clone())
+        .collect::<Vec<ConcurrencyReward>>();
+
+    if unused_rewards.is_empty() {
+        let message = format!("All possible rewards have been handed out.");
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    if unused_rewards
+        .iter()
+        .all(|reward| is_locked_reward(reward, &guard_rewards))
+    {
+        let message = format!(
+            "The remaining rewards will be available after activating \
+            the rewards they depend on."
+        );
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    Ok(())
+}
+
+// Checks that the reward can be claimed at the given moment, according to
+// its claiming window.
+pub fn check_reward_is_claimable(reward: &ConcurrencyReward, now: DateTime<Utc>) -> Result<()> {
+    match reward.claim_window() {
+        (Some(from), _) if now < from => {
+            let message = format!(
+                "This reward can be claimed since {}.",
+                from.format("%Y-%m-%d %H:%M UTC")
+            );
+            Err(Error::from(ErrorKind::Giveaway(message)))
+        }
+        (_, Some(until)) if now >= until => {
+            let message = format!(
+                "This reward could be claimed only until {}.",
+                until.format("%Y-%m-%d %H:%M UTC")
+            );
+            Err(Error::from(ErrorKind::Giveaway(message)))
+        }
+        _ => Ok(()),
+    }
+}
+
+// Checks that the reward depends on another one, which hasn't been activated yet.
+// Dependencies on the rewards that were removed from the giveaway are ignored.
+pub fn is_locked_reward(reward: &ConcurrencyReward, rewards: &[ConcurrencyReward]) -> bool {
+    match reward.depends_on() {
+        Some(reward_id) => rewards.iter().any(|obj| {
+            obj.id() == reward_id
+                && obj.object_state() != ObjectState::Activated
+                && obj.object_state() != ObjectState::Expired
+        }),
+        None => false,
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::commands::giveaway::models::{Participant, Reward};
+use crate::commands::giveaway::strategies::base::{GiveawayStrategy, RollOptions};
+use crate::commands::giveaway::strategies::random::RandomSelectStrategy;
+use crate::error::Result;
+
+// Participants enter the giveaway first and the rewards are handed out
+// only when the owner draws the winners among the entrants.
+#[derive(Debug)]
+pub struct EntryLotteryStrategy;
+
+impl EntryLotteryStrategy {
+    pub fn new() -> Self {
+        EntryLotteryStrategy {}
+    }
+}
+
+impl GiveawayStrategy for EntryLotteryStrategy {
+    // Invoked for each drawn entrant, who gets one of the unused rewards at random.
+    fn roll(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
+        RandomSelectStrategy::new().roll(options)
+    }
+
+    fn to_message(&self, reward: Arc<Box<Reward>>) -> Option<String> {
+        RandomSelectStrategy::new().to_message(reward)
+    }
+
+    fn requires_draw(&self) -> bool {
+        true
+    }
+}
+
+// Picks up to `count` distinct winners among the entrants. When there are
+// fewer entrants than requested, everyone who entered wins.
+pub fn draw_entrants<R: Rng>(
+    entrants: &[Participant],
+    count: usize,
+    rng: &mut R,
+) -> Vec<Participant> {
+    entrants
+        .choose_multiple(rng, count)
+        .cloned()
+        .collect::<Vec<Participant>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serenity::model::id::UserId;
+    use serenity::model::user::{CurrentUser, User as DiscordUser};
+
+    use crate::commands::giveaway::models::Participant;
+    use crate::commands::giveaway::strategies::lottery::draw_entrants;
+
+    fn get_participant(user_id: u64, username: &str) -> Participant {
+        let mut current_user = CurrentUser::default();
+        current_user.id = UserId(user_id);
+        current_user.name = username.to_owned();
+        Participant::from(DiscordUser::from(current_user))
+    }
+
+    #[test]
+    fn test_draw_distinct_entrants() {
+        let entrants = (1..=10)
+            .map(|user_id| get_participant(user_id, "Entrant"))
+            .collect::<Vec<Participant>>();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let winners = draw_entrants(&entrants, 4, &mut rng);
+        let unique_winners = winners
+            .iter()
+            .map(|participant| participant.get_user_id())
+            .collect::<HashSet<u64>>();
+        assert_eq!(winners.len(), 4);
+        assert_eq!(unique_winners.len(), 4);
+    }
+
+    #[test]
+    fn test_draw_everyone_when_fewer_entrants_than_rewards() {
+        let entrants = vec![get_participant(1, "First"), get_participant(2, "Second")];
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let winners = draw_entrants(&entrants, 5, &mut rng);
+        let unique_winners = winners
+            .iter()
+            .map(|participant| participant.get_user_id())
+            .collect::<HashSet<u64>>();
+        assert_eq!(
+            unique_winners,
+            vec![1, 2].into_iter().collect::<HashSet<u64>>()
+        );
+    }
+
+    #[test]
+    fn test_draw_nobody_without_entrants() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let winners = draw_entrants(&[], 3, &mut rng);
+        assert_eq!(winners.is_empty(), true);
+    }
+}
@@ -1,10 +1,13 @@
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use serenity::framework::standard::{Args, Delimiter};
 
-use crate::commands::giveaway::models::{ConcurrencyReward, ObjectState, Reward};
+use crate::commands::giveaway::models::{Giveaway, ObjectState, Reward};
 use crate::commands::giveaway::strategies::base::{GiveawayStrategy, RollOptions};
+use crate::commands::giveaway::strategies::checks::{
+    check_no_unused_rewards, check_reward_is_claimable, check_rewards_are_defined,
+    check_user_has_pending_rewards, is_locked_reward,
+};
 use crate::error::{Error, ErrorKind, Result};
 
 #[derive(Debug)]
@@ -15,71 +18,6 @@ impl ManualSelectStrategy {
         ManualSelectStrategy {}
     }
 
-    fn check_rewards_are_defined(&self, options: &RollOptions) -> Result<()> {
-        if options.rewards().lock().unwrap().len() == 0 {
-            let message = format!(
-                "The giveaway doesn't have any rewards. Please, add rewards \
-                or ask to do an owner."
-            );
-            return Err(Error::from(ErrorKind::Giveaway(message)));
-        }
-
-        Ok(())
-    }
-
-    fn check_user_has_pending_rewards(&self, options: &RollOptions) -> Result<()> {
-        let user_id = options.user().get_user_id();
-        let pending_rewards = match options.stats().get(&user_id) {
-            Some(pair) => pair.value().pending_rewards(),
-            None => HashSet::new(),
-        };
-
-        let pending_rewards = options
-            .rewards()
-            .clone()
-            .lock()
-            .unwrap()
-            .iter()
-            .filter(|obj| {
-                let reward_id = obj.id();
-                let is_pending = obj.object_state() == ObjectState::Pending;
-                is_pending && pending_rewards.contains(&reward_id)
-            })
-            .map(|reward| reward.clone())
-            .collect::<Vec<ConcurrencyReward>>();
-
-        if pending_rewards.len() > 0 {
-            let message = format!(
-                "It's not possible to have more than one reward in \
-                the pending state. Please, activate the previous reward, \
-                or invoke the `!greroll` command."
-            );
-            return Err(Error::from(ErrorKind::Giveaway(message)));
-        }
-
-        Ok(())
-    }
-
-    fn check_no_unused_rewards(&self, options: &RollOptions) -> Result<()> {
-        let no_unused_rewards = options
-            .rewards()
-            .clone()
-            .lock()
-            .unwrap()
-            .iter()
-            .filter(|obj| obj.object_state() == ObjectState::Unused)
-            .map(|reward| reward.clone())
-            .collect::<Vec<ConcurrencyReward>>()
-            .is_empty();
-
-        if no_unused_rewards {
-            let message = format!("All possible rewards have been handed out.");
-            return Err(Error::from(ErrorKind::Giveaway(message)));
-        }
-
-        Ok(())
-    }
-
     fn get_reward(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
         let mut args = Args::new(options.raw_message(), &[Delimiter::Single(' ')]);
         let index = match args.single::<usize>() {
@@ -91,7 +29,7 @@ impl ManualSelectStrategy {
         };
 
         let ref_rewards = options.rewards().clone();
-        let guard_rewards = ref_rewards.lock().unwrap();
+        let guard_rewards = ref_rewards.read().unwrap();
         match index > 0 && index < guard_rewards.len() + 1 {
             true => {
                 let reward = guard_rewards[index - 1].clone();
@@ -101,6 +39,20 @@ impl ManualSelectStrategy {
                     return Err(Error::from(ErrorKind::Giveaway(message)));
                 }
 
+                if is_locked_reward(&reward, &guard_rewards) {
+                    let message = format!(
+                        "This reward will be available after activating the reward it depends on."
+                    );
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+
+                if options.is_restricted(&reward) {
+                    let message = format!("This reward is reserved for the server boosters.");
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+
+                check_reward_is_claimable(&reward, options.now())?;
+
                 Ok(reward)
             }
             false => {
@@ -113,9 +65,9 @@ impl ManualSelectStrategy {
 
 impl GiveawayStrategy for ManualSelectStrategy {
     fn roll(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
-        self.check_rewards_are_defined(options)?;
-        self.check_user_has_pending_rewards(options)?;
-        self.check_no_unused_rewards(options)?;
+        check_rewards_are_defined(options)?;
+        check_user_has_pending_rewards(options)?;
+        check_no_unused_rewards(options)?;
         let reward = self.get_reward(options)?;
         Ok(reward)
     }
@@ -123,12 +75,30 @@ impl GiveawayStrategy for ManualSelectStrategy {
     fn to_message(&self, _reward: Arc<Box<Reward>>) -> Option<String> {
         None
     }
+
+    fn requires_reward_number(&self) -> bool {
+        true
+    }
+
+    // The participants pick the rewards by their numbers, so the list can't be empty.
+    fn validate_start(&self, giveaway: &Giveaway) -> Result<()> {
+        if giveaway.read_rewards().is_empty() {
+            let message = format!(
+                "The giveaway can't be started without rewards. Please, add them \
+                with the `gadd` or `gaddm` commands first."
+            );
+            return Err(Error::from(ErrorKind::Giveaway(message)));
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::{Arc, Mutex};
+    use std::sync::{Arc, RwLock};
 
+    use chrono::{TimeZone, Utc};
     use dashmap::DashMap;
     use serenity::model::id::UserId;
     use serenity::model::user::{CurrentUser, User as DiscordUser};
@@ -151,7 +121,7 @@ mod tests {
         let user = get_user(1, "Test");
         let participant = Participant::from(user);
         let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
-        let rewards = Arc::new(Mutex::new(Box::new(vec![reward_1.clone()])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone()]));
         let stats = Arc::new(DashMap::new());
         let options = RollOptions::new(&participant, &rewards, "1", &stats);
 
@@ -160,11 +130,73 @@ mod tests {
         assert_eq!(roll, reward_1);
     }
 
+    fn get_reward_with_window() -> Arc<Box<Reward>> {
+        let reward = Arc::new(Box::new(Reward::new("reward #1")));
+        reward.set_claim_window(
+            Some(Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap()),
+            Some(Utc.with_ymd_and_hms(2023, 1, 1, 13, 0, 0).unwrap()),
+        );
+        reward
+    }
+
+    #[test]
+    fn test_get_reward_in_the_claiming_window() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let reward_1 = get_reward_with_window();
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let now = Utc.with_ymd_and_hms(2023, 1, 1, 12, 30, 0).unwrap();
+        let options = RollOptions::new(&participant, &rewards, "1", &stats).with_now(now);
+
+        let strategy = ManualSelectStrategy::new();
+        let roll = strategy.roll(&options).unwrap();
+        assert_eq!(roll, reward_1);
+    }
+
+    #[test]
+    fn test_get_error_for_reward_before_the_claiming_window() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let rewards = Arc::new(RwLock::new(vec![get_reward_with_window()]));
+        let stats = Arc::new(DashMap::new());
+        let now = Utc.with_ymd_and_hms(2023, 1, 1, 11, 59, 0).unwrap();
+        let options = RollOptions::new(&participant, &rewards, "1", &stats).with_now(now);
+
+        let strategy = ManualSelectStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward can be claimed since 2023-01-01 12:00 UTC."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_reward_after_the_claiming_window() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let rewards = Arc::new(RwLock::new(vec![get_reward_with_window()]));
+        let stats = Arc::new(DashMap::new());
+        let now = Utc.with_ymd_and_hms(2023, 1, 1, 13, 0, 0).unwrap();
+        let options = RollOptions::new(&participant, &rewards, "1", &stats).with_now(now);
+
+        let strategy = ManualSelectStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "This reward could be claimed only until 2023-01-01 13:00 UTC."
+            )))
+        );
+    }
+
     #[test]
     fn test_get_error_for_empty_rewards() {
         let user = get_user(1, "Test");
         let participant = Participant::from(user);
-        let rewards = Arc::new(Mutex::new(Box::new(vec![])));
+        let rewards = Arc::new(RwLock::new(vec![]));
         let stats = Arc::new(DashMap::new());
         let options = RollOptions::new(&participant, &rewards, "1", &stats);
 
@@ -188,10 +220,7 @@ mod tests {
         let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
         reward_1.set_object_state(ObjectState::Pending);
         let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
-        let rewards = Arc::new(Mutex::new(Box::new(vec![
-            reward_1.clone(),
-            reward_2.clone(),
-        ])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
 
         let mut participant_1_stats = ParticipantStats::new();
         participant_1_stats.add_pending_reward(reward_1.id());
@@ -222,10 +251,7 @@ mod tests {
         reward_1.set_object_state(ObjectState::Activated);
         let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
         reward_2.set_object_state(ObjectState::Activated);
-        let rewards = Arc::new(Mutex::new(Box::new(vec![
-            reward_1.clone(),
-            reward_2.clone(),
-        ])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
 
         let mut participant_1_stats = ParticipantStats::new();
         participant_1_stats.add_retrieved_reward(reward_1.id());
@@ -251,7 +277,7 @@ mod tests {
         let user = get_user(1, "Test");
         let participant = Participant::from(user);
         let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
-        let rewards = Arc::new(Mutex::new(Box::new(vec![reward_1.clone()])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone()]));
         let stats = Arc::new(DashMap::new());
         let options = RollOptions::new(&participant, &rewards, "", &stats);
 
@@ -274,10 +300,7 @@ mod tests {
         let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
         reward_1.set_object_state(ObjectState::Activated);
         let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
-        let rewards = Arc::new(Mutex::new(Box::new(vec![
-            reward_1.clone(),
-            reward_2.clone(),
-        ])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
 
         let mut participant_1_stats = ParticipantStats::new();
         participant_1_stats.add_retrieved_reward(reward_1.id());
@@ -302,7 +325,7 @@ mod tests {
         let user = get_user(1, "Test");
         let participant = Participant::from(user);
         let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
-        let rewards = Arc::new(Mutex::new(Box::new(vec![reward_1.clone()])));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone()]));
         let stats = Arc::new(DashMap::new());
         let options = RollOptions::new(&participant, &rewards, "2", &stats);
 
@@ -316,4 +339,10 @@ mod tests {
             )))
         );
     }
+
+    #[test]
+    fn test_requires_reward_number() {
+        let strategy = ManualSelectStrategy::new();
+        assert_eq!(strategy.requires_reward_number(), true);
+    }
 }
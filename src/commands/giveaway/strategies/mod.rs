@@ -1,5 +1,14 @@
 pub mod base;
+pub mod checks;
+pub mod lottery;
 pub mod manual;
+pub mod random;
+pub mod simulation;
+pub mod weighted;
 
 pub use crate::commands::giveaway::strategies::base::{GiveawayStrategy, RollOptions};
+pub use crate::commands::giveaway::strategies::lottery::{draw_entrants, EntryLotteryStrategy};
 pub use crate::commands::giveaway::strategies::manual::ManualSelectStrategy;
+pub use crate::commands::giveaway::strategies::random::RandomSelectStrategy;
+pub use crate::commands::giveaway::strategies::simulation::{simulate, DistributionReport};
+pub use crate::commands::giveaway::strategies::weighted::WeightedRandomStrategy;
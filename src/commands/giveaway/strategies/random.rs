@@ -0,0 +1,313 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+
+use crate::commands::giveaway::formatters::DefaultRewardFormatter;
+use crate::commands::giveaway::models::{ConcurrencyReward, ObjectState, ObjectType, Reward};
+use crate::commands::giveaway::strategies::base::{GiveawayStrategy, RollOptions};
+use crate::commands::giveaway::strategies::checks::{
+    check_no_unused_rewards, check_rewards_are_defined, check_user_has_pending_rewards,
+    is_locked_reward,
+};
+use crate::error::{Error, ErrorKind, Result};
+
+pub struct RandomSelectStrategy {
+    // The generator used for the rolls. When it isn't set, the thread-local
+    // generator is used.
+    rng: Option<Mutex<Box<dyn RngCore + Send + Sync>>>,
+}
+
+impl RandomSelectStrategy {
+    pub fn new() -> Self {
+        RandomSelectStrategy { rng: None }
+    }
+
+    // Uses the given generator for the rolls. Only the tests seed the rolls,
+    // so the bot always uses the thread-local generator.
+    #[cfg(test)]
+    pub fn with_rng(rng: Box<dyn RngCore + Send + Sync>) -> Self {
+        RandomSelectStrategy {
+            rng: Some(Mutex::new(rng)),
+        }
+    }
+
+    fn get_reward(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
+        let ref_rewards = options.rewards().clone();
+        let guard_rewards = ref_rewards.read().unwrap();
+        let unused_rewards = guard_rewards
+            .iter()
+            .filter(|obj| obj.object_state() == ObjectState::Unused)
+            .filter(|obj| !is_locked_reward(obj, &guard_rewards))
+            .filter(|obj| !options.is_restricted(obj))
+            .filter(|obj| obj.is_claimable_at(options.now()))
+            .map(|reward| reward.clone())
+            .collect::<Vec<ConcurrencyReward>>();
+
+        let selected_reward = match &self.rng {
+            Some(rng) => unused_rewards.choose(&mut *rng.lock().unwrap()).cloned(),
+            None => unused_rewards.choose(&mut rand::thread_rng()).cloned(),
+        };
+        match selected_reward {
+            Some(reward) => Ok(reward),
+            None => {
+                let message = format!("All possible rewards have been handed out.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for RandomSelectStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RandomSelectStrategy")
+            .field("seeded", &self.rng.is_some())
+            .finish()
+    }
+}
+
+impl GiveawayStrategy for RandomSelectStrategy {
+    fn roll(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
+        check_rewards_are_defined(options)?;
+        check_user_has_pending_rewards(options)?;
+        check_no_unused_rewards(options)?;
+        let reward = self.get_reward(options)?;
+        Ok(reward)
+    }
+
+    fn to_message(&self, reward: Arc<Box<Reward>>) -> Option<String> {
+        let value = match reward.object_type() {
+            ObjectType::Key | ObjectType::KeyPreorder => {
+                DefaultRewardFormatter::new().generate_key_with_mask(&reward)
+            }
+            ObjectType::Other => reward.value(),
+        };
+
+        let text = match reward.object_info() {
+            Some(info) => format!("{} {}", value, info),
+            None => format!("{}", value),
+        };
+        Some(format!("You rolled: {}", text))
+    }
+
+    fn simulate_pick(
+        &self,
+        rewards: &[ConcurrencyReward],
+        rng: &mut StdRng,
+    ) -> Option<ConcurrencyReward> {
+        rewards.choose(rng).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::{Arc, RwLock};
+
+    use chrono::{Duration, TimeZone, Utc};
+    use dashmap::DashMap;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serenity::model::id::UserId;
+    use serenity::model::user::{CurrentUser, User as DiscordUser};
+
+    use crate::commands::giveaway::models::{
+        ConcurrencyReward, ObjectState, Participant, ParticipantStats, Reward,
+    };
+    use crate::commands::giveaway::strategies::{
+        GiveawayStrategy, RandomSelectStrategy, RollOptions,
+    };
+    use crate::error::{Error, ErrorKind};
+
+    fn get_user(user_id: u64, username: &str) -> DiscordUser {
+        let mut current_user = CurrentUser::default();
+        current_user.id = UserId(user_id);
+        current_user.name = username.to_owned();
+        DiscordUser::from(current_user)
+    }
+
+    #[test]
+    fn test_get_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = RandomSelectStrategy::new();
+        let roll = strategy.roll(&options).unwrap();
+        assert_eq!(roll == reward_1 || roll == reward_2, true);
+    }
+
+    #[test]
+    fn test_get_reward_with_seeded_rng() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let rewards = Arc::new(RwLock::new(
+            (1..=5)
+                .map(|index| Arc::new(Box::new(Reward::new(&format!("reward #{}", index)))))
+                .collect::<Vec<ConcurrencyReward>>(),
+        ));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = RandomSelectStrategy::with_rng(Box::new(StdRng::seed_from_u64(42)));
+        let roll = strategy.roll(&options).unwrap();
+        assert_eq!(roll.value().as_str(), "reward #2");
+    }
+
+    #[test]
+    fn test_get_only_unused_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        reward_1.set_object_state(ObjectState::Activated);
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let reward_3 = Arc::new(Box::new(Reward::new("reward #3")));
+        reward_3.set_object_state(ObjectState::Pending);
+        let rewards = Arc::new(RwLock::new(vec![
+            reward_1.clone(),
+            reward_2.clone(),
+            reward_3.clone(),
+        ]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "1", &stats);
+
+        let strategy = RandomSelectStrategy::new();
+        for _ in 0..10 {
+            let roll = strategy.roll(&options).unwrap();
+            assert_eq!(roll, reward_2);
+        }
+    }
+
+    #[test]
+    fn test_get_only_unrestricted_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let restricted_rewards = vec![reward_2.id()].into_iter().collect::<HashSet<_>>();
+        let options = RollOptions::new(&participant, &rewards, "", &stats)
+            .with_restricted_rewards(restricted_rewards);
+
+        let strategy = RandomSelectStrategy::new();
+        for _ in 0..10 {
+            let roll = strategy.roll(&options).unwrap();
+            assert_eq!(roll, reward_1);
+        }
+    }
+
+    #[test]
+    fn test_get_only_claimable_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let now = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        reward_2.set_claim_window(Some(now + Duration::hours(1)), None);
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats).with_now(now);
+
+        let strategy = RandomSelectStrategy::new();
+        for _ in 0..10 {
+            let roll = strategy.roll(&options).unwrap();
+            assert_eq!(roll, reward_1);
+        }
+    }
+
+    #[test]
+    fn test_get_error_for_empty_rewards() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let rewards = Arc::new(RwLock::new(vec![]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = RandomSelectStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway doesn't have any rewards. Please, add rewards \
+                or ask to do an owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_when_user_has_pending_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        reward_1.set_object_state(ObjectState::Pending);
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+
+        let mut participant_1_stats = ParticipantStats::new();
+        participant_1_stats.add_pending_reward(reward_1.id());
+        let stats = Arc::new(DashMap::new());
+        stats.insert(participant.get_user_id(), participant_1_stats);
+
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = RandomSelectStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "It's not possible to have more than one reward in \
+                the pending state. Please, activate the previous reward, \
+                or invoke the `!greroll` command."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_no_available_reward_and_they_were_all_taken() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        reward_1.set_object_state(ObjectState::Activated);
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        reward_2.set_object_state(ObjectState::Activated);
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = RandomSelectStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "All possible rewards have been handed out."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_to_message_masks_the_key() {
+        let reward = Arc::new(Box::new(Reward::new(
+            "AAAAA-BBBBB-CCCCC-DDDD [Store] -> Some game",
+        )));
+        reward.set_object_state(ObjectState::Pending);
+
+        let strategy = RandomSelectStrategy::new();
+        let message = strategy.to_message(reward);
+        assert_eq!(
+            message,
+            Some(format!("You rolled: AAAAA-BBBBB-CCCCC-xxxx [Store]"))
+        );
+    }
+}
@@ -0,0 +1,180 @@
+// Simulation of the random draws, so that the owners can check the
+// fairness of the strategy before starting the giveaway
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use uuid::Uuid;
+
+use crate::commands::giveaway::models::ConcurrencyReward;
+use crate::commands::giveaway::strategies::base::GiveawayStrategy;
+use crate::error::{Error, ErrorKind, Result};
+
+pub const MAX_SIMULATION_ITERATIONS: u64 = 100_000;
+
+#[derive(Debug)]
+pub struct DistributionReport {
+    // How many times the whole draw was simulated.
+    iterations: u64,
+    // The number of synthetic participants in each draw.
+    participants: usize,
+    // How many times each reward was won, in the order of the passed rewards.
+    wins: Vec<(Uuid, u64)>,
+}
+
+impl DistributionReport {
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    pub fn participants(&self) -> usize {
+        self.participants
+    }
+
+    pub fn wins(&self) -> &Vec<(Uuid, u64)> {
+        &self.wins
+    }
+
+    // Returns the share of draws in which the reward was won.
+    pub fn win_rate(&self, reward_id: &Uuid) -> f64 {
+        match self.wins.iter().find(|(id, _)| id == reward_id) {
+            Some((_, wins)) if self.iterations > 0 => *wins as f64 / self.iterations as f64,
+            _ => 0.0,
+        }
+    }
+}
+
+// Runs the draw many times, where each participant takes one of the remaining
+// rewards. The rewards and their states are never changed.
+pub fn simulate(
+    strategy: &dyn GiveawayStrategy,
+    rewards: &[ConcurrencyReward],
+    participants: usize,
+    iterations: u64,
+    seed: u64,
+) -> Result<DistributionReport> {
+    if rewards.is_empty() {
+        let message = format!("The giveaway doesn't have any unused rewards to simulate.");
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    if iterations > MAX_SIMULATION_ITERATIONS {
+        let message = format!(
+            "The number of iterations can't be greater than {}.",
+            MAX_SIMULATION_ITERATIONS
+        );
+        return Err(Error::from(ErrorKind::Giveaway(message)));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut counters: HashMap<Uuid, u64> = HashMap::new();
+    for _ in 0..iterations {
+        let mut available_rewards = rewards.to_vec();
+        for _ in 0..participants {
+            if available_rewards.is_empty() {
+                break;
+            }
+
+            let reward = match strategy.simulate_pick(&available_rewards, &mut rng) {
+                Some(reward) => reward,
+                None => {
+                    let message = format!(
+                        "The simulation is available only for giveaways with a random strategy."
+                    );
+                    return Err(Error::from(ErrorKind::Giveaway(message)));
+                }
+            };
+
+            *counters.entry(reward.id()).or_insert(0) += 1;
+            available_rewards.retain(|obj| obj.id() != reward.id());
+        }
+    }
+
+    let wins = rewards
+        .iter()
+        .map(|reward| (reward.id(), *counters.get(&reward.id()).unwrap_or(&0)))
+        .collect();
+
+    Ok(DistributionReport {
+        iterations,
+        participants,
+        wins,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::commands::giveaway::models::{ObjectState, Reward};
+    use crate::commands::giveaway::strategies::{
+        simulate, ManualSelectStrategy, RandomSelectStrategy,
+    };
+    use crate::error::{Error, ErrorKind};
+
+    #[test]
+    fn test_uniform_strategy_yields_even_distribution() {
+        let rewards = vec![
+            Arc::new(Box::new(Reward::new("reward #1"))),
+            Arc::new(Box::new(Reward::new("reward #2"))),
+            Arc::new(Box::new(Reward::new("reward #3"))),
+            Arc::new(Box::new(Reward::new("reward #4"))),
+        ];
+
+        let strategy = RandomSelectStrategy::new();
+        let report = simulate(&strategy, &rewards, 1, 10000, 42).unwrap();
+
+        assert_eq!(report.iterations(), 10000);
+        assert_eq!(report.participants(), 1);
+        for reward in rewards.iter() {
+            let win_rate = report.win_rate(&reward.id());
+            assert_eq!((win_rate - 0.25).abs() < 0.03, true);
+        }
+    }
+
+    #[test]
+    fn test_every_reward_is_won_when_participants_are_enough() {
+        let rewards = vec![
+            Arc::new(Box::new(Reward::new("reward #1"))),
+            Arc::new(Box::new(Reward::new("reward #2"))),
+        ];
+
+        let strategy = RandomSelectStrategy::new();
+        let report = simulate(&strategy, &rewards, 5, 100, 42).unwrap();
+
+        for (_, wins) in report.wins().iter() {
+            assert_eq!(*wins, 100);
+        }
+        for reward in rewards.iter() {
+            assert_eq!(reward.object_state(), ObjectState::Unused);
+        }
+    }
+
+    #[test]
+    fn test_get_error_for_non_random_strategy() {
+        let rewards = vec![Arc::new(Box::new(Reward::new("reward #1")))];
+
+        let strategy = ManualSelectStrategy::new();
+        let result = simulate(&strategy, &rewards, 1, 10, 42);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The simulation is available only for giveaways with a random strategy."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_empty_rewards() {
+        let strategy = RandomSelectStrategy::new();
+        let result = simulate(&strategy, &[], 1, 10, 42);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway doesn't have any unused rewards to simulate."
+            )))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::commands::giveaway::models::{ConcurrencyReward, ObjectState, Reward};
+use crate::commands::giveaway::strategies::base::{GiveawayStrategy, RollOptions};
+use crate::commands::giveaway::strategies::checks::{
+    check_no_unused_rewards, check_rewards_are_defined, check_user_has_pending_rewards,
+    is_locked_reward,
+};
+use crate::commands::giveaway::strategies::random::RandomSelectStrategy;
+use crate::error::{Error, ErrorKind, Result};
+
+#[derive(Debug)]
+pub struct WeightedRandomStrategy;
+
+impl WeightedRandomStrategy {
+    pub fn new() -> Self {
+        WeightedRandomStrategy {}
+    }
+
+    // Picks one of the rewards proportionally to their weights.
+    fn select_reward<R: Rng>(
+        &self,
+        rewards: &[ConcurrencyReward],
+        rng: &mut R,
+    ) -> Option<ConcurrencyReward> {
+        let weights = rewards.iter().map(|reward| reward.weight());
+        match WeightedIndex::new(weights) {
+            Ok(distribution) => Some(rewards[distribution.sample(rng)].clone()),
+            Err(_) => None,
+        }
+    }
+
+    fn get_reward(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
+        let ref_rewards = options.rewards().clone();
+        let guard_rewards = ref_rewards.read().unwrap();
+        let unused_rewards = guard_rewards
+            .iter()
+            .filter(|obj| obj.object_state() == ObjectState::Unused)
+            .filter(|obj| !is_locked_reward(obj, &guard_rewards))
+            .filter(|obj| !options.is_restricted(obj))
+            .filter(|obj| obj.is_claimable_at(options.now()))
+            .map(|reward| reward.clone())
+            .collect::<Vec<ConcurrencyReward>>();
+
+        match self.select_reward(&unused_rewards, &mut rand::thread_rng()) {
+            Some(reward) => Ok(reward),
+            None => {
+                let message = format!("All possible rewards have been handed out.");
+                Err(Error::from(ErrorKind::Giveaway(message)))
+            }
+        }
+    }
+}
+
+impl GiveawayStrategy for WeightedRandomStrategy {
+    fn roll(&self, options: &RollOptions) -> Result<Arc<Box<Reward>>> {
+        check_rewards_are_defined(options)?;
+        check_user_has_pending_rewards(options)?;
+        check_no_unused_rewards(options)?;
+        let reward = self.get_reward(options)?;
+        Ok(reward)
+    }
+
+    // Uses the same masked output as the regular random strategy.
+    fn to_message(&self, reward: Arc<Box<Reward>>) -> Option<String> {
+        RandomSelectStrategy::new().to_message(reward)
+    }
+
+    fn simulate_pick(
+        &self,
+        rewards: &[ConcurrencyReward],
+        rng: &mut StdRng,
+    ) -> Option<ConcurrencyReward> {
+        self.select_reward(rewards, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, RwLock};
+
+    use dashmap::DashMap;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serenity::model::id::UserId;
+    use serenity::model::user::{CurrentUser, User as DiscordUser};
+
+    use crate::commands::giveaway::models::{ObjectState, Participant, ParticipantStats, Reward};
+    use crate::commands::giveaway::strategies::{
+        GiveawayStrategy, RollOptions, WeightedRandomStrategy,
+    };
+    use crate::error::{Error, ErrorKind};
+
+    fn get_user(user_id: u64, username: &str) -> DiscordUser {
+        let mut current_user = CurrentUser::default();
+        current_user.id = UserId(user_id);
+        current_user.name = username.to_owned();
+        DiscordUser::from(current_user)
+    }
+
+    #[test]
+    fn test_selection_frequencies_match_weights() {
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1 {weight=1}")));
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2 {weight=3}")));
+        let reward_3 = Arc::new(Box::new(Reward::new("reward #3 {weight=6}")));
+        let rewards = vec![reward_1.clone(), reward_2.clone(), reward_3.clone()];
+
+        let strategy = WeightedRandomStrategy::new();
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counters = [0; 3];
+        for _ in 0..10000 {
+            let reward = strategy.select_reward(&rewards, &mut rng).unwrap();
+            let position = rewards.iter().position(|obj| *obj == reward).unwrap();
+            counters[position] += 1;
+        }
+
+        assert_eq!((counters[0] as i32 - 1000).abs() < 200, true);
+        assert_eq!((counters[1] as i32 - 3000).abs() < 300, true);
+        assert_eq!((counters[2] as i32 - 6000).abs() < 300, true);
+    }
+
+    #[test]
+    fn test_get_only_unused_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1 {weight=100}")));
+        reward_1.set_object_state(ObjectState::Activated);
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = WeightedRandomStrategy::new();
+        for _ in 0..10 {
+            let roll = strategy.roll(&options).unwrap();
+            assert_eq!(roll, reward_2);
+        }
+    }
+
+    #[test]
+    fn test_get_error_for_empty_rewards() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+        let rewards = Arc::new(RwLock::new(vec![]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = WeightedRandomStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "The giveaway doesn't have any rewards. Please, add rewards \
+                or ask to do an owner."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_when_user_has_pending_reward() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        reward_1.set_object_state(ObjectState::Pending);
+        let reward_2 = Arc::new(Box::new(Reward::new("reward #2")));
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone(), reward_2.clone()]));
+
+        let mut participant_1_stats = ParticipantStats::new();
+        participant_1_stats.add_pending_reward(reward_1.id());
+        let stats = Arc::new(DashMap::new());
+        stats.insert(participant.get_user_id(), participant_1_stats);
+
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = WeightedRandomStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "It's not possible to have more than one reward in \
+                the pending state. Please, activate the previous reward, \
+                or invoke the `!greroll` command."
+            )))
+        );
+    }
+
+    #[test]
+    fn test_get_error_for_no_available_reward_and_they_were_all_taken() {
+        let user = get_user(1, "Test");
+        let participant = Participant::from(user);
+
+        let reward_1 = Arc::new(Box::new(Reward::new("reward #1")));
+        reward_1.set_object_state(ObjectState::Activated);
+        let rewards = Arc::new(RwLock::new(vec![reward_1.clone()]));
+        let stats = Arc::new(DashMap::new());
+        let options = RollOptions::new(&participant, &rewards, "", &stats);
+
+        let strategy = WeightedRandomStrategy::new();
+        let result = strategy.roll(&options);
+        assert_eq!(result.is_err(), true);
+        assert_eq!(
+            result.unwrap_err(),
+            Error::from(ErrorKind::Giveaway(format!(
+                "All possible rewards have been handed out."
+            )))
+        );
+    }
+}
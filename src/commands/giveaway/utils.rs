@@ -1,12 +1,210 @@
+use std::env;
 use std::sync::Arc;
 
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use lazy_static::lazy_static;
+use serenity::http::Http;
 use serenity::model::channel::Message;
+use serenity::model::id::{ChannelId, RoleId, UserId};
 use serenity::prelude::Context;
 
+use crate::commands::giveaway::dto::OwnerDashboard;
 use crate::commands::giveaway::manager::GiveawayManager;
+use crate::commands::giveaway::reactions::parse_reaction_type;
+use crate::commands::giveaway::scheduler::MessageEdit;
 
+// The name of the role that Discord gives to the server boosters.
+pub const BOOSTER_ROLE_NAME: &str = "Server Booster";
+// The largest file with rewards that can be imported (in bytes).
+pub const MAX_IMPORT_FILE_SIZE: u64 = 1024 * 1024;
+// The number of cells in the bar with the reward states.
+pub const STATE_BAR_WIDTH: usize = 20;
+// The number of users shown in the leaderboard.
+pub const LEADERBOARD_SIZE: usize = 10;
+// The longest message (in characters) that Discord allows to send.
+pub const MAX_MESSAGE_LENGTH: usize = 2000;
+
+lazy_static! {
+    // The role and user IDs that are allowed to create giveaways, separated
+    // by commas. When the variable isn't set, everyone can create giveaways.
+    static ref GIVEAWAY_CREATOR_ROLES: Vec<u64> = env::var("GIVEAWAY_CREATOR_ROLES")
+        .map(|value| parse_creator_ids(&value))
+        .unwrap_or_default();
+    // Whether the bot tracks its own giveaway state messages. Enabled by default,
+    // can be disabled when multiple bot instances share a channel.
+    pub static ref TRACK_OWN_MESSAGES_ONLY: bool = env::var("TRACK_OWN_MESSAGES_ONLY")
+        .map(|value| parse_flag(&value))
+        .unwrap_or(true);
+}
+
+// Parses the value of the on/off environment variable. Anything except
+// "0", "false", "off" and "no" is treated as enabled.
+pub fn parse_flag(value: &str) -> bool {
+    match value.trim().to_lowercase().as_str() {
+        "0" | "false" | "off" | "no" => false,
+        _ => true,
+    }
+}
+
+// Checks that the message is a giveaway state message posted by this bot,
+// so its id needs to be remembered. Nothing is processed when the tracking
+// is disabled.
+pub fn should_process_message(enabled: bool, bot_id: u64, author_id: u64, content: &str) -> bool {
+    enabled && author_id == bot_id && content.starts_with("Giveaway #")
+}
+
+// Parses the comma-separated list of the role and user IDs. Invalid entries
+// are skipped.
+pub fn parse_creator_ids(value: &str) -> Vec<u64> {
+    value
+        .split(',')
+        .filter_map(|entry| entry.trim().parse::<u64>().ok())
+        .collect()
+}
+
+// Returns true when the user or one of the member's roles is in the list of
+// allowed creators. An empty list allows everyone.
+pub fn can_create(allowed_ids: &[u64], user_id: UserId, roles: &[RoleId]) -> bool {
+    allowed_ids.is_empty()
+        || allowed_ids.contains(&user_id.0)
+        || roles.iter().any(|role| allowed_ids.contains(&role.0))
+}
+
+// Extracts the giveaway number from the header of the giveaway state
+// message (e.g. "Giveaway #1: (12/20 left)").
+pub fn parse_giveaway_index(content: &str) -> Option<usize> {
+    let header = content.split_terminator("\n").next()?;
+    let index = header.strip_prefix("Giveaway #")?.split(':').next()?;
+    index.trim().parse::<usize>().ok()
+}
+
+// Checks that the key looks like a Steam key: three or five groups of five
+// letters or digits, separated by dashes (e.g. "AAAAA-BBBBB-CCCCC").
+pub fn is_well_formed_key(value: &str) -> bool {
+    let groups = value.split('-').collect::<Vec<&str>>();
+    (groups.len() == 3 || groups.len() == 5)
+        && groups
+            .iter()
+            .all(|group| group.len() == 5 && group.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+// Parses the time in the `YYYY-MM-DDTHH:MM` format as UTC. The `-` value
+// means an open side of the time window and is returned as `Some(None)`.
+pub fn parse_window_bound(value: &str) -> Option<Option<DateTime<Utc>>> {
+    if value == "-" {
+        return Some(None);
+    }
+
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .ok()
+        .map(|naive| Some(Utc.from_utc_datetime(&naive)))
+}
+
+// Splits the text into the parts not longer than the limit (in characters).
+// The text is split by lines, unless a single line exceeds the limit.
+pub fn split_message(content: &str, limit: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    for line in content.lines() {
+        let mut line = line.to_string();
+        while line.chars().count() > limit {
+            if !current.is_empty() {
+                parts.push(current);
+                current = String::new();
+            }
+            let head = line.chars().take(limit).collect::<String>();
+            line = line.chars().skip(limit).collect::<String>();
+            parts.push(head);
+        }
+
+        let extra_length = match current.is_empty() {
+            true => line.chars().count(),
+            false => line.chars().count() + 1,
+        };
+        if !current.is_empty() && current.chars().count() + extra_length > limit {
+            parts.push(current);
+            current = String::new();
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(&line);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// Renders the owner's dashboard as a compact summary: the totals first,
+// then one line per giveaway.
+pub fn render_dashboard(dashboard: &OwnerDashboard) -> String {
+    let mut lines = vec![format!(
+        "Giveaways: {}, rewards: {} ({} left), claims: {}, participants: {}",
+        dashboard.giveaways.len(),
+        dashboard.total_rewards,
+        dashboard.total_remaining,
+        dashboard.total_claims,
+        dashboard.total_participants
+    )];
+    for summary in dashboard.giveaways.iter() {
+        lines.push(format!(
+            "#{} {}: {}/{} left, {} claims, {} participants",
+            summary.index,
+            summary.description,
+            summary.remaining,
+            summary.rewards,
+            summary.claims,
+            summary.participants
+        ));
+    }
+    lines.join("\n")
+}
+
+// Returns the warning appended to the reply after adding the rewards, when
+// some of them look like malformed keys.
+pub fn malformed_keys_warning(malformed: usize) -> String {
+    match malformed {
+        0 => String::new(),
+        _ => format!(" {} may be malformed key(s).", malformed),
+    }
+}
+
+// Extracts the rewards from the content of an existing message, one per
+// line. Empty lines and code block fences are skipped.
+pub fn extract_rewards_from_message(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("```"))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+// Reacts on the state message of the giveaway with the emoji set by the
+// owner, after the reward was claimed.
+pub fn add_claim_reaction(ctx: &Context, giveaway_manager: &Arc<GiveawayManager>, index: usize) {
+    giveaway_manager.add_claim_reaction(index, |channel_id, message_id, emoji| {
+        match channel_id.create_reaction(&ctx.http, message_id, parse_reaction_type(emoji)) {
+            Ok(_) => true,
+            Err(err) => {
+                println!("Can't add the claim reaction: {}", err.to_string());
+                false
+            }
+        }
+    });
+}
+
+// Decodes the uploaded text file with rewards. Returns `None` when the
+// file isn't a valid UTF-8 text. The byte order mark is skipped.
+pub fn decode_rewards_file(data: &[u8]) -> Option<String> {
+    let content = String::from_utf8(data.to_vec()).ok()?;
+    Some(content.trim_start_matches('\u{feff}').to_string())
+}
+
+// Queues the update of the giveaway state message. The message is edited
+// later by the edit scheduler, which coalesces the repeated updates.
 pub fn update_giveaway_message(
-    ctx: &mut Context,
     msg: &Message,
     giveaway_manager: &Arc<GiveawayManager>,
     index: usize,
@@ -30,21 +228,36 @@ pub fn update_giveaway_message(
         }
     };
 
-    match giveaway.get_message_id() {
+    let edit = MessageEdit {
+        channel_id: msg.channel_id,
+        message_id: giveaway.get_message_id(),
+        content: update_msg,
+    };
+    giveaway_manager.edit_scheduler().enqueue(index, edit);
+}
+
+// Applies the queued update of the giveaway state message.
+pub fn apply_message_edit(http: &Arc<Http>, edit: &MessageEdit) {
+    match edit.message_id {
         // Try to edit the existing message instead of printing a new one
         Some(message_id) => {
-            match msg
+            match edit
                 .channel_id
-                .edit_message(&ctx.http, message_id, |m| m.content(&update_msg))
+                .edit_message(http, message_id, |m| m.content(&edit.content))
             {
                 Ok(_) => (),
                 Err(_) => {
-                    msg.channel_id.say(&ctx.http, &update_msg).unwrap();
+                    if let Err(err) = edit.channel_id.say(http, &edit.content) {
+                        println!(
+                            "Impossible to output the giveaway message in the channel. Reason: {}",
+                            err.to_string()
+                        );
+                    }
                 }
             }
         }
         // Send a new message in the chat (if it was missing by some reason)
-        None => match msg.channel_id.say(&ctx.http, &update_msg) {
+        None => match edit.channel_id.say(http, &edit.content) {
             Ok(_) => (),
             Err(err) => {
                 println!(
@@ -56,6 +269,141 @@ pub fn update_giveaway_message(
     }
 }
 
+// Checks that the author of the message is boosting the server, by
+// looking for the booster role among the member's roles.
+pub fn is_server_booster(ctx: &mut Context, msg: &Message) -> bool {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return false,
+    };
+
+    let member = match guild_id.member(&*ctx, msg.author.id) {
+        Ok(member) => member,
+        Err(err) => {
+            println!("Can't get the member of the guild: {}", err.to_string());
+            return false;
+        }
+    };
+
+    match ctx.http.get_guild_roles(guild_id.0) {
+        Ok(roles) => roles
+            .iter()
+            .any(|role| role.name == BOOSTER_ROLE_NAME && member.roles.contains(&role.id)),
+        Err(err) => {
+            println!("Can't get the roles of the guild: {}", err.to_string());
+            false
+        }
+    }
+}
+
+// Checks that the author of the message is allowed to create giveaways,
+// according to the `GIVEAWAY_CREATOR_ROLES` environment variable.
+pub fn is_giveaway_creator(ctx: &mut Context, msg: &Message) -> bool {
+    let allowed_ids = &*GIVEAWAY_CREATOR_ROLES;
+    if can_create(allowed_ids, msg.author.id, &[]) {
+        return true;
+    }
+
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return false,
+    };
+
+    match guild_id.member(&*ctx, msg.author.id) {
+        Ok(member) => can_create(allowed_ids, msg.author.id, &member.roles),
+        Err(err) => {
+            println!("Can't get the member of the guild: {}", err.to_string());
+            false
+        }
+    }
+}
+
+// Returns the median and the average of the given durations, or `None`
+// when there is nothing to aggregate.
+pub fn median_and_average(durations: &[Duration]) -> Option<(Duration, Duration)> {
+    if durations.is_empty() {
+        return None;
+    }
+
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let middle = sorted.len() / 2;
+    let median = match sorted.len() % 2 {
+        0 => (sorted[middle - 1] + sorted[middle]) / 2,
+        _ => sorted[middle],
+    };
+
+    let total = sorted
+        .iter()
+        .fold(Duration::zero(), |acc, duration| acc + *duration);
+    let average = total / sorted.len() as i32;
+    Some((median, average))
+}
+
+// Renders the share of claimed, pending and available rewards as a bar of
+// the given width, e.g. `█████▒▒░░░░░`. The claimed rewards go first.
+pub fn render_state_bar(unused: usize, pending: usize, activated: usize, width: usize) -> String {
+    let total = unused + pending + activated;
+    if total == 0 {
+        return "░".repeat(width);
+    }
+
+    // Rounding the boundaries instead of each part keeps the total width
+    let cells = |count: usize| (count * width + total / 2) / total;
+    let activated_cells = cells(activated);
+    let pending_cells = cells(activated + pending) - activated_cells;
+    let unused_cells = width - activated_cells - pending_cells;
+
+    format!(
+        "{}{}{}",
+        "█".repeat(activated_cells),
+        "▒".repeat(pending_cells),
+        "░".repeat(unused_cells)
+    )
+}
+
+// Formats the duration in a human-readable form (e.g. "1h 2m 3s").
+pub fn format_duration(duration: &Duration) -> String {
+    let total_seconds = duration.num_seconds();
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    let seconds = total_seconds % 60;
+
+    match (hours, minutes) {
+        (0, 0) => format!("{}s", seconds),
+        (0, _) => format!("{}m {}s", minutes, seconds),
+        _ => format!("{}h {}m {}s", hours, minutes, seconds),
+    }
+}
+
+// Notifies the channel when the giveaway was paused after the last roll,
+// because it reached the limit of claims.
+pub fn claims_limit_output(
+    http: &Arc<Http>,
+    channel_id: ChannelId,
+    giveaway_manager: &Arc<GiveawayManager>,
+    index: usize,
+) {
+    let giveaway = match giveaway_manager.get_giveaway_by_index(index) {
+        Ok(giveaway) => giveaway,
+        Err(err) => {
+            println!("Can't get giveaway by index: {}", err.to_string());
+            return;
+        }
+    };
+
+    match giveaway.pause_after_claims() {
+        Some(limit) if !giveaway.is_activated() && giveaway.claims() == limit => {
+            let response = format!(
+                "The giveaway #{} has been paused after {} claims.",
+                index, limit
+            );
+            channel_id.say(http, &response).unwrap();
+        }
+        _ => (),
+    }
+}
+
 pub fn periodic_giveaway_state_output(
     ctx: &mut Context,
     msg: &Message,
@@ -86,3 +434,290 @@ pub fn periodic_giveaway_state_output(
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use serenity::model::id::{RoleId, UserId};
+
+    use crate::commands::giveaway::dto::{GiveawaySummary, OwnerDashboard};
+    use crate::commands::giveaway::utils::{
+        can_create, decode_rewards_file, extract_rewards_from_message, format_duration,
+        is_well_formed_key, malformed_keys_warning, median_and_average, parse_creator_ids,
+        parse_flag, parse_giveaway_index, parse_window_bound, render_dashboard, render_state_bar,
+        should_process_message, split_message,
+    };
+
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(parse_flag("1"), true);
+        assert_eq!(parse_flag("true"), true);
+        assert_eq!(parse_flag(" Off "), false);
+        assert_eq!(parse_flag("false"), false);
+        assert_eq!(parse_flag("0"), false);
+    }
+
+    #[test]
+    fn test_should_process_own_state_message() {
+        assert_eq!(
+            should_process_message(true, 1, 1, "Giveaway #1: (3/20 left)"),
+            true
+        );
+    }
+
+    #[test]
+    fn test_should_not_process_messages_of_other_authors() {
+        assert_eq!(
+            should_process_message(true, 1, 2, "Giveaway #1: (3/20 left)"),
+            false
+        );
+    }
+
+    #[test]
+    fn test_should_not_process_other_own_messages() {
+        assert_eq!(
+            should_process_message(true, 1, 1, "The giveaway has been deactivated."),
+            false
+        );
+    }
+
+    #[test]
+    fn test_should_not_process_messages_when_tracking_is_disabled() {
+        assert_eq!(
+            should_process_message(false, 1, 1, "Giveaway #1: (3/20 left)"),
+            false
+        );
+    }
+
+    #[test]
+    fn test_parse_giveaway_index_from_header_with_remaining_rewards() {
+        let content = "Giveaway #12: (3/20 left)\n1. [ ] something";
+
+        assert_eq!(parse_giveaway_index(content), Some(12));
+    }
+
+    #[test]
+    fn test_parse_giveaway_index_from_header_without_rewards() {
+        let content = "Giveaway #2: (0/0 left)\n";
+
+        assert_eq!(parse_giveaway_index(content), Some(2));
+    }
+
+    #[test]
+    fn test_parse_giveaway_index_from_legacy_header() {
+        let content = "Giveaway #3:\n1. [ ] something";
+
+        assert_eq!(parse_giveaway_index(content), Some(3));
+    }
+
+    #[test]
+    fn test_parse_giveaway_index_from_invalid_header() {
+        assert_eq!(parse_giveaway_index("Giveaway #abc: (1/1 left)"), None);
+        assert_eq!(parse_giveaway_index("Just a regular message"), None);
+        assert_eq!(parse_giveaway_index(""), None);
+    }
+
+    #[test]
+    fn test_extract_rewards_from_message() {
+        let content = "AAAAA-BBBBB-CCCCC [Steam] -> Some game\n\n  Just a text  \r\nDDDDD-EEEEE\n";
+
+        assert_eq!(
+            extract_rewards_from_message(content),
+            vec![
+                "AAAAA-BBBBB-CCCCC [Steam] -> Some game",
+                "Just a text",
+                "DDDDD-EEEEE"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_rewards_from_message_with_code_block() {
+        let content = "```\nAAAAA-BBBBB-CCCCC\nDDDDD-EEEEE\n```";
+
+        assert_eq!(
+            extract_rewards_from_message(content),
+            vec!["AAAAA-BBBBB-CCCCC", "DDDDD-EEEEE"]
+        );
+    }
+
+    #[test]
+    fn test_extract_rewards_from_empty_message() {
+        assert_eq!(extract_rewards_from_message("\n  \n").len(), 0);
+    }
+
+    #[test]
+    fn test_decode_rewards_file_with_byte_order_mark() {
+        let data = "\u{feff}AAAAA-BBBBB-CCCCC\r\nDDDDD-EEEEE\r\n".as_bytes();
+
+        let content = decode_rewards_file(data).unwrap();
+        assert_eq!(
+            extract_rewards_from_message(&content),
+            vec!["AAAAA-BBBBB-CCCCC", "DDDDD-EEEEE"]
+        );
+    }
+
+    #[test]
+    fn test_decode_rewards_file_with_invalid_encoding() {
+        assert_eq!(decode_rewards_file(&[0xff, 0xfe, 0x41]), None);
+    }
+
+    #[test]
+    fn test_median_and_average_for_odd_number_of_durations() {
+        let durations = vec![
+            Duration::seconds(90),
+            Duration::seconds(10),
+            Duration::seconds(20),
+        ];
+
+        assert_eq!(
+            median_and_average(&durations),
+            Some((Duration::seconds(20), Duration::seconds(40)))
+        );
+    }
+
+    #[test]
+    fn test_median_and_average_for_even_number_of_durations() {
+        let durations = vec![
+            Duration::seconds(40),
+            Duration::seconds(10),
+            Duration::seconds(20),
+            Duration::seconds(10),
+        ];
+
+        assert_eq!(
+            median_and_average(&durations),
+            Some((Duration::seconds(15), Duration::seconds(20)))
+        );
+    }
+
+    #[test]
+    fn test_median_and_average_for_empty_list() {
+        assert_eq!(median_and_average(&[]), None);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(&Duration::seconds(42)), "42s");
+        assert_eq!(format_duration(&Duration::seconds(125)), "2m 5s");
+        assert_eq!(format_duration(&Duration::seconds(3723)), "1h 2m 3s");
+    }
+
+    #[test]
+    fn test_render_state_bar_with_all_activated_rewards() {
+        assert_eq!(render_state_bar(0, 0, 5, 10), "██████████");
+    }
+
+    #[test]
+    fn test_render_state_bar_with_all_unused_rewards() {
+        assert_eq!(render_state_bar(5, 0, 0, 10), "░░░░░░░░░░");
+        assert_eq!(render_state_bar(0, 0, 0, 10), "░░░░░░░░░░");
+    }
+
+    #[test]
+    fn test_render_state_bar_proportions() {
+        assert_eq!(render_state_bar(5, 2, 3, 10), "███▒▒░░░░░");
+        assert_eq!(render_state_bar(1, 1, 1, 10).chars().count(), 10);
+    }
+
+    #[test]
+    fn test_well_formed_keys() {
+        assert_eq!(is_well_formed_key("AAAAA-BBBBB-CCCCC"), true);
+        assert_eq!(is_well_formed_key("A1B2C-D3E4F-00000"), true);
+        assert_eq!(is_well_formed_key("AAAAA-BBBBB-CCCCC-DDDDD-EEEEE"), true);
+    }
+
+    #[test]
+    fn test_malformed_keys() {
+        assert_eq!(is_well_formed_key("AAAAA-BBBBB"), false);
+        assert_eq!(is_well_formed_key("AAAAA-BBBBB-CCCC"), false);
+        assert_eq!(is_well_formed_key("AAAAA-BBBBB-CCCCC-DDDDD"), false);
+        assert_eq!(is_well_formed_key("AAAAA-BB_BB-CCCCC"), false);
+        assert_eq!(is_well_formed_key("AAAAA--CCCCC"), false);
+    }
+
+    #[test]
+    fn test_split_message_by_lines() {
+        let content = "aaaa\nbbbb\ncccc";
+
+        assert_eq!(split_message(content, 9), vec!["aaaa\nbbbb", "cccc"]);
+        assert_eq!(split_message(content, 100), vec![content]);
+    }
+
+    #[test]
+    fn test_split_message_with_long_line() {
+        let content = "aa\nbbbbbbb";
+
+        assert_eq!(split_message(content, 3), vec!["aa", "bbb", "bbb", "b"]);
+    }
+
+    #[test]
+    fn test_render_dashboard() {
+        let dashboard = OwnerDashboard {
+            giveaways: vec![GiveawaySummary {
+                index: 2,
+                description: format!("Games"),
+                rewards: 5,
+                remaining: 3,
+                claims: 2,
+                participants: 2,
+            }],
+            total_rewards: 5,
+            total_remaining: 3,
+            total_claims: 2,
+            total_participants: 2,
+        };
+
+        assert_eq!(
+            render_dashboard(&dashboard),
+            "Giveaways: 1, rewards: 5 (3 left), claims: 2, participants: 2\n\
+            #2 Games: 3/5 left, 2 claims, 2 participants"
+        );
+    }
+
+    #[test]
+    fn test_parse_window_bound() {
+        assert_eq!(
+            parse_window_bound("2023-01-01T12:30"),
+            Some(Some(Utc.with_ymd_and_hms(2023, 1, 1, 12, 30, 0).unwrap()))
+        );
+        assert_eq!(parse_window_bound("-"), Some(None));
+        assert_eq!(parse_window_bound("tomorrow"), None);
+    }
+
+    #[test]
+    fn test_malformed_keys_warning() {
+        assert_eq!(malformed_keys_warning(0), "");
+        assert_eq!(malformed_keys_warning(2), " 2 may be malformed key(s).");
+    }
+
+    #[test]
+    fn test_can_create_without_configured_roles() {
+        assert_eq!(can_create(&[], UserId(1), &[]), true);
+    }
+
+    #[test]
+    fn test_can_create_with_the_required_role() {
+        let allowed_ids = parse_creator_ids("100, 200");
+
+        assert_eq!(
+            can_create(&allowed_ids, UserId(1), &[RoleId(5), RoleId(200)]),
+            true
+        );
+    }
+
+    #[test]
+    fn test_can_create_without_the_required_role() {
+        let allowed_ids = parse_creator_ids("100,200");
+
+        assert_eq!(can_create(&allowed_ids, UserId(1), &[RoleId(5)]), false);
+    }
+
+    #[test]
+    fn test_can_create_for_the_allowed_user() {
+        let allowed_ids = parse_creator_ids("1,invalid");
+
+        assert_eq!(allowed_ids, vec![1]);
+        assert_eq!(can_create(&allowed_ids, UserId(1), &[]), true);
+    }
+}
@@ -2,10 +2,60 @@ use std::collections::HashSet;
 
 use serenity::client::Context;
 use serenity::framework::standard::{
-    help_commands, macros::help, Args, CommandGroup, CommandResult, HelpOptions,
+    help_commands,
+    macros::{command, group, help},
+    Args, CommandGroup, CommandResult, HelpOptions,
 };
 use serenity::model::prelude::{Message, UserId};
 
+// The explanation of the text accepted by the commands that add rewards.
+// Must be kept in sync with `parse_message`.
+pub const REWARD_FORMAT_HELP: &str = "**Reward format**
+- `AAAAA-BBBBB-CCCCC [Steam] -> Some game` - a store key. The part before `->` is the key, the optional text in square brackets is the store and the text after `->` is the description.
+- `AAAAA-BBBBB-CCCCC -> Some game (pre-order)` - a pre-order key. Any key with the `preorder` or `pre-order` word in the description is treated as a pre-order.
+- `https://example.com/gift` - any text without `->` is a plain reward, shown as is.
+- `3x Nitro` - the leading `Nx` token makes the given number of identical prizes from a single reward.
+- `AAAAA-BBBBB-CCCCC -> Some game {weight=5}` - the `{weight=N}` token makes the reward rolled more often in the giveaways created with `gcreate --weighted`.
+The keys are expected to have three or five groups of five letters or digits, otherwise the bot warns about a possibly malformed key.";
+
+// The commands that accept the rewards in the text format.
+const REWARD_COMMANDS: &[&str] = &["gadd", "gaddm", "gaddreply", "gimport"];
+
+// Returns the extra help for the command or the topic. The reward commands
+// and the `format` topic explain the reward format.
+pub fn explain_command(name: &str) -> String {
+    let name = name.trim().trim_start_matches('!');
+    match name {
+        "" | "format" => REWARD_FORMAT_HELP.to_string(),
+        name if REWARD_COMMANDS.contains(&name) => format!(
+            "The `{}` command accepts the rewards in the following format.\n\n{}",
+            name, REWARD_FORMAT_HELP
+        ),
+        name => format!(
+            "There is no extra help for the `{}` command. Use `!help {}` for its usage and examples.",
+            name, name
+        ),
+    }
+}
+
+#[group]
+#[commands(explain)]
+struct Help;
+
+#[command("ghelp")]
+#[max_args(1)]
+#[help_available]
+#[usage("[command-name]")]
+#[example("gadd")]
+#[example("format")]
+#[description = "Explain the certain giveaway command in more details. Without the command name explains the reward format"]
+fn explain(ctx: &mut Context, msg: &Message, args: Args) -> CommandResult {
+    let content = explain_command(args.rest());
+    msg.channel_id.say(&ctx.http, content)?;
+
+    Ok(())
+}
+
 #[help]
 pub fn get_commands_list(
     context: &mut Context,
@@ -17,3 +67,57 @@ pub fn get_commands_list(
 ) -> CommandResult {
     help_commands::plain(context, msg, args, &help_options, groups, owners)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::giveaway::models::ObjectType;
+    use crate::commands::giveaway::parser::parse_message;
+    use crate::commands::help::{explain_command, REWARD_FORMAT_HELP};
+
+    #[test]
+    fn test_explain_reward_format_for_reward_commands() {
+        let output = explain_command("gadd");
+        assert_eq!(
+            output.starts_with("The `gadd` command accepts the rewards"),
+            true
+        );
+        assert_eq!(output.ends_with(REWARD_FORMAT_HELP), true);
+        assert_eq!(
+            explain_command("!gimport").contains(REWARD_FORMAT_HELP),
+            true
+        );
+        assert_eq!(explain_command(""), REWARD_FORMAT_HELP);
+        assert_eq!(explain_command("format"), REWARD_FORMAT_HELP);
+    }
+
+    #[test]
+    fn test_explain_command_without_extra_help() {
+        assert_eq!(
+            explain_command("glist"),
+            "There is no extra help for the `glist` command. Use `!help glist` for its usage and examples."
+        );
+    }
+
+    #[test]
+    fn test_reward_format_examples_are_parsed_as_described() {
+        let key = parse_message("AAAAA-BBBBB-CCCCC [Steam] -> Some game");
+        assert_eq!(key.value, "AAAAA-BBBBB-CCCCC");
+        assert_eq!(key.object_info, Some(format!("[Steam]")));
+        assert_eq!(key.description, Some(format!("Some game")));
+        assert_eq!(key.object_type, ObjectType::Key);
+
+        let preorder = parse_message("AAAAA-BBBBB-CCCCC -> Some game (pre-order)");
+        assert_eq!(preorder.object_type, ObjectType::KeyPreorder);
+
+        let other = parse_message("https://example.com/gift");
+        assert_eq!(other.object_type, ObjectType::Other);
+
+        let copies = parse_message("3x Nitro");
+        assert_eq!(copies.value, "Nitro");
+        assert_eq!(copies.quantity, 3);
+
+        let weighted = parse_message("AAAAA-BBBBB-CCCCC -> Some game {weight=5}");
+        assert_eq!(weighted.weight, 5);
+        assert_eq!(weighted.description, Some(format!("Some game")));
+    }
+}
@@ -3,4 +3,4 @@ pub mod help;
 
 // Re-exports for the later usage in bot.rs
 pub use crate::commands::giveaway::GIVEAWAY_GROUP;
-pub use crate::commands::help::GET_COMMANDS_LIST;
+pub use crate::commands::help::{GET_COMMANDS_LIST, HELP_GROUP};
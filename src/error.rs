@@ -49,6 +49,8 @@ pub enum ErrorKind {
     RwLock(String),
     #[fail(display = "{}", _0)]
     Giveaway(String),
+    #[fail(display = "{}", _0)]
+    Json(String),
     #[fail(display = "{}", description)]
     Other { description: String },
 }
@@ -75,3 +77,35 @@ impl<T> From<TryLockError<T>> for Error {
         Error::from(Context::new(kind))
     }
 }
+
+// The JSON error isn't cloneable, so only its description is kept.
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        let kind = ErrorKind::Json(format!("Invalid JSON: {}", err));
+        Error::from(Context::new(kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{ErrorKind, Result};
+
+    fn parse_numbers(data: &str) -> Result<Vec<u64>> {
+        let numbers = serde_json::from_str::<Vec<u64>>(data)?;
+        Ok(numbers)
+    }
+
+    #[test]
+    fn test_convert_serde_json_error() {
+        assert_eq!(parse_numbers("[1, 2]").unwrap(), vec![1, 2]);
+
+        let result = parse_numbers("[1, 2");
+        assert_eq!(result.is_err(), true);
+        match result.unwrap_err().kind() {
+            ErrorKind::Json(description) => {
+                assert_eq!(description.starts_with("Invalid JSON: "), true)
+            }
+            kind => panic!("Unexpected error kind: {:?}", kind),
+        }
+    }
+}